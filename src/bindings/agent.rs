@@ -0,0 +1,93 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    clients::{
+        engine_protocol::{Answer, Request},
+        heuristic_round_player::HeuristicRoundPlayer,
+        protocol_round_player::{ProtocolRoundPlayer, RequestAnswerer},
+        random_round_player::RandomRoundPlayer,
+        search_round_player::SearchRoundPlayer,
+    },
+    game::{round::Round, round_player::RoundPlayer, table::Seat},
+};
+
+use super::BindingError;
+
+/// First error of the agents of a round, it is handed to the language once
+/// the round is over.
+pub type AgentError<E> = Rc<RefCell<Option<E>>>;
+
+/// Agent written in the language of a binding. It gets the round, the
+/// request line of the [`engine_protocol`](crate::clients::engine_protocol)
+/// and every answer the request allows, and returns one of them.
+pub trait Agent {
+    type Error: From<BindingError>;
+
+    fn ask(
+        &self,
+        round_state: &Round,
+        seat: Seat,
+        request: &Request,
+    ) -> Result<String, Self::Error>;
+}
+
+struct AgentAnswerer<A: Agent> {
+    agent: A,
+    error: AgentError<A::Error>,
+}
+
+impl<A: Agent> AgentAnswerer<A> {
+    fn ask(&self, round_state: &Round, seat: Seat, request: &Request) -> Result<Answer, A::Error> {
+        let answer = self.agent.ask(round_state, seat, request)?;
+        let answer = request.parse_answer(&answer).ok_or_else(|| {
+            BindingError::new(format!(
+                "agent answered `{}` to `{}`",
+                answer,
+                request.to_line()
+            ))
+        })?;
+
+        Ok(answer)
+    }
+}
+
+impl<A: Agent> RequestAnswerer for AgentAnswerer<A> {
+    /// Once an agent failed the heuristic bot decides for every agent of
+    /// the round, so the round can end and the error can be handed on.
+    fn answer(&self, round_state: &Round, seat: Seat, request: Request) -> Option<Answer> {
+        if self.error.borrow().is_some() {
+            return None;
+        }
+        match self.ask(round_state, seat, &request) {
+            Ok(answer) => Some(answer),
+            Err(error) => {
+                *self.error.borrow_mut() = Some(error);
+                None
+            }
+        }
+    }
+}
+
+/// Bot of the engine with the name.
+pub fn get_bot(name: &str) -> Result<Box<dyn RoundPlayer>, BindingError> {
+    match name {
+        "random" => Ok(Box::new(RandomRoundPlayer)),
+        "heuristic" => Ok(HeuristicRoundPlayer::boxed()),
+        "search" => Ok(SearchRoundPlayer::boxed()),
+        _ => Err(BindingError::new(format!(
+            "there is no `{}` bot, try random, heuristic or search",
+            name
+        ))),
+    }
+}
+
+/// Player of a seat that asks the agent, its first error goes to `error`.
+pub fn get_agent_player<A: Agent + 'static>(
+    agent: A,
+    error: &AgentError<A::Error>,
+) -> Box<dyn RoundPlayer> {
+    Box::new(ProtocolRoundPlayer::new(AgentAnswerer {
+        agent,
+        error: error.clone(),
+    }))
+}
@@ -0,0 +1,72 @@
+//! What the Python module and the JavaScript API share, they only turn
+//! values and errors of their language into the ones of the engine and
+//! back.
+
+pub mod agent;
+pub mod round;
+
+use std::{error::Error, fmt};
+
+use crate::game::{
+    deck::Card, declaration::get_possible_declarations, player::Hand, points::get_card_points,
+    trump::ContractKind,
+};
+
+/// Wrong value a binding was given, it becomes an error of the language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingError {
+    pub message: String,
+}
+
+impl BindingError {
+    pub fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl fmt::Display for BindingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for BindingError {}
+
+pub fn get_cards(names: &[String]) -> Result<Vec<Card>, BindingError> {
+    names
+        .iter()
+        .map(|name| {
+            Card::from_name(name)
+                .ok_or_else(|| BindingError::new(format!("`{}` isn't a card", name)))
+        })
+        .collect()
+}
+
+pub fn get_names(cards: &[Card]) -> Vec<String> {
+    cards.iter().map(Card::get_name).collect()
+}
+
+pub fn get_contract(name: &str) -> Result<ContractKind, BindingError> {
+    ContractKind::from_name(name)
+        .ok_or_else(|| BindingError::new(format!("`{}` isn't a contract", name)))
+}
+
+/// Declarations the hand holds, as their cards and points.
+pub fn get_declarations(hand: &[String]) -> Result<Vec<(Vec<String>, usize)>, BindingError> {
+    let hand = Hand::new(get_cards(hand)?);
+    let declarations = get_possible_declarations(&hand)
+        .into_iter()
+        .map(|declaration| (get_names(&declaration.cards), declaration.points))
+        .collect();
+
+    Ok(declarations)
+}
+
+/// Points the card is worth in a trick under the contract.
+pub fn get_points(card: &str, contract: &str) -> Result<usize, BindingError> {
+    let card = get_cards(&[card.to_string()])?.remove(0);
+    Ok(get_card_points(&card, &get_contract(contract)?))
+}
+
+#[cfg(test)]
+mod test_bindings;
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    SeatedRoundPlayer,
+    game::{
+        deck::{Deal, Deck},
+        round::Round,
+        round_player::RoundPlayer,
+        rules::RuleSet,
+        table::{Seat, TableConfig},
+        team::{Team, TeamPoints},
+    },
+};
+
+use super::{BindingError, agent::AgentError};
+
+pub fn get_table_config(
+    rules: RuleSet,
+    seed: Option<u64>,
+    dealer: Option<usize>,
+) -> Result<TableConfig, BindingError> {
+    let number_of_players = rules.variant.get_number_of_players();
+    let names = (1..=number_of_players)
+        .map(|seat| format!("Player {}", seat))
+        .collect();
+    let table_config = TableConfig::new(names, rules).with_seed(seed);
+    match dealer {
+        Some(dealer) if dealer >= number_of_players => Err(BindingError::new(format!(
+            "there is no seat {} at a table of {}",
+            dealer, number_of_players
+        ))),
+        Some(dealer) => Ok(table_config.with_dealer(Seat::new(dealer))),
+        None => Ok(table_config),
+    }
+}
+
+/// Round of the deal, or of a shuffled deal without one. The hands and the
+/// talon have to hold every card of the deck once.
+pub fn get_round(table_config: &TableConfig, deal: Option<Deal>) -> Result<Round, BindingError> {
+    let Some(deal) = deal else {
+        return Ok(Round::new(table_config));
+    };
+    let variant = table_config.rules.variant;
+    let mut deck = Deck::new().deck;
+    let talon_size = deck.len() - variant.get_number_of_players() * variant.get_hand_size();
+    let has_sizes = deal.hands.len() == variant.get_number_of_players()
+        && deal
+            .hands
+            .iter()
+            .all(|hand| hand.len() == variant.get_hand_size())
+        && deal.talon.len() == talon_size;
+    if !has_sizes {
+        return Err(BindingError::new(format!(
+            "every one of {} hands needs {} cards and the talon {}",
+            variant.get_number_of_players(),
+            variant.get_hand_size(),
+            talon_size
+        )));
+    }
+    let mut cards = deal.hands.concat();
+    cards.extend(deal.talon.iter().cloned());
+    cards.sort();
+    deck.sort();
+    if cards != deck {
+        return Err(BindingError::new(
+            "the hands and the talon need every card of the deck once".to_string(),
+        ));
+    }
+
+    Ok(Round::from_deal(table_config, &deal))
+}
+
+/// Plays the round with an agent for every seat, the first error of an
+/// agent is handed back once the round is over.
+pub fn play<T, E: From<BindingError>>(
+    agents: Vec<T>,
+    number_of_players: usize,
+    get_round_player: impl Fn(T, &AgentError<E>) -> Result<Box<dyn RoundPlayer>, E>,
+    play_round: impl FnOnce(Box<SeatedRoundPlayer>),
+) -> Result<(), E> {
+    if agents.len() != number_of_players {
+        return Err(BindingError::new(format!(
+            "{} agents can't play at a table of {}",
+            agents.len(),
+            number_of_players
+        ))
+        .into());
+    }
+    let error = AgentError::default();
+    let players = agents
+        .into_iter()
+        .map(|agent| get_round_player(agent, &error))
+        .collect::<Result<_, _>>()?;
+    play_round(Box::new(SeatedRoundPlayer::new(players)));
+
+    match error.borrow_mut().take() {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+pub fn get_team_points(points: &TeamPoints, teams: &[Team]) -> BTreeMap<&'static str, usize> {
+    teams
+        .iter()
+        .map(|team| ((*team).into(), points.get_points(*team)))
+        .collect()
+}
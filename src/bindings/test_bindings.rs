@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{
+        bindings::{
+            BindingError,
+            agent::{Agent, AgentError, get_agent_player, get_bot},
+            get_declarations, get_points,
+            round::{get_round, get_table_config, play},
+        },
+        clients::engine_protocol::Request,
+        game::{
+            deck::Deal,
+            round::Round,
+            round_observer::NullObserver,
+            rules::{RuleFlags, RuleSet},
+            table::Seat,
+        },
+    };
+
+    // answers with the first option, or with `answer` when it is set
+    #[derive(Clone, Default)]
+    struct TestAgent {
+        answer: Option<String>,
+        requests: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl Agent for TestAgent {
+        type Error = BindingError;
+
+        fn ask(
+            &self,
+            round_state: &Round,
+            seat: Seat,
+            request: &Request,
+        ) -> Result<String, BindingError> {
+            if request.is_trump_called() {
+                assert_eq!(round_state.current_trick.get_seat_turn(), seat);
+            }
+            self.requests.borrow_mut().push(request.to_line());
+            Ok(self
+                .answer
+                .clone()
+                .unwrap_or_else(|| request.get_options()[0].clone()))
+        }
+    }
+
+    // seats without a bot name are played by the agent
+    fn play_round(
+        round: &mut Round,
+        bots: &[Option<&str>],
+        agent: &TestAgent,
+    ) -> Result<(), BindingError> {
+        let number_of_players = round.get_number_of_players();
+        play(
+            bots.to_vec(),
+            number_of_players,
+            |bot, error: &AgentError<BindingError>| match bot {
+                Some(name) => get_bot(name),
+                None => Ok(get_agent_player(agent.clone(), error)),
+            },
+            |round_player| round.play_round(round_player, &NullObserver),
+        )
+    }
+
+    fn get_new_round(flags: RuleFlags, seed: u64) -> Round {
+        let table_config = get_table_config(RuleSet::from_flags(flags), Some(seed), None).unwrap();
+        get_round(&table_config, None).unwrap()
+    }
+
+    #[test]
+    fn test_agents_play_against_bots() {
+        let mut round = get_new_round(RuleFlags::default(), 1);
+        let agent = TestAgent::default();
+        let bots = [None, Some("heuristic"), None, Some("random")];
+        assert_eq!(play_round(&mut round, &bots, &agent), Ok(()));
+
+        assert!(!round.players.have_cards());
+        assert_eq!(round.trick_history.len(), 8);
+        let requests = agent.requests.borrow();
+        let cards = requests.iter().filter(|line| line.starts_with("card"));
+        assert_eq!(cards.count(), 16);
+    }
+
+    #[test]
+    fn test_agent_errors_are_handed_back_once_the_round_is_over() {
+        let mut round = get_new_round(RuleFlags::default(), 2);
+        let agent = TestAgent {
+            answer: Some("nonsense".to_string()),
+            ..TestAgent::default()
+        };
+        let bots = [None, Some("random"), Some("random"), Some("random")];
+        assert_eq!(
+            play_round(&mut round, &bots, &agent),
+            Err(BindingError::new(
+                "agent answered `nonsense` to `trump`".to_string()
+            ))
+        );
+        // the heuristic bot played for the agent after its first answer
+        assert!(!round.players.have_cards());
+        assert_eq!(agent.requests.borrow().len(), 1);
+    }
+
+    #[test]
+    fn test_wrong_agents_are_rejected() {
+        let agent = TestAgent::default();
+        let mut round = get_new_round(RuleFlags::default(), 3);
+        assert_eq!(
+            play_round(&mut round, &[Some("heuristic"); 3], &agent),
+            Err(BindingError::new(
+                "3 agents can't play at a table of 4".to_string()
+            ))
+        );
+        assert_eq!(
+            play_round(&mut round, &[Some("chess"); 4], &agent),
+            Err(BindingError::new(
+                "there is no `chess` bot, try random, heuristic or search".to_string()
+            ))
+        );
+        assert!(round.players.have_cards());
+
+        let rules = RuleSet::from_flags(RuleFlags {
+            three_players: true,
+            ..RuleFlags::default()
+        });
+        assert_eq!(
+            get_table_config(rules, None, Some(3)).err(),
+            Some(BindingError::new(
+                "there is no seat 3 at a table of 3".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_given_deals_are_played() {
+        let flags = RuleFlags {
+            three_players: true,
+            ..RuleFlags::default()
+        };
+        let dealt = get_new_round(flags, 4).deal;
+        let table_config = get_table_config(RuleSet::from_flags(flags), None, Some(0)).unwrap();
+        let mut round = get_round(&table_config, Some(dealt.clone())).unwrap();
+        assert_eq!(round.deal.hands, dealt.hands);
+        assert_eq!(round.talon, dealt.talon);
+        assert_eq!(round.current_trick.get_seat_turn(), Seat::new(1));
+
+        let bots = [None, Some("heuristic"), Some("search")];
+        assert_eq!(play_round(&mut round, &bots, &TestAgent::default()), Ok(()));
+        assert_eq!(round.trick_history.len(), 10);
+
+        let deal = Deal {
+            hands: vec![dealt.hands[0].clone(); 3],
+            talon: dealt.talon,
+        };
+        assert_eq!(
+            get_round(&table_config, Some(deal)).err(),
+            Some(BindingError::new(
+                "the hands and the talon need every card of the deck once".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_cards_are_scored_like_the_engine() {
+        let hand = ["Herz-VII", "Herz-VIII", "Herz-IX", "Leaf-Jack", "Herz-Jack"];
+        let hand = hand.map(String::from);
+        let declarations = get_declarations(&hand).unwrap();
+        let run = ["Herz-VII", "Herz-VIII", "Herz-IX"]
+            .map(String::from)
+            .to_vec();
+        assert!(declarations.contains(&(run, 20)));
+
+        assert_eq!(get_points("Herz-Jack", "Herz"), Ok(20));
+        assert_eq!(get_points("Herz-Jack", "Leaf"), Ok(2));
+        assert_eq!(
+            get_points("Herz-Joker", "Herz"),
+            Err(BindingError::new("`Herz-Joker` isn't a card".to_string()))
+        );
+        assert_eq!(
+            get_points("Herz-Jack", "Diamonds"),
+            Err(BindingError::new("`Diamonds` isn't a contract".to_string()))
+        );
+    }
+}
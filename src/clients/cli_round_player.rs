@@ -1,72 +1,251 @@
+use crate::game::auction::{Bid, get_all_contract_kinds, get_bid_for_contract_call};
 use crate::game::deck::{Card, CardSuit};
 use crate::game::player::Player;
-use crate::game::round::{Round, RoundUpdateEvent};
+use crate::game::round::Round;
 use crate::game::round_player::RoundPlayer;
-use crate::utils::console::{clear_console, print_current_points, wait_for_std_input};
-use crate::utils::random::get_random_suit;
-use rand::seq::SliceRandom;
+use crate::game::table::Seat;
+use crate::game::trump::ContractKind;
+use crate::render::Renderer;
+use crate::simulation::hint::{Hint, get_card_hints, get_contract_hints};
+use crate::utils::console::wait_for_std_input;
+use crate::utils::random::{RandomSource, get_random_suit};
+use rand::{Rng, seq::SliceRandom};
 use std::io;
 
-pub struct CliRoundPlayer;
+pub struct CliRoundPlayer {
+    /// Seat of the person at the terminal.
+    human_seat: Seat,
+    renderer: Renderer,
+    /// Deals simulated for a hint, hints are off without them.
+    hint_samples: Option<usize>,
+}
 
 impl CliRoundPlayer {
-    pub fn boxed() -> Box<dyn RoundPlayer> {
-        Box::new(Self)
+    pub fn boxed(
+        human_seat: Seat,
+        renderer: Renderer,
+        hint_samples: Option<usize>,
+    ) -> Box<dyn RoundPlayer> {
+        Box::new(Self {
+            human_seat,
+            renderer,
+            hint_samples,
+        })
+    }
+
+    fn is_hint_request(&self, input: &str) -> bool {
+        self.hint_samples.is_some() && matches!(input.trim(), "?" | "hint")
+    }
+
+    fn print_hint_help(&self) {
+        if self.hint_samples.is_some() {
+            println!("{}", self.renderer.language.hint_help());
+        }
+    }
+
+    /// Prints the options from the best to the worst.
+    fn print_hints<T>(&self, mut hints: Vec<Hint<T>>, get_name: impl Fn(&T) -> String) {
+        hints.sort_by(|first, second| second.points.get_mean().total_cmp(&first.points.get_mean()));
+        println!("{}", self.renderer.language.expected_points());
+        for hint in &hints {
+            println!(
+                "{:>7.1} ± {:<5.1} {}",
+                hint.points.get_mean(),
+                hint.points.get_margin(),
+                get_name(&hint.option)
+            );
+        }
+    }
+
+    fn print_contract_hints(&self, round_state: &Round, seat: Seat, shown_cards: &[Card]) {
+        let Some(samples) = self.hint_samples else {
+            return;
+        };
+        let hints = get_contract_hints(
+            round_state,
+            seat,
+            shown_cards,
+            &self.get_contract_kinds(round_state),
+            samples,
+            &mut RandomSource,
+        );
+        self.print_hints(hints, |contract| self.renderer.get_contract_name(contract));
     }
 
-    fn is_human_player(&self, player_index: usize) -> bool {
-        player_index == 0
+    fn read_input(&self) -> String {
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)
+            .expect("Failed to read line");
+        input
+    }
+
+    fn ask_yes_no(&self, question: &str) -> bool {
+        let language = self.renderer.language;
+        println!("{} {}", question, language.yes_no_hint());
+        language.is_yes(&self.read_input())
+    }
+
+    /// Shows the hand before asking the human about kontra or rekontra,
+    /// the other seats never double.
+    fn ask_doubling(&self, round_state: &Round, seat: Seat, question: &str) -> bool {
+        if !self.is_human_player(seat) {
+            return false;
+        }
+        self.print_player_cards(round_state.get_player(seat));
+        self.ask_yes_no(question)
+    }
+
+    fn is_human_player(&self, seat: Seat) -> bool {
+        self.human_seat == seat
     }
 
     fn print_player_cards(&self, player: &Player) {
-        println!("Your cards are:");
+        println!("{}", self.renderer.language.your_cards());
         for (i, card) in player.hand.cards().iter().enumerate() {
-            let card_suit: &str = card.suit.clone().into();
-            let card_value: &str = card.value.clone().into();
-            println!("{}. {} {}", i + 1, card_suit, card_value);
+            println!("{}. {}", i + 1, self.renderer.get_card_name(card));
         }
     }
 
     fn print_player_hand_for_card_play(&self, player: &Player, available_cards: &[Card]) {
-        println!("Your cards are:");
+        println!("{}", self.renderer.language.your_cards());
         for (i, card) in player.hand.cards().iter().enumerate() {
             let is_available = available_cards.contains(card);
             let marker = if is_available { "*" } else { " " };
-            let card_suit: &str = card.suit.clone().into();
-            let card_value: &str = card.value.clone().into();
-            println!("{}.{} {} {}", i + 1, marker, card_suit, card_value);
+            println!("{}.{} {}", i + 1, marker, self.renderer.get_card_name(card));
+        }
+    }
+
+    fn get_contract_kinds(&self, round_state: &Round) -> Vec<ContractKind> {
+        get_all_contract_kinds()
+            .into_iter()
+            .filter(|kind| {
+                round_state.rules.extended_contracts || matches!(kind, ContractKind::Suit(_))
+            })
+            .collect()
+    }
+
+    fn get_contract_options(&self, round_state: &Round) -> String {
+        self.get_contract_kinds(round_state)
+            .iter()
+            .map(|kind| self.renderer.get_contract_name(kind))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn parse_contract(&self, round_state: &Round, input: &str) -> Option<ContractKind> {
+        self.renderer
+            .parse_contract(input, &self.get_contract_kinds(round_state))
+    }
+
+    fn prompt_for_trump_call(&self, round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        let player = round_state.get_player(seat);
+        let mut hand_clone = player.hand.cards().clone();
+        hand_clone.shuffle(&mut RandomSource);
+
+        let (hidden_cards, shown_cards) = hand_clone.split_at(2);
+
+        let mut sorted_shown_cards = shown_cards.to_vec();
+        sorted_shown_cards.sort_by_key(|card| (card.suit.clone(), card.value.clone()));
+
+        let language = self.renderer.language;
+        println!("{}", language.your_cards_with_hidden());
+        for (i, card) in sorted_shown_cards.iter().enumerate() {
+            println!("{}. {}", i + 1, self.renderer.get_card_name(card));
+        }
+
+        loop {
+            println!(
+                "{} ({}), {}:",
+                language.choose_trump(),
+                self.get_contract_options(round_state),
+                language.type_to_pass()
+            );
+            self.print_hint_help();
+            let input = self.read_input().trim().to_lowercase();
+            if self.is_hint_request(&input) {
+                self.print_contract_hints(round_state, seat, &sorted_shown_cards);
+                continue;
+            }
+            let trump = match self.parse_contract(round_state, &input) {
+                Some(contract) => Some(contract),
+                None if input == language.pass().to_lowercase() || input == "dalje" => None,
+                None => {
+                    println!("{}", language.invalid_input());
+                    continue;
+                }
+            };
+
+            println!("{}", language.hidden_cards_were());
+            for card in hidden_cards {
+                println!("{}", self.renderer.get_card_name(card));
+            }
+            wait_for_std_input();
+
+            return trump;
+        }
+    }
+
+    fn prompt_for_forced_trump_call(&self, round_state: &Round, seat: Seat) -> ContractKind {
+        let player = round_state.get_player(seat);
+        self.print_player_cards(player);
+        let language = self.renderer.language;
+        loop {
+            println!(
+                "{} ({}):",
+                language.must_choose_trump(),
+                self.get_contract_options(round_state)
+            );
+            self.print_hint_help();
+            let input = self.read_input();
+            if self.is_hint_request(&input) {
+                self.print_contract_hints(round_state, seat, player.hand.cards());
+                continue;
+            }
+            match self.parse_contract(round_state, &input) {
+                Some(contract) => return contract,
+                None => println!("{}", language.invalid_input()),
+            }
         }
     }
 
     fn prompt_for_card_selection(
         &self,
-        player_hand_cards: &[Card],
+        round_state: &Round,
+        seat: Seat,
         available_cards: &[Card],
     ) -> Card {
+        let player_hand_cards = round_state.get_player(seat).hand.cards();
+        let language = self.renderer.language;
         loop {
-            println!("Please select a card to play (choose a number for a card marked with '*'):");
-
-            let mut input = String::new();
-            io::stdin()
-                .read_line(&mut input)
-                .expect("Failed to read line");
+            println!("{}", language.choose_marked_card());
+            self.print_hint_help();
 
+            let input = self.read_input();
+            if let Some(samples) = self.hint_samples
+                && self.is_hint_request(&input)
+            {
+                let hints = get_card_hints(
+                    round_state,
+                    seat,
+                    available_cards,
+                    samples,
+                    &mut RandomSource,
+                );
+                self.print_hints(hints, |card| self.renderer.get_card_name(card));
+                continue;
+            }
             match input.trim().parse::<usize>() {
                 Ok(n) if n > 0 && n <= player_hand_cards.len() => {
                     let selected_card = &player_hand_cards[n - 1];
                     if available_cards.contains(selected_card) {
                         return selected_card.clone();
                     } else {
-                        println!(
-                            "You cannot play that card. Please select one of the available cards."
-                        );
+                        println!("{}", language.card_not_playable());
                     }
                 }
                 _ => {
-                    println!(
-                        "Invalid input. Please enter a number between 1 and {}.",
-                        player_hand_cards.len()
-                    );
+                    println!("{}", language.enter_number(player_hand_cards.len()));
                 }
             }
         }
@@ -74,160 +253,75 @@ impl CliRoundPlayer {
 }
 
 impl RoundPlayer for CliRoundPlayer {
-    fn on_update(&self, round_state: &Round, round_event: RoundUpdateEvent) {
-        match round_event {
-            RoundUpdateEvent::CardPlayed { player_index, card } => {
-                let player = round_state.get_player_by_index(player_index);
-                let card_suit: &str = card.suit.clone().into();
-                let card_value: &str = card.value.clone().into();
-                println!("Player {} played {} {}", player.name, card_suit, card_value);
-                wait_for_std_input();
-            }
-            RoundUpdateEvent::DeclarationsCalled(declarations) => {
-                for declaration in declarations {
-                    let player_index = declaration.player_index;
-                    let declaration = &declaration.declaration;
-                    let player = round_state.get_player_by_index(player_index);
-                    println!("{} declared: ", player.name);
-                    println!("{:#?}", declaration);
-                    println!("");
-                }
-                wait_for_std_input();
-                clear_console();
-                print_current_points(round_state);
-            }
-            RoundUpdateEvent::TrumpCallEvent {
-                player_index,
-                trump,
-            } => {
-                if player_index == round_state.player_turn_index {
-                    clear_console();
-                }
-                let player = round_state.get_player_by_index(player_index);
-                let color: &str = match trump {
-                    Some(trump) => trump.trump_suit.clone().into(),
-                    None => "Dalje",
-                };
-                println!("Player {} zove {}", player.name, color);
-                wait_for_std_input();
-                if let Some(_) = trump {
-                    clear_console();
-                    print_current_points(round_state);
-                }
-            }
-            RoundUpdateEvent::BelaDeclared { player_index } => {
-                let player = round_state.get_player_by_index(player_index);
-                println!("{} called BELA!!!", player.name);
-                wait_for_std_input();
-            }
-            RoundUpdateEvent::TrickDone(trick_item) => {
-                let points = trick_item.get_points();
-                let team_winner: &str = trick_item.get_winner_team().clone().into();
-                let player = round_state.get_player_by_index(trick_item.get_winner_index());
-
-                println!(
-                    "{} Won trick for team {} with {} points",
-                    player.name, team_winner, points
-                );
-                wait_for_std_input();
-                clear_console();
-                print_current_points(round_state);
-            }
+    fn try_call_trump(&self, round_state: &Round, seat: Seat) -> Option<CardSuit> {
+        if self.is_human_player(seat) {
+            self.prompt_for_trump_call(round_state, seat)
+                .and_then(|contract| match contract {
+                    ContractKind::Suit(suit) => Some(suit),
+                    _ => None,
+                })
+        } else if RandomSource.random::<bool>() {
+            Some(get_random_suit())
+        } else {
+            None
         }
     }
 
-    fn try_call_trump(&self, round_state: &Round, player_index: usize) -> Option<CardSuit> {
-        if self.is_human_player(player_index) {
-            let player = round_state.get_player_by_index(player_index);
-            let mut hand_clone = player.hand.cards().clone();
-            hand_clone.shuffle(&mut rand::rng());
-
-            let (hidden_cards, shown_cards) = hand_clone.split_at(2);
-
-            let mut sorted_shown_cards = shown_cards.to_vec();
-            sorted_shown_cards.sort_by_key(|card| (card.suit.clone(), card.value.clone()));
-
-            println!("Your cards (two are hidden):");
-            for (i, card) in sorted_shown_cards.iter().enumerate() {
-                let card_suit: &str = card.suit.clone().into();
-                let card_value: &str = card.value.clone().into();
-                println!("{}. {} {}", i + 1, card_suit, card_value);
+    fn must_call_trump(&self, round_state: &Round, seat: Seat) -> CardSuit {
+        if self.is_human_player(seat) {
+            match self.prompt_for_forced_trump_call(round_state, seat) {
+                ContractKind::Suit(suit) => suit,
+                _ => get_random_suit(),
             }
+        } else {
+            get_random_suit()
+        }
+    }
 
-            loop {
-                println!(
-                    "Please choose a trump suit (Leaf, Pumpkin, Herz, Acorn), or type 'Dalje' to pass:"
-                );
-                let mut input = String::new();
-                io::stdin()
-                    .read_line(&mut input)
-                    .expect("Failed to read line");
-
-                let trump = match input.trim().to_lowercase().as_str() {
-                    "leaf" => Some(CardSuit::Leaf),
-                    "pumpkin" => Some(CardSuit::Pumpkin),
-                    "herz" => Some(CardSuit::Herz),
-                    "acorn" => Some(CardSuit::Acorn),
-                    "dalje" => None,
-                    _ => {
-                        println!("Invalid input. Please try again.");
-                        continue;
-                    }
-                };
-
-                println!("Your hidden cards were:");
-                for card in hidden_cards {
-                    let card_suit: &str = card.suit.clone().into();
-                    let card_value: &str = card.value.clone().into();
-                    println!("{} {}", card_suit, card_value);
-                }
-                wait_for_std_input();
+    fn try_call_contract(&self, round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        if self.is_human_player(seat) {
+            self.prompt_for_trump_call(round_state, seat)
+        } else {
+            self.try_call_trump(round_state, seat)
+                .map(ContractKind::Suit)
+        }
+    }
 
-                return trump;
-            }
+    fn must_call_contract(&self, round_state: &Round, seat: Seat) -> ContractKind {
+        if self.is_human_player(seat) {
+            self.prompt_for_forced_trump_call(round_state, seat)
         } else {
-            if rand::random::<bool>() {
-                Some(get_random_suit())
-            } else {
-                None
-            }
+            ContractKind::Suit(get_random_suit())
         }
     }
 
-    fn must_call_trump(&self, round_state: &Round, player_index: usize) -> CardSuit {
-        if self.is_human_player(player_index) {
-            let player = round_state.get_player_by_index(player_index);
-            self.print_player_cards(player);
-            loop {
-                println!("You must choose a trump suit (Leaf, Pumpkin, Herz, Acorn):");
-                let mut input = String::new();
-                io::stdin()
-                    .read_line(&mut input)
-                    .expect("Failed to read line");
-
-                match input.trim().to_lowercase().as_str() {
-                    "leaf" => return CardSuit::Leaf,
-                    "pumpkin" => return CardSuit::Pumpkin,
-                    "herz" => return CardSuit::Herz,
-                    "acorn" => return CardSuit::Acorn,
-                    _ => println!("Invalid trump suit. Please try again."),
-                }
+    fn make_bid(&self, round_state: &Round, seat: Seat, valid_bids: Vec<Bid>) -> Bid {
+        if !self.is_human_player(seat) {
+            let contract = self.try_call_contract(round_state, seat);
+            return get_bid_for_contract_call(contract, &valid_bids);
+        }
+
+        let player = round_state.get_player(seat);
+        self.print_player_cards(player);
+        let language = self.renderer.language;
+        println!("{}", language.your_bids());
+        for (i, bid) in valid_bids.iter().enumerate() {
+            println!("{}. {}", i + 1, self.renderer.get_bid_name(bid));
+        }
+        loop {
+            println!("{}:", language.make_bid());
+            match self.read_input().trim().parse::<usize>() {
+                Ok(n) if n > 0 && n <= valid_bids.len() => return valid_bids[n - 1].clone(),
+                _ => println!("{}", language.enter_number(valid_bids.len())),
             }
-        } else {
-            get_random_suit()
         }
     }
 
-    fn play_card(
-        &self,
-        round_state: &Round,
-        player_index: usize,
-        available_cards: Vec<Card>,
-    ) -> Card {
-        if self.is_human_player(player_index) {
-            let player = round_state.get_player_by_index(player_index);
+    fn play_card(&self, round_state: &Round, seat: Seat, available_cards: Vec<Card>) -> Card {
+        if self.is_human_player(seat) {
+            let player = round_state.get_player(seat);
             self.print_player_hand_for_card_play(player, &available_cards);
-            self.prompt_for_card_selection(player.hand.cards(), &available_cards)
+            self.prompt_for_card_selection(round_state, seat, &available_cards)
         } else {
             available_cards[0].clone()
         }
@@ -236,32 +330,35 @@ impl RoundPlayer for CliRoundPlayer {
     fn call_declaration(
         &self,
         _round_state: &Round,
-        player_index: usize,
+        seat: Seat,
         declaration: &crate::game::declaration::Declaration,
     ) -> bool {
-        if self.is_human_player(player_index) {
-            println!("You have a declaration: {:#?}", declaration);
-            println!("Do you want to declare it? (y/n)");
-            let mut input = String::new();
-            io::stdin()
-                .read_line(&mut input)
-                .expect("Failed to read line");
-            input.trim().to_lowercase() == "y"
+        if self.is_human_player(seat) {
+            let cards = self.renderer.get_card_names(&declaration.cards);
+            self.ask_yes_no(
+                &self
+                    .renderer
+                    .language
+                    .ask_declaration(&cards, declaration.points),
+            )
         } else {
             true
         }
     }
 
-    fn will_declare_bella(&self, _round_state: &Round, player_index: usize) -> bool {
-        if self.is_human_player(player_index) {
-            println!("Do you want to declare bela? (y/n)");
-            let mut input = String::new();
-            io::stdin()
-                .read_line(&mut input)
-                .expect("Failed to read line");
-            input.trim().to_lowercase() == "y"
+    fn will_declare_bella(&self, _round_state: &Round, seat: Seat) -> bool {
+        if self.is_human_player(seat) {
+            self.ask_yes_no(self.renderer.language.ask_bela())
         } else {
             true
         }
     }
+
+    fn will_call_kontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.ask_doubling(round_state, seat, self.renderer.language.ask_kontra())
+    }
+
+    fn will_call_rekontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.ask_doubling(round_state, seat, self.renderer.language.ask_rekontra())
+    }
 }
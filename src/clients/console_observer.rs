@@ -0,0 +1,50 @@
+use crate::{
+    clients::log_observer::get_event_description,
+    game::{
+        round::{Round, RoundUpdateEvent},
+        round_observer::RoundObserver,
+    },
+    render::Renderer,
+    utils::console::{clear_console, print_current_points, wait_for_std_input},
+};
+
+/// Shows the round in the terminal and waits for enter after every event,
+/// so a person can follow the game.
+#[derive(Debug, Default)]
+pub struct ConsoleObserver {
+    renderer: Renderer,
+}
+
+impl ConsoleObserver {
+    pub fn new(renderer: Renderer) -> Self {
+        Self { renderer }
+    }
+}
+
+impl RoundObserver for ConsoleObserver {
+    fn on_update(&self, round_state: &Round, round_event: RoundUpdateEvent) {
+        if let RoundUpdateEvent::TrumpCallEvent { seat, .. } = &round_event
+            && *seat == round_state.seat_turn
+        {
+            clear_console();
+        }
+        let show_points = match &round_event {
+            RoundUpdateEvent::TrumpCallEvent { trump, .. } => trump.is_some(),
+            RoundUpdateEvent::DeclarationsCalled(_) | RoundUpdateEvent::TrickDone(_) => true,
+            _ => false,
+        };
+        let is_redealt = matches!(round_event, RoundUpdateEvent::Redealt);
+
+        println!(
+            "{}",
+            get_event_description(&self.renderer, round_state, round_event)
+        );
+        wait_for_std_input();
+        if show_points || is_redealt {
+            clear_console();
+        }
+        if show_points {
+            print_current_points(&self.renderer, round_state);
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! Line protocol spoken with bots that run as separate processes, in the
+//! spirit of UCI for chess engines. The engine writes one message per line
+//! to the standard input of the bot and reads answers from its standard
+//! output, words on a line are separated by single spaces.
+//!
+//! ```text
+//! belot 1                     greeting, the bot answers `ready`
+//! newround <seat> <teams>     a new deal, <teams> has the team of every seat
+//! hand <cards>                cards dealt to the bot
+//! event <event>               something happened at the table
+//! ask <request>               the bot has to decide, it answers with one line
+//! quit                        the bot should exit
+//! ```
+//!
+//! Seats count from 0. Cards, contracts and bids use the names of round
+//! records, for example `Herz-Jack`, `Acorn`, `AllTrumps` or `Dalje`.
+//! `newround` is sent again when everybody passed in the auction and the
+//! cards were dealt again.
+//!
+//! Events tell the bot what happened since it was last asked:
+//!
+//! ```text
+//! event bid <seat> <bid>
+//! event pass <seat>               a seat didn't call trump
+//! event trump <seat> <contract>   contract of the round and who called it
+//! event doubling <Kontra|Rekontra>
+//! event declaration <seat> <cards>
+//! event card <seat> <card>
+//! event bela <seat>
+//! event trick <seat> <points>     winner of the trick and its card points
+//! ```
+//!
+//! Requests and the answers they expect:
+//!
+//! ```text
+//! ask trump                   a suit or `pass`
+//! ask trump forced            a suit
+//! ask contract                a contract or `pass`
+//! ask contract forced         a contract
+//! ask bid <bids>              one of the bids
+//! ask declaration <cards>     `yes` or `no`
+//! ask kontra                  `yes` or `no`
+//! ask rekontra                `yes` or `no`
+//! ask bela                    `yes` or `no`, the bela card was just played
+//! ask card <cards>            one of the cards
+//! ```
+
+use strum::IntoEnumIterator;
+
+use crate::game::{
+    auction::{Bid, get_all_contract_kinds},
+    deck::{Card, CardSuit, get_card_names},
+    declaration::Declaration,
+    kontra::Doubling,
+    table::Seat,
+    team::Team,
+    trump::ContractKind,
+};
+
+pub const PROTOCOL_VERSION: usize = 1;
+
+/// Something that happened at the table.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TableEvent {
+    Bid { seat: Seat, bid: Bid },
+    Pass { seat: Seat },
+    Trump { seat: Seat, contract: ContractKind },
+    Doubling(Doubling),
+    Declaration { seat: Seat, cards: Vec<Card> },
+    Card { seat: Seat, card: Card },
+    Bela { seat: Seat },
+    Trick { seat: Seat, points: usize },
+}
+
+/// Decision the bot is asked to make.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Request {
+    Trump,
+    ForcedTrump,
+    Contract,
+    ForcedContract,
+    Bid(Vec<Bid>),
+    Declaration(Declaration),
+    Kontra,
+    Rekontra,
+    Bela,
+    Card(Vec<Card>),
+}
+
+/// Answer to a request, only answers the request allows are parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    /// Called contract, `None` is a pass.
+    Contract(Option<ContractKind>),
+    Bid(Bid),
+    Card(Card),
+    YesNo(bool),
+}
+
+/// Line the engine sends to the bot.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum EngineMessage {
+    Hello,
+    NewRound { seat: Seat, teams: Vec<Team> },
+    Hand(Vec<Card>),
+    Event(TableEvent),
+    Ask(Request),
+    Quit,
+}
+
+fn get_bid_names(bids: &[Bid]) -> String {
+    bids.iter().map(Bid::get_name).collect::<Vec<_>>().join(" ")
+}
+
+impl TableEvent {
+    pub fn to_line(&self) -> String {
+        match self {
+            Self::Bid { seat, bid } => format!("bid {} {}", seat.index(), bid.get_name()),
+            Self::Pass { seat } => format!("pass {}", seat.index()),
+            Self::Trump { seat, contract } => {
+                format!("trump {} {}", seat.index(), contract.get_name())
+            }
+            Self::Doubling(doubling) => {
+                let name: &str = (*doubling).into();
+                format!("doubling {}", name)
+            }
+            Self::Declaration { seat, cards } => {
+                format!("declaration {} {}", seat.index(), get_card_names(cards))
+            }
+            Self::Card { seat, card } => format!("card {} {}", seat.index(), card.get_name()),
+            Self::Bela { seat } => format!("bela {}", seat.index()),
+            Self::Trick { seat, points } => format!("trick {} {}", seat.index(), points),
+        }
+    }
+}
+
+impl Request {
+    pub fn to_line(&self) -> String {
+        match self {
+            Self::Trump => "trump".to_string(),
+            Self::ForcedTrump => "trump forced".to_string(),
+            Self::Contract => "contract".to_string(),
+            Self::ForcedContract => "contract forced".to_string(),
+            Self::Bid(bids) => format!("bid {}", get_bid_names(bids)),
+            Self::Declaration(declaration) => {
+                format!("declaration {}", get_card_names(&declaration.cards))
+            }
+            Self::Kontra => "kontra".to_string(),
+            Self::Rekontra => "rekontra".to_string(),
+            Self::Bela => "bela".to_string(),
+            Self::Card(cards) => format!("card {}", get_card_names(cards)),
+        }
+    }
+
+    /// First word of the request line.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Trump | Self::ForcedTrump => "trump",
+            Self::Contract | Self::ForcedContract => "contract",
+            Self::Bid(_) => "bid",
+            Self::Declaration(_) => "declaration",
+            Self::Kontra => "kontra",
+            Self::Rekontra => "rekontra",
+            Self::Bela => "bela",
+            Self::Card(_) => "card",
+        }
+    }
+
+    /// Whether trump was called before the request, the auction requests
+    /// come before it.
+    pub fn is_trump_called(&self) -> bool {
+        !matches!(
+            self,
+            Self::Trump | Self::ForcedTrump | Self::Contract | Self::ForcedContract | Self::Bid(_)
+        )
+    }
+
+    /// Every answer the request allows.
+    pub fn get_options(&self) -> Vec<String> {
+        let suits = CardSuit::iter().map(|suit| {
+            let name: &str = suit.into();
+            name.to_string()
+        });
+        let contracts = get_all_contract_kinds()
+            .into_iter()
+            .map(|kind| kind.get_name().to_string());
+        let pass = ["pass".to_string()];
+        match self {
+            Self::Trump => suits.chain(pass).collect(),
+            Self::ForcedTrump => suits.collect(),
+            Self::Contract => contracts.chain(pass).collect(),
+            Self::ForcedContract => contracts.collect(),
+            Self::Bid(bids) => bids.iter().map(|bid| bid.get_name().to_string()).collect(),
+            Self::Declaration(_) | Self::Kontra | Self::Rekontra | Self::Bela => {
+                vec!["yes".to_string(), "no".to_string()]
+            }
+            Self::Card(cards) => cards.iter().map(Card::get_name).collect(),
+        }
+    }
+
+    /// Reads the answer of the bot, `None` when it isn't one of the
+    /// answers this request allows.
+    pub fn parse_answer(&self, answer: &str) -> Option<Answer> {
+        let answer = answer.trim();
+        match self {
+            Self::Trump | Self::ForcedTrump => {
+                if answer == "pass" {
+                    return (*self == Self::Trump).then_some(Answer::Contract(None));
+                }
+                let suit = CardSuit::iter().find(|suit| {
+                    let name: &str = suit.clone().into();
+                    name == answer
+                })?;
+                Some(Answer::Contract(Some(ContractKind::Suit(suit))))
+            }
+            Self::Contract | Self::ForcedContract => {
+                if answer == "pass" {
+                    return (*self == Self::Contract).then_some(Answer::Contract(None));
+                }
+                let kind = ContractKind::from_name(answer)?;
+                Some(Answer::Contract(Some(kind)))
+            }
+            Self::Bid(bids) => bids
+                .iter()
+                .find(|bid| bid.get_name() == answer)
+                .map(|bid| Answer::Bid(bid.clone())),
+            Self::Declaration(_) | Self::Kontra | Self::Rekontra | Self::Bela => match answer {
+                "yes" => Some(Answer::YesNo(true)),
+                "no" => Some(Answer::YesNo(false)),
+                _ => None,
+            },
+            Self::Card(cards) => {
+                let card = Card::from_name(answer)?;
+                cards.contains(&card).then_some(Answer::Card(card))
+            }
+        }
+    }
+}
+
+impl EngineMessage {
+    pub fn to_line(&self) -> String {
+        match self {
+            Self::Hello => format!("belot {}", PROTOCOL_VERSION),
+            Self::NewRound { seat, teams } => {
+                let teams = teams
+                    .iter()
+                    .map(|team| {
+                        let name: &str = (*team).into();
+                        name
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                format!("newround {} {}", seat.index(), teams)
+            }
+            Self::Hand(cards) => format!("hand {}", get_card_names(cards)),
+            Self::Event(event) => format!("event {}", event.to_line()),
+            Self::Ask(request) => format!("ask {}", request.to_line()),
+            Self::Quit => "quit".to_string(),
+        }
+    }
+}
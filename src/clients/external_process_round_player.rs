@@ -0,0 +1,433 @@
+use std::{
+    cell::RefCell,
+    error::Error,
+    fmt,
+    io::{self, BufRead, BufReader, Write},
+    process::{Child, ChildStdin, Command, Stdio},
+    rc::Rc,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::game::{deck::Card, round::Round, round_player::RoundPlayer, table::Seat};
+
+use super::{
+    engine_protocol::{Answer, EngineMessage, Request, TableEvent},
+    protocol_round_player::{ProtocolRoundPlayer, RequestAnswerer},
+};
+
+/// How long a bot may think about one decision.
+pub const DEFAULT_ANSWER_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long a bot gets to start and answer the greeting, at least as long
+/// as it gets for a decision.
+const START_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long a bot gets to exit after `quit` before it is killed.
+const QUIT_TIMEOUT: Duration = Duration::from_millis(200);
+
+#[derive(Debug)]
+pub enum ExternalProcessError {
+    Io(io::Error),
+    /// The bot closed its output, usually because it crashed.
+    Exited,
+    Timeout {
+        request: String,
+    },
+    InvalidAnswer {
+        request: String,
+        answer: String,
+    },
+}
+
+impl fmt::Display for ExternalProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "bot process failed: {}", error),
+            Self::Exited => write!(f, "bot process exited"),
+            Self::Timeout { request } => write!(f, "bot didn't answer `{}` in time", request),
+            Self::InvalidAnswer { request, answer } => {
+                write!(f, "bot answered `{}` to `{}`", answer, request)
+            }
+        }
+    }
+}
+
+impl Error for ExternalProcessError {}
+
+impl From<io::Error> for ExternalProcessError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Running bot, its output is read on a separate thread so reading can
+/// time out.
+struct BotProcess {
+    child: Child,
+    stdin: ChildStdin,
+    lines: Receiver<String>,
+    timeout: Duration,
+}
+
+impl BotProcess {
+    fn spawn(mut command: Command, timeout: Duration) -> Result<Self, ExternalProcessError> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin should be piped");
+        let stdout = child.stdout.take().expect("stdout should be piped");
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut process = Self {
+            child,
+            stdin,
+            lines,
+            timeout,
+        };
+        let greeting = EngineMessage::Hello.to_line();
+        process.send(&greeting)?;
+        let answer = process.receive(&greeting, timeout.max(START_TIMEOUT))?;
+        if answer.trim() != "ready" {
+            return Err(ExternalProcessError::InvalidAnswer {
+                request: greeting,
+                answer,
+            });
+        }
+
+        Ok(process)
+    }
+
+    fn send(&mut self, line: &str) -> Result<(), ExternalProcessError> {
+        writeln!(self.stdin, "{}", line)?;
+        self.stdin.flush()?;
+        Ok(())
+    }
+
+    fn receive(
+        &mut self,
+        request: &str,
+        timeout: Duration,
+    ) -> Result<String, ExternalProcessError> {
+        match self.lines.recv_timeout(timeout) {
+            Ok(line) => Ok(line),
+            Err(RecvTimeoutError::Timeout) => Err(ExternalProcessError::Timeout {
+                request: request.to_string(),
+            }),
+            Err(RecvTimeoutError::Disconnected) => Err(ExternalProcessError::Exited),
+        }
+    }
+}
+
+impl Drop for BotProcess {
+    fn drop(&mut self) {
+        let _ = self.send(&EngineMessage::Quit.to_line());
+        let started = Instant::now();
+        while started.elapsed() < QUIT_TIMEOUT {
+            if let Ok(Some(_)) = self.child.try_wait() {
+                return;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Part of the round a request belongs to, it decides which events the
+/// bot can already be told about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Bidding,
+    TrumpCall(Seat),
+    Doubling,
+    Declarations,
+    CardPlay,
+}
+
+fn get_stage(request: &Request, seat: Seat) -> Stage {
+    match request {
+        Request::Bid(_) => Stage::Bidding,
+        Request::Trump | Request::ForcedTrump | Request::Contract | Request::ForcedContract => {
+            Stage::TrumpCall(seat)
+        }
+        Request::Kontra | Request::Rekontra => Stage::Doubling,
+        Request::Declaration(_) => Stage::Declarations,
+        Request::Bela | Request::Card(_) => Stage::CardPlay,
+    }
+}
+
+/// What the bot was already told about the round it plays. Events are
+/// found by comparing the round with it before every request, the same
+/// way for any table and any observer.
+#[derive(Debug, Default)]
+struct ToldState {
+    seat: Option<Seat>,
+    deal: Vec<Card>,
+    cards_played: usize,
+    bids: usize,
+    passes: usize,
+    trump: bool,
+    doubling: bool,
+    declarations: bool,
+    card_events: usize,
+}
+
+fn get_cards_played(round_state: &Round) -> usize {
+    round_state
+        .trick_history
+        .iter()
+        .map(|item| item.trick.cards_on_table.len())
+        .sum::<usize>()
+        + round_state.current_trick.cards_on_table.len()
+}
+
+/// Cards, bela announcements and finished tricks in the order they
+/// happened.
+fn get_card_events(round_state: &Round) -> Vec<TableEvent> {
+    let number_of_players = round_state.get_number_of_players();
+    let tricks = round_state
+        .trick_history
+        .iter()
+        .map(|item| (&item.trick, Some(item)))
+        .chain([(&round_state.current_trick, None)]);
+    let mut events = vec![];
+    for (trick, history_item) in tricks {
+        for (offset, card) in trick.cards_on_table.iter().enumerate() {
+            let seat = trick.get_first_seat().offset(offset, number_of_players);
+            events.push(TableEvent::Card {
+                seat,
+                card: card.clone(),
+            });
+            let has_bela = round_state
+                .bela_announcements
+                .iter()
+                .any(|announcement| announcement.seat == seat && announcement.card == *card);
+            if has_bela {
+                events.push(TableEvent::Bela { seat });
+            }
+        }
+        if let Some(item) = history_item {
+            events.push(TableEvent::Trick {
+                seat: item.winner_seat,
+                points: item.points,
+            });
+        }
+    }
+
+    events
+}
+
+impl ToldState {
+    /// Messages that bring the bot up to date before it gets a request.
+    fn get_messages(
+        &mut self,
+        round_state: &Round,
+        seat: Seat,
+        stage: Stage,
+    ) -> Vec<EngineMessage> {
+        let mut messages = vec![];
+        let dealt_cards = &round_state.deal.hands[seat.index()];
+        let cards_played = get_cards_played(round_state);
+        if self.seat != Some(seat) || self.deal != *dealt_cards || cards_played < self.cards_played
+        {
+            *self = Self {
+                seat: Some(seat),
+                deal: dealt_cards.clone(),
+                ..Default::default()
+            };
+            messages.push(EngineMessage::NewRound {
+                seat,
+                teams: round_state.players.get_seat_teams(),
+            });
+            messages.push(EngineMessage::Hand(dealt_cards.clone()));
+        }
+        self.cards_played = cards_played;
+
+        let events = self.get_events(round_state, stage);
+        messages.extend(events.into_iter().map(EngineMessage::Event));
+        messages
+    }
+
+    fn get_events(&mut self, round_state: &Round, stage: Stage) -> Vec<TableEvent> {
+        let mut events = vec![];
+        let rules = &round_state.rules;
+        if rules.auction {
+            let bids = &round_state.auction.bids;
+            events.extend(
+                bids[self.bids.min(bids.len())..]
+                    .iter()
+                    .map(|item| TableEvent::Bid {
+                        seat: item.seat,
+                        bid: item.bid.clone(),
+                    }),
+            );
+            self.bids = bids.len();
+        } else {
+            // seats before the one that called trump passed, in turn from
+            // the first seat of the round
+            let number_of_players = round_state.get_number_of_players();
+            let first_seat = round_state
+                .trick_history
+                .first()
+                .map(|item| item.trick.get_first_seat())
+                .unwrap_or(round_state.current_trick.get_first_seat());
+            let last_seat = match stage {
+                Stage::Bidding => first_seat,
+                Stage::TrumpCall(seat) => seat,
+                _ => round_state.trump.seat,
+            };
+            let passes =
+                (last_seat.index() + number_of_players - first_seat.index()) % number_of_players;
+            events.extend((self.passes..passes).map(|offset| TableEvent::Pass {
+                seat: first_seat.offset(offset, number_of_players),
+            }));
+            self.passes = self.passes.max(passes);
+        }
+        if matches!(stage, Stage::Bidding | Stage::TrumpCall(_)) {
+            return events;
+        }
+
+        if !self.trump {
+            events.push(TableEvent::Trump {
+                seat: round_state.trump.seat,
+                contract: round_state.trump.kind.clone(),
+            });
+            self.trump = true;
+        }
+        if stage == Stage::Doubling {
+            return events;
+        }
+
+        if !rules.auction && !self.doubling && round_state.doubling != Default::default() {
+            events.push(TableEvent::Doubling(round_state.doubling));
+            self.doubling = true;
+        }
+        if stage == Stage::Declarations {
+            return events;
+        }
+
+        if !self.declarations {
+            let declarations = round_state.team_declarations.declarations.iter().flatten();
+            events.extend(declarations.map(|item| TableEvent::Declaration {
+                seat: item.seat,
+                cards: item.declaration.cards.clone(),
+            }));
+            self.declarations = true;
+        }
+        let card_events = get_card_events(round_state);
+        self.card_events = self.card_events.min(card_events.len());
+        events.extend(card_events[self.card_events..].iter().cloned());
+        self.card_events = card_events.len();
+
+        events
+    }
+}
+
+struct Connection {
+    /// `None` once the bot misbehaved and was stopped.
+    process: Option<BotProcess>,
+    told: ToldState,
+    name: String,
+}
+
+impl Connection {
+    fn ask(
+        &mut self,
+        round_state: &Round,
+        seat: Seat,
+        stage: Stage,
+        request: Request,
+    ) -> Option<Answer> {
+        let process = self.process.as_mut()?;
+        let messages = self.told.get_messages(round_state, seat, stage);
+        match Self::exchange(process, messages, &request) {
+            Ok(answer) => Some(answer),
+            Err(error) => {
+                eprintln!("{}: {}, the fallback plays from now on", self.name, error);
+                self.process = None;
+                None
+            }
+        }
+    }
+
+    fn exchange(
+        process: &mut BotProcess,
+        messages: Vec<EngineMessage>,
+        request: &Request,
+    ) -> Result<Answer, ExternalProcessError> {
+        for message in messages {
+            process.send(&message.to_line())?;
+        }
+        let request_line = EngineMessage::Ask(request.clone()).to_line();
+        process.send(&request_line)?;
+        let timeout = process.timeout;
+        let answer = process.receive(&request_line, timeout)?;
+
+        request
+            .parse_answer(&answer)
+            .ok_or(ExternalProcessError::InvalidAnswer {
+                request: request_line,
+                answer,
+            })
+    }
+}
+
+/// Bot running as a separate process, clones share the process so one bot
+/// keeps its process for a whole match.
+#[derive(Clone)]
+pub struct ExternalProcess {
+    connection: Rc<RefCell<Connection>>,
+}
+
+impl RequestAnswerer for ExternalProcess {
+    fn answer(&self, round_state: &Round, seat: Seat, request: Request) -> Option<Answer> {
+        self.connection
+            .borrow_mut()
+            .ask(round_state, seat, get_stage(&request, seat), request)
+    }
+}
+
+/// Lets a bot running as a separate process play, see
+/// [`engine_protocol`](super::engine_protocol) for what is said to it. A
+/// bot that crashes, takes too long or gives an answer it wasn't offered is
+/// stopped, and the fallback player decides for it from then on.
+pub type ExternalProcessRoundPlayer = ProtocolRoundPlayer<ExternalProcess>;
+
+impl ExternalProcessRoundPlayer {
+    /// Starts the bot and waits until it answers the greeting, which may
+    /// take longer than the `timeout` of a decision. Standard input and
+    /// output of the command are used for the protocol, its standard error
+    /// is left as the command sets it.
+    pub fn spawn(command: Command, timeout: Duration) -> Result<Self, ExternalProcessError> {
+        let name = command.get_program().to_string_lossy().into_owned();
+        let process = BotProcess::spawn(command, timeout)?;
+
+        Ok(Self::new(ExternalProcess {
+            connection: Rc::new(RefCell::new(Connection {
+                process: Some(process),
+                told: ToldState::default(),
+                name,
+            })),
+        }))
+    }
+
+    pub fn boxed(&self) -> Box<dyn RoundPlayer> {
+        Box::new(self.clone())
+    }
+
+    /// The bot still plays, it wasn't stopped for misbehaving.
+    pub fn is_connected(&self) -> bool {
+        self.answerer.connection.borrow().process.is_some()
+    }
+}
@@ -0,0 +1,179 @@
+use strum::IntoEnumIterator;
+
+use crate::game::{
+    deck::{Card, CardSuit, CardValue},
+    declaration::Declaration,
+    player::Hand,
+    points::{get_card_points, get_trump_points},
+    round::Round,
+    round_player::RoundPlayer,
+    table::Seat,
+    trump::ContractKind,
+};
+
+/// Trump strength a hand needs before the bot calls it without being forced.
+const TRUMP_CALL_THRESHOLD: usize = 45;
+/// Aces a hand needs before the bot calls no trumps.
+const NO_TRUMPS_ACES: usize = 3;
+
+/// Bot that plays by simple rules of thumb: call a suit with strong trumps,
+/// take tricks with the cheapest winning card, give points to a partner
+/// who holds the trick and throw away the cheapest card otherwise.
+#[derive(Debug, Default)]
+pub struct HeuristicRoundPlayer;
+
+impl HeuristicRoundPlayer {
+    pub fn boxed() -> Box<dyn RoundPlayer> {
+        Box::new(Self)
+    }
+
+    fn get_suit_strength(&self, hand: &Hand, suit: &CardSuit) -> usize {
+        let trump_points: usize = hand
+            .cards()
+            .iter()
+            .filter(|card| card.suit == *suit)
+            .map(|card| get_trump_points(&card.value) + 5)
+            .sum();
+        let side_aces = hand
+            .cards()
+            .iter()
+            .filter(|card| card.suit != *suit && card.value == CardValue::Kec)
+            .count();
+
+        trump_points + side_aces * 10
+    }
+
+    fn get_strongest_suit(&self, hand: &Hand) -> (CardSuit, usize) {
+        CardSuit::iter()
+            .map(|suit| {
+                let strength = self.get_suit_strength(hand, &suit);
+                (suit, strength)
+            })
+            .max_by_key(|(_, strength)| *strength)
+            .expect("there should always be a suit")
+    }
+
+    fn count_aces(&self, hand: &Hand) -> usize {
+        hand.cards()
+            .iter()
+            .filter(|card| card.value == CardValue::Kec)
+            .count()
+    }
+
+    fn wins_trick_with(&self, round_state: &Round, seat: Seat, card: &Card) -> bool {
+        let mut trick = round_state.current_trick.clone();
+        trick.play_card(card.clone());
+
+        trick.get_current_winner(&round_state.trump.kind) == Some(seat)
+    }
+
+    fn get_cheapest_card(&self, cards: &[Card], contract: &ContractKind) -> Card {
+        cards
+            .iter()
+            .min_by_key(|card| (get_card_points(card, contract), card.value.clone()))
+            .expect("there should be a playable card")
+            .clone()
+    }
+
+    fn get_card_to_lead(&self, round_state: &Round, seat: Seat, cards: &[Card]) -> Card {
+        let contract = &round_state.trump.kind;
+        let is_caller_team = round_state.players.get_team(seat)
+            == round_state.players.get_team(round_state.trump.seat);
+        let trump_jack = cards
+            .iter()
+            .find(|card| card.value == CardValue::Jack && contract.is_trump_suit(&card.suit));
+        if let Some(trump_jack) = trump_jack.filter(|_| is_caller_team) {
+            return trump_jack.clone();
+        }
+
+        let side_ace = cards
+            .iter()
+            .find(|card| card.value == CardValue::Kec && !contract.is_trump_suit(&card.suit));
+        if let Some(side_ace) = side_ace {
+            return side_ace.clone();
+        }
+
+        self.get_cheapest_card(cards, contract)
+    }
+}
+
+impl RoundPlayer for HeuristicRoundPlayer {
+    fn try_call_trump(&self, round_state: &Round, seat: Seat) -> Option<CardSuit> {
+        let hand = round_state.get_player(seat).get_hand();
+        let (suit, strength) = self.get_strongest_suit(hand);
+
+        (strength >= TRUMP_CALL_THRESHOLD).then_some(suit)
+    }
+
+    fn must_call_trump(&self, round_state: &Round, seat: Seat) -> CardSuit {
+        let hand = round_state.get_player(seat).get_hand();
+
+        self.get_strongest_suit(hand).0
+    }
+
+    fn try_call_contract(&self, round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        let hand = round_state.get_player(seat).get_hand();
+        if round_state.rules.extended_contracts && self.count_aces(hand) >= NO_TRUMPS_ACES {
+            return Some(ContractKind::NoTrumps);
+        }
+
+        self.try_call_trump(round_state, seat)
+            .map(ContractKind::Suit)
+    }
+
+    fn play_card(&self, round_state: &Round, seat: Seat, available_cards: Vec<Card>) -> Card {
+        let contract = &round_state.trump.kind;
+        let trick = &round_state.current_trick;
+        if trick.cards_on_table.is_empty() {
+            return self.get_card_to_lead(round_state, seat, &available_cards);
+        }
+
+        let current_winner = trick
+            .get_current_winner(contract)
+            .expect("trick with cards should have a winner");
+        let is_partner_winning = current_winner != seat
+            && round_state.players.get_team(current_winner) == round_state.players.get_team(seat);
+        let is_last_to_play = trick.cards_on_table.len() + 1 == round_state.get_number_of_players();
+        if is_partner_winning && is_last_to_play {
+            return available_cards
+                .iter()
+                .max_by_key(|card| get_card_points(card, contract))
+                .expect("there should be a playable card")
+                .clone();
+        }
+
+        let winning_cards = available_cards
+            .iter()
+            .filter(|card| self.wins_trick_with(round_state, seat, card))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !is_partner_winning && !winning_cards.is_empty() {
+            return self.get_cheapest_card(&winning_cards, contract);
+        }
+
+        self.get_cheapest_card(&available_cards, contract)
+    }
+
+    fn call_declaration(
+        &self,
+        _round_state: &Round,
+        _seat: Seat,
+        _declaration: &Declaration,
+    ) -> bool {
+        true
+    }
+
+    fn will_declare_bella(&self, _round_state: &Round, _seat: Seat) -> bool {
+        true
+    }
+
+    fn will_call_kontra(&self, round_state: &Round, seat: Seat) -> bool {
+        let hand = round_state.get_player(seat).get_hand();
+        match &round_state.trump.kind {
+            ContractKind::Suit(suit) => {
+                self.get_suit_strength(hand, suit) >= TRUMP_CALL_THRESHOLD * 2
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::{cell::RefCell, io::Write};
+
+use crate::{
+    game::{
+        round::{Round, RoundUpdateEvent},
+        round_observer::RoundObserver,
+    },
+    render::Renderer,
+};
+
+/// One line telling what happened, for logs and event lists.
+pub fn get_event_description(
+    renderer: &Renderer,
+    round_state: &Round,
+    round_event: RoundUpdateEvent,
+) -> String {
+    let language = renderer.language;
+    let get_name = |seat| round_state.get_player(seat).name.as_str();
+    match round_event {
+        RoundUpdateEvent::CardPlayed { seat, card } => {
+            language.plays(get_name(seat), &renderer.get_card_name(&card))
+        }
+        RoundUpdateEvent::TrumpCallEvent { seat, trump } => match trump {
+            Some(trump) => language.calls(get_name(seat), &renderer.get_contract_name(&trump.kind)),
+            None => language.passes(get_name(seat)),
+        },
+        RoundUpdateEvent::BidMade { seat, bid } => {
+            language.bids(get_name(seat), &renderer.get_bid_name(&bid))
+        }
+        RoundUpdateEvent::Redealt => language.redealt().to_string(),
+        RoundUpdateEvent::DeclarationsCalled(declarations) => declarations
+            .iter()
+            .map(|info| {
+                language.declares(
+                    get_name(info.seat),
+                    &renderer.get_card_names(&info.declaration.cards),
+                    info.declaration.points,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        RoundUpdateEvent::KontraCalled { seat } => language.calls_kontra(get_name(seat)),
+        RoundUpdateEvent::RekontraCalled { seat } => language.calls_rekontra(get_name(seat)),
+        RoundUpdateEvent::BelaDeclared(announcement) => {
+            language.declares_bela(get_name(announcement.seat))
+        }
+        RoundUpdateEvent::TrickDone(trick_item) => {
+            let team_name: &str = (*trick_item.get_winner_team()).into();
+            language.takes_trick(
+                get_name(trick_item.get_winner_seat()),
+                trick_item.get_points(),
+                team_name,
+            )
+        }
+    }
+}
+
+/// Writes one line for every event of the round, without waiting for
+/// anybody. Useful for logging bot matches or replays to a file.
+pub struct LogObserver<W: Write> {
+    writer: RefCell<W>,
+    renderer: Renderer,
+}
+
+impl<W: Write> LogObserver<W> {
+    pub fn new(writer: W, renderer: Renderer) -> Self {
+        Self {
+            writer: RefCell::new(writer),
+            renderer,
+        }
+    }
+}
+
+impl<W: Write> RoundObserver for LogObserver<W> {
+    fn on_update(&self, round_state: &Round, round_event: RoundUpdateEvent) {
+        let line = get_event_description(&self.renderer, round_state, round_event);
+        writeln!(self.writer.borrow_mut(), "{}", line).expect("log should be writable");
+    }
+}
@@ -1,2 +1,18 @@
-pub mod random_round_player;
 pub mod cli_round_player;
+pub mod console_observer;
+pub mod engine_protocol;
+pub mod external_process_round_player;
+pub mod heuristic_round_player;
+pub mod log_observer;
+pub mod protocol_round_player;
+pub mod random_round_player;
+pub mod replay_round_player;
+pub mod search_round_player;
+pub mod seated_round_player;
+pub mod spectator_observer;
+
+#[cfg(test)]
+mod test_engine_protocol;
+
+#[cfg(test)]
+mod test_external_process_round_player;
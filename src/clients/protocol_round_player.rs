@@ -0,0 +1,143 @@
+use std::rc::Rc;
+
+use crate::game::{
+    auction::Bid,
+    deck::{Card, CardSuit},
+    declaration::Declaration,
+    round::Round,
+    round_player::RoundPlayer,
+    table::Seat,
+    trump::ContractKind,
+};
+
+use super::{
+    engine_protocol::{Answer, Request},
+    heuristic_round_player::HeuristicRoundPlayer,
+};
+
+/// Player living outside of the engine, like a bot process or somebody
+/// connected over the network, that answers requests of the
+/// [`engine_protocol`](super::engine_protocol).
+pub trait RequestAnswerer {
+    /// Answer allowed by the request, `None` when there is none and the
+    /// fallback has to decide.
+    fn answer(&self, round_state: &Round, seat: Seat, request: Request) -> Option<Answer>;
+}
+
+/// Turns every decision into a protocol request for the answerer.
+#[derive(Clone)]
+pub struct ProtocolRoundPlayer<A> {
+    pub answerer: A,
+    fallback: Rc<dyn RoundPlayer>,
+}
+
+impl<A> ProtocolRoundPlayer<A> {
+    /// The heuristic bot decides whenever the answerer doesn't.
+    pub fn new(answerer: A) -> Self {
+        Self {
+            answerer,
+            fallback: Rc::new(HeuristicRoundPlayer),
+        }
+    }
+
+    pub fn with_fallback(mut self, fallback: Box<dyn RoundPlayer>) -> Self {
+        self.fallback = fallback.into();
+        self
+    }
+}
+
+impl<A: RequestAnswerer> ProtocolRoundPlayer<A> {
+    fn ask_yes_no(&self, round_state: &Round, seat: Seat, request: Request) -> Option<bool> {
+        match self.answerer.answer(round_state, seat, request)? {
+            Answer::YesNo(answer) => Some(answer),
+            _ => None,
+        }
+    }
+
+    fn ask_contract(
+        &self,
+        round_state: &Round,
+        seat: Seat,
+        request: Request,
+    ) -> Option<Option<ContractKind>> {
+        match self.answerer.answer(round_state, seat, request)? {
+            Answer::Contract(contract) => Some(contract),
+            _ => None,
+        }
+    }
+}
+
+fn get_suit(contract: Option<ContractKind>) -> Option<CardSuit> {
+    match contract? {
+        ContractKind::Suit(suit) => Some(suit),
+        _ => None,
+    }
+}
+
+impl<A: RequestAnswerer> RoundPlayer for ProtocolRoundPlayer<A> {
+    fn try_call_trump(&self, round_state: &Round, seat: Seat) -> Option<CardSuit> {
+        match self.ask_contract(round_state, seat, Request::Trump) {
+            Some(contract) => get_suit(contract),
+            None => self.fallback.try_call_trump(round_state, seat),
+        }
+    }
+
+    fn must_call_trump(&self, round_state: &Round, seat: Seat) -> CardSuit {
+        self.ask_contract(round_state, seat, Request::ForcedTrump)
+            .and_then(get_suit)
+            .unwrap_or_else(|| self.fallback.must_call_trump(round_state, seat))
+    }
+
+    fn try_call_contract(&self, round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        match self.ask_contract(round_state, seat, Request::Contract) {
+            Some(contract) => contract,
+            None => self.fallback.try_call_contract(round_state, seat),
+        }
+    }
+
+    fn must_call_contract(&self, round_state: &Round, seat: Seat) -> ContractKind {
+        self.ask_contract(round_state, seat, Request::ForcedContract)
+            .flatten()
+            .unwrap_or_else(|| self.fallback.must_call_contract(round_state, seat))
+    }
+
+    fn make_bid(&self, round_state: &Round, seat: Seat, valid_bids: Vec<Bid>) -> Bid {
+        let request = Request::Bid(valid_bids.clone());
+        match self.answerer.answer(round_state, seat, request) {
+            Some(Answer::Bid(bid)) => bid,
+            _ => self.fallback.make_bid(round_state, seat, valid_bids),
+        }
+    }
+
+    fn play_card(&self, round_state: &Round, seat: Seat, available_cards: Vec<Card>) -> Card {
+        let request = Request::Card(available_cards.clone());
+        match self.answerer.answer(round_state, seat, request) {
+            Some(Answer::Card(card)) => card,
+            _ => self.fallback.play_card(round_state, seat, available_cards),
+        }
+    }
+
+    fn call_declaration(&self, round_state: &Round, seat: Seat, declaration: &Declaration) -> bool {
+        let request = Request::Declaration(declaration.clone());
+        self.ask_yes_no(round_state, seat, request)
+            .unwrap_or_else(|| {
+                self.fallback
+                    .call_declaration(round_state, seat, declaration)
+            })
+    }
+
+    fn will_declare_bella(&self, round_state: &Round, seat: Seat) -> bool {
+        self.ask_yes_no(round_state, seat, Request::Bela)
+            .unwrap_or_else(|| self.fallback.will_declare_bella(round_state, seat))
+    }
+
+    fn will_call_kontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.ask_yes_no(round_state, seat, Request::Kontra)
+            .unwrap_or_else(|| self.fallback.will_call_kontra(round_state, seat))
+    }
+
+    fn will_call_rekontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.ask_yes_no(round_state, seat, Request::Rekontra)
+            .unwrap_or_else(|| self.fallback.will_call_rekontra(round_state, seat))
+    }
+}
@@ -0,0 +1,121 @@
+use std::cell::Cell;
+
+use crate::game::{
+    auction::Bid,
+    deck::{Card, CardSuit},
+    declaration::Declaration,
+    kontra::Doubling,
+    record::RoundRecord,
+    round::Round,
+    round_player::RoundPlayer,
+    table::Seat,
+    trump::ContractKind,
+};
+
+/// Makes the same decisions that were stored in a round record.
+pub struct ReplayRoundPlayer {
+    record: RoundRecord,
+    next_bid: Cell<usize>,
+}
+
+impl ReplayRoundPlayer {
+    pub fn new(record: RoundRecord) -> Self {
+        Self {
+            record,
+            next_bid: Cell::new(0),
+        }
+    }
+
+    fn get_recorded_contract(&self, seat: Seat) -> Option<ContractKind> {
+        (self.record.trump.seat == seat).then(|| self.record.trump.kind.clone())
+    }
+}
+
+impl RoundPlayer for ReplayRoundPlayer {
+    fn try_call_trump(&self, _round_state: &Round, seat: Seat) -> Option<CardSuit> {
+        match self.get_recorded_contract(seat) {
+            Some(ContractKind::Suit(suit)) => Some(suit),
+            _ => None,
+        }
+    }
+
+    fn must_call_trump(&self, round_state: &Round, seat: Seat) -> CardSuit {
+        self.try_call_trump(round_state, seat)
+            .expect("record should have the forced trump call")
+    }
+
+    fn try_call_contract(&self, _round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        self.get_recorded_contract(seat)
+    }
+
+    fn must_call_contract(&self, _round_state: &Round, seat: Seat) -> ContractKind {
+        self.get_recorded_contract(seat)
+            .expect("record should have the forced contract call")
+    }
+
+    fn make_bid(&self, _round_state: &Round, seat: Seat, valid_bids: Vec<Bid>) -> Bid {
+        let index = self.next_bid.get();
+        self.next_bid.set(index + 1);
+        let recorded_bid = self
+            .record
+            .bids
+            .get(index)
+            .expect("record should have every bid");
+        assert_eq!(
+            recorded_bid.seat, seat,
+            "recorded bid should be made by the seat on turn"
+        );
+        assert!(
+            valid_bids.contains(&recorded_bid.bid),
+            "recorded bid should be valid"
+        );
+
+        recorded_bid.bid.clone()
+    }
+
+    fn play_card(&self, round_state: &Round, seat: Seat, available_cards: Vec<Card>) -> Card {
+        let index = round_state.trick_history.len() * round_state.get_number_of_players()
+            + round_state.current_trick.cards_on_table.len();
+        let (recorded_seat, card) = self
+            .record
+            .plays
+            .get(index)
+            .expect("record should have every played card");
+        assert_eq!(
+            *recorded_seat, seat,
+            "recorded card should be played by the seat on turn"
+        );
+        assert!(
+            available_cards.contains(card),
+            "recorded card should be playable"
+        );
+
+        card.clone()
+    }
+
+    fn call_declaration(
+        &self,
+        _round_state: &Round,
+        seat: Seat,
+        declaration: &Declaration,
+    ) -> bool {
+        self.record
+            .declarations
+            .iter()
+            .any(|info| info.seat == seat && info.declaration == *declaration)
+    }
+
+    fn will_declare_bella(&self, _round_state: &Round, seat: Seat) -> bool {
+        self.record.bela_seats.contains(&seat)
+    }
+
+    // who exactly doubled doesn't change the result, so the first defender
+    // that is asked answers for the team
+    fn will_call_kontra(&self, _round_state: &Round, _seat: Seat) -> bool {
+        self.record.doubling != Doubling::None
+    }
+
+    fn will_call_rekontra(&self, _round_state: &Round, _seat: Seat) -> bool {
+        self.record.doubling == Doubling::Rekontra
+    }
+}
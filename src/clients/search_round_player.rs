@@ -0,0 +1,102 @@
+use crate::{
+    clients::heuristic_round_player::HeuristicRoundPlayer,
+    game::{
+        auction::Bid,
+        deck::{Card, CardSuit},
+        declaration::Declaration,
+        round::Round,
+        round_player::RoundPlayer,
+        table::Seat,
+        trump::ContractKind,
+    },
+    simulation::hint::get_card_hints,
+    utils::random::RandomSource,
+};
+
+/// Deals the bot simulates for every card it plays.
+pub const DEFAULT_SEARCH_SAMPLES: usize = 30;
+
+/// Bot that plays the card with the best expected points over simulated
+/// deals of the cards it can't see, every other decision is left to the
+/// heuristic bot.
+#[derive(Debug)]
+pub struct SearchRoundPlayer {
+    samples: usize,
+    bot: HeuristicRoundPlayer,
+}
+
+impl SearchRoundPlayer {
+    pub fn new(samples: usize) -> Self {
+        Self {
+            samples,
+            bot: HeuristicRoundPlayer,
+        }
+    }
+
+    pub fn boxed() -> Box<dyn RoundPlayer> {
+        Box::new(Self::default())
+    }
+}
+
+impl Default for SearchRoundPlayer {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEARCH_SAMPLES)
+    }
+}
+
+impl RoundPlayer for SearchRoundPlayer {
+    fn try_call_trump(&self, round_state: &Round, seat: Seat) -> Option<CardSuit> {
+        self.bot.try_call_trump(round_state, seat)
+    }
+
+    fn must_call_trump(&self, round_state: &Round, seat: Seat) -> CardSuit {
+        self.bot.must_call_trump(round_state, seat)
+    }
+
+    fn try_call_contract(&self, round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        self.bot.try_call_contract(round_state, seat)
+    }
+
+    fn must_call_contract(&self, round_state: &Round, seat: Seat) -> ContractKind {
+        self.bot.must_call_contract(round_state, seat)
+    }
+
+    fn make_bid(&self, round_state: &Round, seat: Seat, valid_bids: Vec<Bid>) -> Bid {
+        self.bot.make_bid(round_state, seat, valid_bids)
+    }
+
+    fn play_card(&self, round_state: &Round, seat: Seat, available_cards: Vec<Card>) -> Card {
+        if available_cards.len() == 1 || self.samples == 0 {
+            return self.bot.play_card(round_state, seat, available_cards);
+        }
+        let hints = get_card_hints(
+            round_state,
+            seat,
+            &available_cards,
+            self.samples,
+            &mut RandomSource,
+        );
+
+        hints
+            .into_iter()
+            .max_by(|a, b| a.points.get_mean().total_cmp(&b.points.get_mean()))
+            .map(|hint| hint.option)
+            .expect("a card should be available")
+    }
+
+    fn call_declaration(&self, round_state: &Round, seat: Seat, declaration: &Declaration) -> bool {
+        self.bot.call_declaration(round_state, seat, declaration)
+    }
+
+    fn will_declare_bella(&self, round_state: &Round, seat: Seat) -> bool {
+        self.bot.will_declare_bella(round_state, seat)
+    }
+
+    fn will_call_kontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.bot.will_call_kontra(round_state, seat)
+    }
+
+    fn will_call_rekontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.bot.will_call_rekontra(round_state, seat)
+    }
+}
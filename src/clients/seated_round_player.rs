@@ -0,0 +1,67 @@
+use crate::game::{
+    auction::Bid,
+    deck::{Card, CardSuit},
+    declaration::Declaration,
+    round::Round,
+    round_player::RoundPlayer,
+    table::Seat,
+    trump::ContractKind,
+};
+
+/// Lets a different player decide for every seat.
+pub struct SeatedRoundPlayer {
+    players: Vec<Box<dyn RoundPlayer>>,
+}
+
+impl SeatedRoundPlayer {
+    pub fn new(players: Vec<Box<dyn RoundPlayer>>) -> Self {
+        Self { players }
+    }
+
+    fn get(&self, seat: Seat) -> &dyn RoundPlayer {
+        self.players[seat.index()].as_ref()
+    }
+}
+
+impl RoundPlayer for SeatedRoundPlayer {
+    fn try_call_trump(&self, round_state: &Round, seat: Seat) -> Option<CardSuit> {
+        self.get(seat).try_call_trump(round_state, seat)
+    }
+
+    fn must_call_trump(&self, round_state: &Round, seat: Seat) -> CardSuit {
+        self.get(seat).must_call_trump(round_state, seat)
+    }
+
+    fn try_call_contract(&self, round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        self.get(seat).try_call_contract(round_state, seat)
+    }
+
+    fn must_call_contract(&self, round_state: &Round, seat: Seat) -> ContractKind {
+        self.get(seat).must_call_contract(round_state, seat)
+    }
+
+    fn make_bid(&self, round_state: &Round, seat: Seat, valid_bids: Vec<Bid>) -> Bid {
+        self.get(seat).make_bid(round_state, seat, valid_bids)
+    }
+
+    fn play_card(&self, round_state: &Round, seat: Seat, available_cards: Vec<Card>) -> Card {
+        self.get(seat).play_card(round_state, seat, available_cards)
+    }
+
+    fn call_declaration(&self, round_state: &Round, seat: Seat, declaration: &Declaration) -> bool {
+        self.get(seat)
+            .call_declaration(round_state, seat, declaration)
+    }
+
+    fn will_declare_bella(&self, round_state: &Round, seat: Seat) -> bool {
+        self.get(seat).will_declare_bella(round_state, seat)
+    }
+
+    fn will_call_kontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.get(seat).will_call_kontra(round_state, seat)
+    }
+
+    fn will_call_rekontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.get(seat).will_call_rekontra(round_state, seat)
+    }
+}
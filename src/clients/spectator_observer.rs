@@ -0,0 +1,60 @@
+use std::{cell::RefCell, thread, time::Duration};
+
+use crate::{
+    clients::log_observer::get_event_description,
+    game::{
+        deck::Card,
+        round::{Round, RoundUpdateEvent},
+        round_observer::RoundObserver,
+        table::Seat,
+        visibility::Visibility,
+    },
+    render::Renderer,
+};
+
+/// Shows a table to somebody watching it in the terminal: every event and,
+/// before every trick, the hands the visibility allows. Waits `delay` after
+/// every event so a match of bots can be followed.
+#[derive(Debug)]
+pub struct SpectatorObserver {
+    renderer: Renderer,
+    visibility: Visibility,
+    delay: Duration,
+    /// Hands that were shown last.
+    hands: RefCell<Vec<(Seat, Vec<Card>)>>,
+}
+
+impl SpectatorObserver {
+    pub fn new(renderer: Renderer, visibility: Visibility, delay: Duration) -> Self {
+        Self {
+            renderer,
+            visibility,
+            delay,
+            hands: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl RoundObserver for SpectatorObserver {
+    fn on_update(&self, round_state: &Round, round_event: RoundUpdateEvent) {
+        println!(
+            "{}",
+            get_event_description(&self.renderer, round_state, round_event)
+        );
+
+        let hands = self.visibility.get_hands(round_state);
+        let has_cards = hands.iter().any(|(_, cards)| !cards.is_empty());
+        if round_state.current_trick.cards_on_table.is_empty()
+            && has_cards
+            && *self.hands.borrow() != hands
+        {
+            for (seat, cards) in &hands {
+                let name = &round_state.get_player(*seat).name;
+                let cards = self.renderer.get_card_names(cards);
+                println!("  {}", self.renderer.language.holds(name, &cards));
+            }
+            self.hands.replace(hands);
+        }
+        thread::sleep(self.delay);
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::clients::engine_protocol::{Answer, EngineMessage, Request, TableEvent};
+    use crate::game::auction::Bid;
+    use crate::game::deck::{Card, CardSuit, CardValue};
+    use crate::game::kontra::Doubling;
+    use crate::game::table::Seat;
+    use crate::game::team::Team;
+    use crate::game::trump::ContractKind;
+
+    #[test]
+    fn test_messages_use_record_names() {
+        let cards = vec![
+            Card::new(CardSuit::Herz, CardValue::Jack),
+            Card::new(CardSuit::Acorn, CardValue::VII),
+        ];
+        let messages = [
+            EngineMessage::Hello,
+            EngineMessage::NewRound {
+                seat: Seat::new(1),
+                teams: vec![Team::A, Team::B, Team::A, Team::B],
+            },
+            EngineMessage::Hand(cards.clone()),
+            EngineMessage::Event(TableEvent::Trump {
+                seat: Seat::new(2),
+                contract: ContractKind::AllTrumps,
+            }),
+            EngineMessage::Event(TableEvent::Doubling(Doubling::Kontra)),
+            EngineMessage::Ask(Request::Bid(vec![Bid::Pass, Bid::Double])),
+            EngineMessage::Ask(Request::Card(cards)),
+        ];
+
+        let lines = messages
+            .iter()
+            .map(EngineMessage::to_line)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            [
+                "belot 1",
+                "newround 1 A B A B",
+                "hand Herz-Jack Acorn-VII",
+                "event trump 2 AllTrumps",
+                "event doubling Kontra",
+                "ask bid Dalje Kontra",
+                "ask card Herz-Jack Acorn-VII",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_only_offered_answers_are_accepted() {
+        let card = Card::new(CardSuit::Leaf, CardValue::Kec);
+        let request = Request::Card(vec![card.clone()]);
+        assert_eq!(request.parse_answer("Leaf-Kec\n"), Some(Answer::Card(card)));
+        assert_eq!(request.parse_answer("Herz-Kec"), None);
+        assert_eq!(request.parse_answer("kec"), None);
+
+        assert_eq!(
+            Request::Trump.parse_answer("pass"),
+            Some(Answer::Contract(None))
+        );
+        assert_eq!(Request::ForcedTrump.parse_answer("pass"), None);
+        assert_eq!(Request::Trump.parse_answer("NoTrumps"), None);
+        assert_eq!(
+            Request::ForcedContract.parse_answer("NoTrumps"),
+            Some(Answer::Contract(Some(ContractKind::NoTrumps)))
+        );
+
+        let request = Request::Bid(vec![Bid::Pass, Bid::Contract(ContractKind::AllTrumps)]);
+        assert_eq!(request.parse_answer("Dalje"), Some(Answer::Bid(Bid::Pass)));
+        assert_eq!(request.parse_answer("Herz"), None);
+
+        assert_eq!(Request::Bela.parse_answer("yes"), Some(Answer::YesNo(true)));
+        assert_eq!(Request::Kontra.parse_answer("maybe"), None);
+    }
+}
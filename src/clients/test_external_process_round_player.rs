@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, process::Command, time::Duration};
+
+    use crate::clients::external_process_round_player::{
+        DEFAULT_ANSWER_TIMEOUT, ExternalProcessRoundPlayer,
+    };
+    use crate::clients::seated_round_player::SeatedRoundPlayer;
+    use crate::game::round::Round;
+    use crate::game::round_observer::NullObserver;
+    use crate::game::rules::RuleSet;
+    use crate::game::table::TableConfig;
+
+    // only for bots that never answer, the others get the default
+    const SHORT_TIMEOUT: Duration = Duration::from_millis(200);
+
+    // passes when it can, calls herz when it must, says no to everything
+    // else and plays the first card it is offered
+    const FIRST_CARD_BOT: &str = r#"
+        while read -r kind request first rest; do
+            [ -n "$LOG" ] && echo "$kind $request $first $rest" >> "$LOG"
+            case "$kind $request" in
+                "belot 1") echo ready ;;
+                "ask trump") [ "$first" = forced ] && echo Herz || echo pass ;;
+                "ask card" | "ask bid") echo "$first" ;;
+                "ask "*) echo no ;;
+                "quit "*) exit 0 ;;
+            esac
+        done
+    "#;
+
+    fn spawn_bot(script: &str, timeout: Duration) -> ExternalProcessRoundPlayer {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        ExternalProcessRoundPlayer::spawn(command, timeout).expect("bot should start")
+    }
+
+    fn play_round(players: &[ExternalProcessRoundPlayer]) -> Round {
+        let player_names = ["a", "b", "c", "d"].map(String::from).to_vec();
+        let table_config = TableConfig::new(player_names, RuleSet::default()).with_seed(Some(3));
+        let mut round = Round::new(&table_config);
+        let round_player =
+            SeatedRoundPlayer::new(players.iter().map(|player| player.boxed()).collect());
+        round.play_round(Box::new(round_player), &NullObserver);
+
+        round
+    }
+
+    #[test]
+    fn test_bots_play_a_whole_round() {
+        let log = std::env::temp_dir().join(format!("belot-bot-{}.log", std::process::id()));
+        let _ = fs::remove_file(&log);
+        let mut command = Command::new("sh");
+        command.args(["-c", FIRST_CARD_BOT]).env("LOG", &log);
+        let logged_bot =
+            ExternalProcessRoundPlayer::spawn(command, DEFAULT_ANSWER_TIMEOUT).unwrap();
+        let players = [
+            logged_bot,
+            spawn_bot(FIRST_CARD_BOT, DEFAULT_ANSWER_TIMEOUT),
+            spawn_bot(FIRST_CARD_BOT, DEFAULT_ANSWER_TIMEOUT),
+            spawn_bot(FIRST_CARD_BOT, DEFAULT_ANSWER_TIMEOUT),
+        ];
+
+        let round = play_round(&players);
+
+        assert_eq!(round.trick_history.len(), 8);
+        assert!(players.iter().all(ExternalProcessRoundPlayer::is_connected));
+        let lines = fs::read_to_string(&log).unwrap();
+        let _ = fs::remove_file(&log);
+        let lines = lines.lines().map(str::trim_end).collect::<Vec<_>>();
+        assert_eq!(lines[0], "belot 1");
+        assert_eq!(lines[1], "newround 0 A B A B");
+        assert!(lines[2].starts_with("hand "));
+        let count = |prefix: &str| lines.iter().filter(|line| line.starts_with(prefix)).count();
+        assert_eq!(count("event trump "), 1);
+        assert_eq!(count("ask card "), 8);
+        // the bot is asked for its last card before the last trick is done
+        assert_eq!(count("event trick "), 7);
+    }
+
+    #[test]
+    fn test_fallback_plays_for_misbehaving_bots() {
+        let players = [
+            spawn_bot("read -r line; echo ready; sleep 5", SHORT_TIMEOUT),
+            spawn_bot("read -r line; echo ready; exit 1", DEFAULT_ANSWER_TIMEOUT),
+            spawn_bot(
+                "read -r line; echo ready; while read -r line; do echo ace; done",
+                DEFAULT_ANSWER_TIMEOUT,
+            ),
+            spawn_bot(FIRST_CARD_BOT, DEFAULT_ANSWER_TIMEOUT),
+        ];
+
+        let round = play_round(&players);
+
+        assert_eq!(round.trick_history.len(), 8);
+        let connected = players
+            .iter()
+            .map(ExternalProcessRoundPlayer::is_connected)
+            .collect::<Vec<_>>();
+        assert_eq!(connected, [false, false, false, true]);
+    }
+
+    #[test]
+    fn test_bot_has_to_answer_the_greeting() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "read -r line; echo hello"]);
+        assert!(ExternalProcessRoundPlayer::spawn(command, DEFAULT_ANSWER_TIMEOUT).is_err());
+
+        let command = Command::new("belot-bot-that-does-not-exist");
+        assert!(ExternalProcessRoundPlayer::spawn(command, DEFAULT_ANSWER_TIMEOUT).is_err());
+    }
+}
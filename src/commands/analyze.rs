@@ -0,0 +1,229 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use belot_game_optmal::{
+    game::{
+        points::get_card_points, record::RoundRecord, round::Round, round_observer::NullObserver,
+        solver::DoubleDummySolver, table::Seat, team::Team, trick::Trick,
+    },
+    render::Renderer,
+    simulation::analysis::{
+        AnalysisConfig, DEFAULT_ANALYSIS_SAMPLES, Decision, DecisionKind, RoundReport,
+        analyze_round,
+    },
+};
+
+use super::{CommandResult, DisplayOptions, read_records, replay_round};
+
+const LAST_TRICK_POINTS: usize = 10;
+
+#[derive(Debug, Args)]
+pub struct AnalyzeArgs {
+    /// Record file written by `belot play --record`, rules are taken from it.
+    record: PathBuf,
+    /// Deals sampled from a player's point of view for every decision.
+    #[arg(long, default_value_t = DEFAULT_ANALYSIS_SAMPLES)]
+    samples: usize,
+    /// Show every decision, not only the mistakes.
+    #[arg(long)]
+    all: bool,
+    #[command(flatten)]
+    display: DisplayOptions,
+}
+
+/// Round as it was before the given trick, with the cards of earlier tricks
+/// already out of the hands.
+fn get_round_before_trick(record: &RoundRecord, played_round: &Round, trick_index: usize) -> Round {
+    let mut round = Round::from_deal(&record.table_config, &record.deal);
+    round.trump = record.trump.clone();
+    let number_of_players = round.get_number_of_players();
+    for (seat, card) in record.plays.iter().take(trick_index * number_of_players) {
+        round
+            .players
+            .get_mut(*seat)
+            .expect("recorded seat should be at the table")
+            .remove_card(card);
+    }
+    let leader = match trick_index {
+        0 => round.seat_turn,
+        _ => played_round.trick_history[trick_index - 1].winner_seat,
+    };
+    round.current_trick = Trick::new(leader, number_of_players);
+
+    round
+}
+
+fn solve(round: &Round, team: Team) -> usize {
+    DoubleDummySolver::new(round, team).solve()
+}
+
+fn print_trick_analysis(record: &RoundRecord) {
+    let played_round = replay_round(record, &NullObserver);
+    let caller = played_round.get_player(record.trump.seat);
+    let caller_team = caller.get_team();
+    let team_name: &str = caller_team.into();
+    println!(
+        "Contract {} called by {} for team {}",
+        record.trump.kind.get_name(),
+        caller.name,
+        team_name
+    );
+
+    let number_of_tricks = played_round.trick_history.len();
+    let talon_points: usize = record
+        .deal
+        .talon
+        .iter()
+        .map(|card| get_card_points(card, &record.trump.kind))
+        .sum();
+    let mut points_before = solve(
+        &get_round_before_trick(record, &played_round, 0),
+        caller_team,
+    );
+    let mut taken_points = 0;
+    println!(
+        "Double dummy play gives team {} {} card points",
+        team_name, points_before
+    );
+    for (index, trick_item) in played_round.trick_history.iter().enumerate() {
+        let is_last_trick = index + 1 == number_of_tricks;
+        let points_after = if is_last_trick {
+            0
+        } else {
+            solve(
+                &get_round_before_trick(record, &played_round, index + 1),
+                caller_team,
+            )
+        };
+        let mut won_points = 0;
+        if trick_item.team_winner == caller_team {
+            won_points = trick_item.points;
+            if is_last_trick {
+                won_points += LAST_TRICK_POINTS + talon_points;
+            }
+        }
+        taken_points += won_points;
+
+        let winner = played_round.get_player(trick_item.winner_seat);
+        let difference = (won_points + points_after) as isize - points_before as isize;
+        let verdict = match difference {
+            0 => String::new(),
+            difference if difference > 0 => format!(", team {} gained {}", team_name, difference),
+            difference => format!(", team {} lost {}", team_name, -difference),
+        };
+        println!(
+            "Trick {}: {} won {} points{}",
+            index + 1,
+            winner.name,
+            trick_item.points,
+            verdict
+        );
+        points_before = points_after;
+    }
+
+    println!("Team {} took {} card points", team_name, taken_points);
+}
+
+fn get_decision_description(renderer: &Renderer, round: &Round, decision: &Decision) -> String {
+    let name = &round.get_player(decision.seat).name;
+    let points_lost = decision.points_lost;
+    match &decision.kind {
+        DecisionKind::Contract { called, best } if called == best => format!(
+            "{} called {}, the best contract",
+            name,
+            renderer.get_contract_name(called)
+        ),
+        DecisionKind::Contract { called, best } => format!(
+            "{} called {}, {} was better by {:.1} points",
+            name,
+            renderer.get_contract_name(called),
+            renderer.get_contract_name(best),
+            points_lost
+        ),
+        DecisionKind::Declaration(declaration) => format!(
+            "{} didn't declare {}, lost {:.1} points",
+            name,
+            renderer.get_card_names(&declaration.cards),
+            points_lost
+        ),
+        DecisionKind::Bela => format!(
+            "{} didn't announce bela, lost {:.1} points",
+            name, points_lost
+        ),
+        DecisionKind::Card {
+            trick_index,
+            played,
+            best,
+        } if played == best => format!(
+            "Trick {}: {} played {}, the best card",
+            trick_index + 1,
+            name,
+            renderer.get_card_name(played)
+        ),
+        DecisionKind::Card {
+            trick_index,
+            played,
+            best,
+        } => format!(
+            "Trick {}: {} played {}, {} was better by {:.1} points",
+            trick_index + 1,
+            name,
+            renderer.get_card_name(played),
+            renderer.get_card_name(best),
+            points_lost
+        ),
+        _ => format!("{} lost {:.1} points", name, points_lost),
+    }
+}
+
+/// Prints the decisions of a round, mistakes are marked with `!` and only
+/// they are shown unless `show_all` is set.
+pub fn print_report(renderer: &Renderer, round: &Round, report: &RoundReport, show_all: bool) {
+    for decision in &report.decisions {
+        if !show_all && !decision.is_mistake() {
+            continue;
+        }
+        let marker = if decision.is_mistake() { "!" } else { " " };
+        println!(
+            "{} {}",
+            marker,
+            get_decision_description(renderer, round, decision)
+        );
+    }
+
+    let number_of_players = round.get_number_of_players();
+    let points_lost = report.get_points_lost(number_of_players);
+    let summary = points_lost
+        .iter()
+        .enumerate()
+        .map(|(index, points)| {
+            let name = &round.get_player(Seat::new(index)).name;
+            format!("{} {:.1}", name, points)
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!("Points lost: {}", summary);
+}
+
+pub fn run(args: AnalyzeArgs) -> CommandResult {
+    let renderer = args.display.get_renderer();
+    for (index, record) in read_records(&args.record)?.iter().enumerate() {
+        println!("Round {}", index + 1);
+        print_trick_analysis(record);
+        let config = AnalysisConfig {
+            samples: args.samples,
+            seed: index as u64,
+        };
+        let report = analyze_round(record, config);
+        print_report(
+            &renderer,
+            &replay_round(record, &NullObserver),
+            &report,
+            args.all,
+        );
+        println!();
+    }
+
+    Ok(())
+}
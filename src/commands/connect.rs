@@ -0,0 +1,511 @@
+use std::{error::Error, io, net::TcpStream, thread, time::Duration};
+
+use clap::Args;
+
+use belot_game_optmal::{
+    game::{
+        auction::Bid, deck::Card, rules::GameVariant, trump::ContractKind, visibility::Visibility,
+    },
+    net::{
+        Connection, fair_deal,
+        protocol::{
+            ClientMessage, EventMessage, HandMessage, ServerMessage, ViewMessage, VisibilityMessage,
+        },
+    },
+    render::Renderer,
+};
+
+use super::{CommandResult, DisplayOptions, parse_visibility};
+
+/// Tries to get back to a match after the connection was lost.
+const RECONNECT_ATTEMPTS: usize = 5;
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Args)]
+pub struct ConnectArgs {
+    /// Address of a server started with `belot serve`.
+    #[arg(default_value = "127.0.0.1:7878")]
+    address: String,
+    /// Name the other players see.
+    #[arg(long, default_value = "Player")]
+    name: String,
+    /// Room to join, a quick match is played without one.
+    #[arg(long)]
+    room: Option<usize>,
+    /// Seat to take in the room, any free seat without one.
+    #[arg(long)]
+    seat: Option<usize>,
+    /// Watch the room instead of playing, with `public` information only,
+    /// the hands of `seat-N` or every hand `open`.
+    #[arg(long, value_parser = parse_visibility, requires = "room", conflicts_with = "seat")]
+    watch: Option<Visibility>,
+    #[command(flatten)]
+    display: DisplayOptions,
+}
+
+fn get_card_name(renderer: &Renderer, name: &str) -> String {
+    Card::from_name(name)
+        .map(|card| renderer.get_card_name(&card))
+        .unwrap_or_else(|| name.to_string())
+}
+
+fn get_card_names(renderer: &Renderer, names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| get_card_name(renderer, name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn get_contract_name(renderer: &Renderer, name: &str) -> String {
+    ContractKind::from_name(name)
+        .map(|kind| renderer.get_contract_name(&kind))
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// Option of a request the way the person at the terminal reads it.
+fn get_option_name(renderer: &Renderer, request: &str, option: &str) -> String {
+    let language = renderer.language;
+    match (request, option) {
+        (_, "pass") => language.pass().to_string(),
+        (_, "yes") => language.yes().to_string(),
+        (_, "no") => language.no().to_string(),
+        ("card", _) => get_card_name(renderer, option),
+        ("bid", _) => Bid::from_name(option)
+            .map(|bid| renderer.get_bid_name(&bid))
+            .unwrap_or_else(|| option.to_string()),
+        _ => get_contract_name(renderer, option),
+    }
+}
+
+/// Deal the server committed to, checked once its secret is revealed.
+struct CommittedDeal {
+    commitment: String,
+    /// Entropy the client gave for the deal, by seat.
+    entropies: Vec<(usize, String)>,
+    /// Cards the client saw before any of them was played, by seat.
+    hands: Vec<(usize, Vec<Card>)>,
+}
+
+fn get_cards(names: &[String]) -> Vec<Card> {
+    names
+        .iter()
+        .filter_map(|name| Card::from_name(name))
+        .collect()
+}
+
+/// Terminal side of a networked match, it only knows what the server
+/// tells it.
+struct Client {
+    connection: Connection,
+    address: String,
+    name: String,
+    /// Brings the client back to its seat after a lost connection.
+    token: Option<String>,
+    /// Whether a match is played, a lost connection is only restored then.
+    playing: bool,
+    room: Option<usize>,
+    requested_seat: Option<usize>,
+    watch: Option<Visibility>,
+    /// Hands a spectator sees, they are shown before every trick.
+    hands: Vec<HandMessage>,
+    /// Whether the hands of a new deal are still to be shown.
+    show_hands: bool,
+    committed_deal: Option<CommittedDeal>,
+    seat: Option<usize>,
+    /// Id the server gave the client, it finds the own seat in a room.
+    client: Option<usize>,
+    renderer: Renderer,
+    names: Vec<String>,
+    teams: Vec<String>,
+    view: Option<ViewMessage>,
+    /// Last request and its options, asked again after an error.
+    request: String,
+    options: Vec<String>,
+}
+
+impl Client {
+    fn get_variant(&self) -> GameVariant {
+        match self.names.len() {
+            3 => GameVariant::ThreePlayers,
+            _ => GameVariant::FourPlayers,
+        }
+    }
+
+    /// Keeps the hands of the committed deal, hands that are partly played
+    /// after a lost connection can't be checked.
+    fn keep_dealt_hands(&mut self, hands: Vec<(usize, Vec<Card>)>) {
+        let hand_size = self.get_variant().get_hand_size();
+        if let Some(committed_deal) = &mut self.committed_deal
+            && committed_deal.hands.is_empty()
+            && hands.iter().all(|(_, cards)| cards.len() == hand_size)
+        {
+            committed_deal.hands = hands;
+        }
+    }
+
+    fn get_name(&self, seat: usize) -> &str {
+        self.names.get(seat).map(String::as_str).unwrap_or("?")
+    }
+
+    fn get_event_description(&self, event: &EventMessage) -> String {
+        let renderer = &self.renderer;
+        let language = renderer.language;
+        match event {
+            EventMessage::Card { seat, card } => {
+                language.plays(self.get_name(*seat), &get_card_name(renderer, card))
+            }
+            EventMessage::Pass { seat } => language.passes(self.get_name(*seat)),
+            EventMessage::Trump { seat, contract } => {
+                language.calls(self.get_name(*seat), &get_contract_name(renderer, contract))
+            }
+            EventMessage::Bid { seat, bid } => {
+                language.bids(self.get_name(*seat), &get_option_name(renderer, "bid", bid))
+            }
+            EventMessage::Redealt => language.redealt().to_string(),
+            EventMessage::Declaration {
+                seat,
+                cards,
+                points,
+            } => language.declares(
+                self.get_name(*seat),
+                &get_card_names(renderer, cards),
+                *points,
+            ),
+            EventMessage::Kontra { seat } => language.calls_kontra(self.get_name(*seat)),
+            EventMessage::Rekontra { seat } => language.calls_rekontra(self.get_name(*seat)),
+            EventMessage::Bela { seat } => language.declares_bela(self.get_name(*seat)),
+            EventMessage::Trick { seat, points } => language.takes_trick(
+                self.get_name(*seat),
+                *points,
+                self.teams.get(*seat).map(String::as_str).unwrap_or("?"),
+            ),
+            EventMessage::TimedOut { seat } => language.timed_out(self.get_name(*seat)),
+            EventMessage::BotTookOver { seat } => language.bot_took_over(self.get_name(*seat)),
+            EventMessage::PlayerReturned { seat } => language.player_returned(self.get_name(*seat)),
+            _ => format!("{:?}", event),
+        }
+    }
+
+    /// Shows the hands unless they are empty, tells whether they were.
+    fn print_hands(&self) -> bool {
+        if self.hands.iter().all(|hand| hand.cards.is_empty()) {
+            return false;
+        }
+        for hand in &self.hands {
+            let cards = get_card_names(&self.renderer, &hand.cards);
+            let name = self.get_name(hand.seat);
+            println!("  {}", self.renderer.language.holds(name, &cards));
+        }
+
+        true
+    }
+
+    fn print_prompt(&self, declaration_prompt: Option<String>) {
+        let language = self.renderer.language;
+        if let Some(view) = &self.view {
+            if let Some(trump) = &view.trump {
+                println!(
+                    "{}: {}",
+                    language.trump(),
+                    get_contract_name(&self.renderer, &trump.contract)
+                );
+            }
+            println!(
+                "{} {}",
+                language.your_cards(),
+                get_card_names(&self.renderer, &view.hand)
+            );
+        }
+        let is_forced = !self.options.iter().any(|option| option == "pass");
+        let prompt = match self.request.as_str() {
+            "trump" | "contract" if is_forced => language.must_choose_trump().to_string(),
+            "trump" | "contract" => language.choose_trump().to_string(),
+            "bid" => language.make_bid().to_string(),
+            "declaration" => declaration_prompt.unwrap_or_default(),
+            "kontra" => language.ask_kontra().to_string(),
+            "rekontra" => language.ask_rekontra().to_string(),
+            "bela" => language.ask_bela().to_string(),
+            "card" => language.your_turn().to_string(),
+            request => request.to_string(),
+        };
+        println!("{}", prompt);
+        for (index, option) in self.options.iter().enumerate() {
+            println!(
+                "{}) {}",
+                index + 1,
+                get_option_name(&self.renderer, &self.request, option)
+            );
+        }
+    }
+
+    /// Reads options until the input is one of them, `None` once the
+    /// input is closed.
+    fn read_answer(&self) -> io::Result<Option<String>> {
+        let language = self.renderer.language;
+        loop {
+            let mut input = String::new();
+            if io::stdin().read_line(&mut input)? == 0 {
+                return Ok(None);
+            }
+            let input = input.trim();
+            let chosen = match input.parse::<usize>() {
+                Ok(number) => number
+                    .checked_sub(1)
+                    .and_then(|index| self.options.get(index)),
+                Err(_) => self.options.iter().find(|option| {
+                    option.as_str() == input
+                        || get_option_name(&self.renderer, &self.request, option)
+                            .eq_ignore_ascii_case(input)
+                }),
+            };
+            match chosen {
+                Some(option) => return Ok(Some(option.clone())),
+                None => println!("{}", language.enter_number(self.options.len())),
+            }
+        }
+    }
+
+    fn answer(&mut self) -> CommandResult {
+        let Some(answer) = self.read_answer()? else {
+            return Err("input closed".into());
+        };
+        self.connection.send(&ClientMessage::Answer { answer })?;
+
+        Ok(())
+    }
+
+    /// Comes back to the seat on a new connection.
+    fn reconnect(&mut self) -> CommandResult {
+        let mut connection = Connection::new(TcpStream::connect(&self.address)?)?;
+        connection.send(&ClientMessage::Hello {
+            name: self.name.clone(),
+            token: self.token.clone(),
+        })?;
+        match connection.receive::<ServerMessage>()? {
+            Some(ServerMessage::Welcome { .. }) => {
+                self.connection = connection;
+                Ok(())
+            }
+            Some(ServerMessage::Error { message }) => Err(message.into()),
+            _ => Err("the server didn't take the client back".into()),
+        }
+    }
+
+    /// Plays until the match is over, a lost connection is restored while
+    /// the match is played.
+    fn run(&mut self) -> CommandResult {
+        let language = self.renderer.language;
+        loop {
+            let error = match self.receive_messages() {
+                Ok(true) => return Ok(()),
+                Ok(false) => "the server closed the connection".into(),
+                Err(error) => error,
+            };
+            if !self.playing || self.token.is_none() {
+                return Err(error);
+            }
+            println!("{}", language.reconnecting());
+            let mut attempts = 0;
+            while let Err(error) = self.reconnect() {
+                attempts += 1;
+                if attempts == RECONNECT_ATTEMPTS {
+                    return Err(error);
+                }
+                thread::sleep(RECONNECT_DELAY);
+            }
+        }
+    }
+
+    /// Shows messages and answers requests, `true` once the match is over
+    /// and `false` when the connection is closed before.
+    fn receive_messages(&mut self) -> Result<bool, Box<dyn Error>> {
+        let language = self.renderer.language;
+        while let Some(message) = self.connection.receive::<ServerMessage>()? {
+            match message {
+                ServerMessage::Welcome { client, token, .. } => {
+                    self.client = Some(client);
+                    self.token = Some(token);
+                    let message = match (self.watch, self.room) {
+                        (Some(visibility), Some(room)) => ClientMessage::Spectate {
+                            room,
+                            visibility: VisibilityMessage::new(visibility),
+                        },
+                        _ => ClientMessage::JoinRoom {
+                            room: self.room,
+                            seat: self.requested_seat,
+                            team: None,
+                        },
+                    };
+                    self.connection.send(&message)?;
+                }
+                ServerMessage::Room { room } if !room.playing => {
+                    let seat = room
+                        .seats
+                        .iter()
+                        .find(|seat| seat.client.is_some() && seat.client == self.client)
+                        .map(|seat| seat.seat);
+                    if let Some(seat) = seat
+                        && self.seat != Some(seat)
+                    {
+                        self.seat = Some(seat);
+                        println!("{}", language.waiting_for_players(seat));
+                    }
+                }
+                ServerMessage::MatchStarted {
+                    seat, names, teams, ..
+                } => {
+                    self.playing = true;
+                    self.seat = Some(seat);
+                    self.names = names;
+                    self.teams = teams;
+                }
+                ServerMessage::Watching { names, teams, .. } => {
+                    self.names = names;
+                    self.teams = teams;
+                    self.show_hands = true;
+                }
+                ServerMessage::Hands { hands } => {
+                    self.keep_dealt_hands(
+                        hands
+                            .iter()
+                            .map(|hand| (hand.seat, get_cards(&hand.cards)))
+                            .collect(),
+                    );
+                    self.hands = hands;
+                    if self.show_hands {
+                        self.show_hands = !self.print_hands();
+                    }
+                }
+                ServerMessage::View { view } => {
+                    self.keep_dealt_hands(vec![(view.seat, get_cards(&view.hand))]);
+                    self.view = Some(view);
+                }
+                ServerMessage::DealCommitted { commitment, .. } => {
+                    let mut committed_deal = CommittedDeal {
+                        commitment,
+                        entropies: vec![],
+                        hands: vec![],
+                    };
+                    if self.watch.is_none() {
+                        let entropy = format!("{:032x}", rand::random::<u128>());
+                        if let Some(seat) = self.seat {
+                            committed_deal.entropies.push((seat, entropy.clone()));
+                        }
+                        self.connection.send(&ClientMessage::Entropy { entropy })?;
+                    }
+                    self.committed_deal = Some(committed_deal);
+                }
+                ServerMessage::DealRevealed {
+                    round,
+                    secret,
+                    entropies,
+                } => {
+                    if let Some(committed_deal) = self.committed_deal.take() {
+                        let result = fair_deal::verify_deal(
+                            &committed_deal.commitment,
+                            &secret,
+                            &entropies,
+                            self.get_variant(),
+                            &committed_deal.entropies,
+                            &committed_deal.hands,
+                        );
+                        match result {
+                            Ok(()) => println!("{}", language.deal_verified(round)),
+                            Err(reason) => {
+                                println!("{}", language.deal_not_verified(round, &reason))
+                            }
+                        }
+                    }
+                }
+                ServerMessage::Event { event } => {
+                    println!("{}", self.get_event_description(&event));
+                    match event {
+                        EventMessage::Trick { .. } => _ = self.print_hands(),
+                        EventMessage::Redealt => self.show_hands = true,
+                        _ => {}
+                    }
+                    // the person at the terminal is back once the answer
+                    // that came too late is given
+                    if let EventMessage::BotTookOver { seat } = event
+                        && Some(seat) == self.seat
+                    {
+                        self.connection.send(&ClientMessage::Resume)?;
+                    }
+                }
+                ServerMessage::Ask {
+                    request,
+                    options,
+                    declaration,
+                } => {
+                    self.request = request;
+                    self.options = options;
+                    let declaration_prompt = declaration.map(|declaration| {
+                        language.ask_declaration(
+                            &get_card_names(&self.renderer, &declaration.cards),
+                            declaration.points,
+                        )
+                    });
+                    self.print_prompt(declaration_prompt);
+                    self.answer()?;
+                }
+                ServerMessage::Error { message } if self.playing => {
+                    println!("{}", message);
+                    self.answer()?;
+                }
+                ServerMessage::Error { message } => return Err(message.into()),
+                ServerMessage::RoundOver { round, scores } => {
+                    self.show_hands = true;
+                    println!("{}", language.round_over(round));
+                    for score in scores {
+                        println!(
+                            "{} {}: +{} = {}",
+                            language.team(),
+                            score.team,
+                            score.points,
+                            score.total
+                        );
+                    }
+                }
+                ServerMessage::MatchOver { winner } => {
+                    println!("{}", language.won_match(&winner));
+                    return Ok(true);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+pub fn run(args: ConnectArgs) -> CommandResult {
+    let connection = Connection::new(TcpStream::connect(&args.address)?)?;
+    let mut client = Client {
+        connection,
+        address: args.address,
+        name: args.name,
+        token: None,
+        playing: false,
+        room: args.room,
+        requested_seat: args.seat,
+        watch: args.watch,
+        hands: vec![],
+        show_hands: false,
+        committed_deal: None,
+        seat: None,
+        client: None,
+        renderer: args.display.get_renderer(),
+        names: vec![],
+        teams: vec![],
+        view: None,
+        request: String::new(),
+        options: vec![],
+    };
+    client.connection.send(&ClientMessage::Hello {
+        name: client.name.clone(),
+        token: None,
+    })?;
+
+    client.run()
+}
@@ -0,0 +1,135 @@
+use std::{fs, io::ErrorKind, path::PathBuf};
+
+use clap::{Args, Subcommand};
+
+use belot_game_optmal::{
+    game::game_match::DEFAULT_TARGET_POINTS,
+    simulation::{Simulation, SimulationConfig, ladder::Ladder},
+};
+
+use super::{AgentKind, CommandResult, GameOptions, get_agents, get_default_names, get_threads};
+
+#[derive(Debug, Args)]
+pub struct LadderArgs {
+    /// File the ratings are kept in.
+    #[arg(long, default_value = "belot-ladder.txt", global = true)]
+    file: PathBuf,
+    #[command(subcommand)]
+    command: LadderCommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum LadderCommand {
+    /// Play rated matches between bots.
+    Run(RunArgs),
+    /// Print the leaderboard.
+    Show,
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    #[command(flatten)]
+    game: GameOptions,
+    /// Bot playing for every team, separated by commas. Every team gets a
+    /// heuristic bot when missing.
+    #[arg(long, value_enum, value_delimiter = ',')]
+    agents: Vec<AgentKind>,
+    /// Names the agents are rated under, separated by commas. Bots are
+    /// rated under the name of their kind when missing.
+    #[arg(long, value_delimiter = ',')]
+    names: Vec<String>,
+    /// Number of matches to play.
+    #[arg(long, default_value_t = 20)]
+    matches: usize,
+    /// Points a team needs to win a match.
+    #[arg(long, default_value_t = DEFAULT_TARGET_POINTS)]
+    target: usize,
+    /// Threads playing the matches, all cores are used when missing.
+    #[arg(long)]
+    threads: Option<usize>,
+}
+
+fn read_ladder(path: &PathBuf) -> Result<Ladder, Box<dyn std::error::Error>> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Ladder::parse(&text)?),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Ladder::default()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn print_leaderboard(ladder: &Ladder) {
+    for (index, rating) in ladder.get_leaderboard().iter().enumerate() {
+        println!(
+            "{:>3}. {:<16} {:>7.1}  {} matches, {} won",
+            index + 1,
+            rating.name,
+            rating.rating,
+            rating.matches,
+            rating.wins
+        );
+    }
+}
+
+fn run_matches(args: RunArgs, ladder: &mut Ladder) -> CommandResult {
+    let number_of_players = args.game.get_rules().variant.get_number_of_players();
+    let table_config = args
+        .game
+        .get_table_config(get_default_names(number_of_players));
+    let mut agents = get_agents(&args.agents, table_config.get_teams().len())?;
+    if !args.names.is_empty() {
+        if args.names.len() != agents.len() {
+            return Err(format!("{} names are needed, one for every agent", agents.len()).into());
+        }
+        if args
+            .names
+            .iter()
+            .any(|name| name.split_whitespace().count() != 1)
+        {
+            return Err("agent names can't contain spaces".into());
+        }
+        for (agent, name) in agents.iter_mut().zip(args.names) {
+            agent.name = name;
+        }
+    }
+    let names = agents
+        .iter()
+        .map(|agent| agent.name.clone())
+        .collect::<Vec<_>>();
+
+    let config = SimulationConfig {
+        table_config,
+        number_of_matches: args.matches,
+        target_points: args.target,
+        seed: args.game.seed.unwrap_or_else(rand::random),
+        threads: get_threads(args.threads),
+        duplicate_deals: true,
+    };
+    let result = Simulation::new(config, agents).run();
+    let old_ratings = names
+        .iter()
+        .map(|name| ladder.get_rating(name))
+        .collect::<Vec<_>>();
+    for points in &result.match_points {
+        ladder.add_match(&names, points);
+    }
+
+    println!("{} rated matches played", result.match_points.len());
+    for (name, old_rating) in names.iter().zip(old_ratings) {
+        let new_rating = ladder.get_rating(name);
+        println!("{}: {:.1} -> {:.1}", name, old_rating, new_rating);
+    }
+    println!();
+
+    Ok(())
+}
+
+pub fn run(args: LadderArgs) -> CommandResult {
+    let mut ladder = read_ladder(&args.file)?;
+    if let LadderCommand::Run(run_args) = args.command {
+        run_matches(run_args, &mut ladder)?;
+        fs::write(&args.file, ladder.to_text())?;
+    }
+    print_leaderboard(&ladder);
+
+    Ok(())
+}
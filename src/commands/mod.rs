@@ -0,0 +1,354 @@
+mod analyze;
+#[cfg(feature = "net")]
+mod connect;
+mod ladder;
+mod play;
+mod replay;
+#[cfg(feature = "net")]
+mod serve;
+mod simulate;
+mod solve;
+mod tournament;
+
+use std::{
+    error::Error,
+    fs,
+    path::Path,
+    process::{Command as ProcessCommand, Stdio},
+    thread,
+    time::Duration,
+};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use belot_game_optmal::{
+    clients::{
+        external_process_round_player::{DEFAULT_ANSWER_TIMEOUT, ExternalProcessRoundPlayer},
+        heuristic_round_player::HeuristicRoundPlayer,
+        random_round_player::RandomRoundPlayer,
+        replay_round_player::ReplayRoundPlayer,
+        search_round_player::SearchRoundPlayer,
+        seated_round_player::SeatedRoundPlayer,
+    },
+    game::{
+        record::RoundRecord,
+        round::Round,
+        round_observer::RoundObserver,
+        round_player::RoundPlayer,
+        rules::{RuleFlags, RuleSet},
+        table::{Seat, TableConfig},
+        visibility::Visibility,
+    },
+    render::{CardFace, Language, Renderer},
+    simulation::Agent,
+};
+
+pub type CommandResult = Result<(), Box<dyn Error>>;
+
+#[derive(Debug, Parser)]
+#[command(name = "belot", about = "Play, simulate and analyze belot rounds")]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Play an interactive match against bots.
+    Play(play::PlayArgs),
+    /// Play many matches between bots and print statistics.
+    Simulate(simulate::SimulateArgs),
+    /// Compare bots on the same deals with a duplicate tournament.
+    Tournament(tournament::TournamentArgs),
+    /// Rate bots against each other and keep a leaderboard.
+    Ladder(ladder::LadderArgs),
+    /// Show double dummy results for a deal.
+    Solve(solve::SolveArgs),
+    /// Step through recorded rounds.
+    Replay(replay::ReplayArgs),
+    /// Compare recorded card play with double dummy play.
+    Analyze(analyze::AnalyzeArgs),
+    /// Host tables for players connecting over the network.
+    #[cfg(feature = "net")]
+    Serve(serve::ServeArgs),
+    /// Join a table hosted with `belot serve`.
+    #[cfg(feature = "net")]
+    Connect(connect::ConnectArgs),
+}
+
+/// Options every command that deals cards shares.
+#[derive(Debug, Clone, Args)]
+pub struct GameOptions {
+    /// Seed that makes the deals reproducible.
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Play the three player variant.
+    #[arg(long)]
+    three_players: bool,
+    /// Allow kontra and rekontra after the trump call.
+    #[arg(long)]
+    kontra: bool,
+    /// Allow no trumps and all trumps contracts.
+    #[arg(long)]
+    extended_contracts: bool,
+    /// Choose the contract in an auction.
+    #[arg(long)]
+    auction: bool,
+    /// Announce bela with the second card of the pair.
+    #[arg(long)]
+    bela_on_second_card: bool,
+}
+
+impl GameOptions {
+    pub fn get_rules(&self) -> RuleSet {
+        RuleSet::from_flags(RuleFlags {
+            three_players: self.three_players,
+            kontra: self.kontra,
+            extended_contracts: self.extended_contracts,
+            auction: self.auction,
+            bela_on_second_card: self.bela_on_second_card,
+        })
+    }
+
+    pub fn get_table_config(&self, player_names: Vec<String>) -> TableConfig {
+        TableConfig::new(player_names, self.get_rules()).with_seed(self.seed)
+    }
+}
+
+/// How cards and messages are shown to people.
+#[derive(Debug, Clone, Args)]
+pub struct DisplayOptions {
+    /// Card names: deck, croatian-french, croatian-german, french or english.
+    #[arg(long, default_value = "deck")]
+    cards: CardFace,
+    /// Language of prompts and messages: english or croatian.
+    #[arg(long, default_value = "english")]
+    language: Language,
+    /// Color suits in the terminal.
+    #[arg(long)]
+    color: bool,
+    /// Show suits as Unicode symbols.
+    #[arg(long)]
+    symbols: bool,
+}
+
+impl DisplayOptions {
+    pub fn get_renderer(&self) -> Renderer {
+        Renderer {
+            card_face: self.cards,
+            language: self.language,
+            colors: self.color,
+            symbols: self.symbols,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PlayerKind {
+    Human,
+    Heuristic,
+    Random,
+    /// Bot that simulates deals to choose its cards.
+    Search,
+    /// Bot started with `--bot-command`.
+    External,
+}
+
+/// Bots of the engine, for commands where nobody else plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AgentKind {
+    Heuristic,
+    Random,
+    /// Bot that simulates deals to choose its cards.
+    Search,
+}
+
+/// Kind of player in every seat, seats without a choice get the command
+/// default.
+#[derive(Debug, Clone, Args)]
+pub struct SeatKinds<K: ValueEnum + Copy + Send + Sync + 'static> {
+    #[arg(long, value_enum)]
+    seat0: Option<K>,
+    #[arg(long, value_enum)]
+    seat1: Option<K>,
+    #[arg(long, value_enum)]
+    seat2: Option<K>,
+    #[arg(long, value_enum)]
+    seat3: Option<K>,
+}
+
+impl<K: ValueEnum + Copy + Send + Sync + 'static> SeatKinds<K> {
+    /// Kind of every seat, `None` for seats without a choice.
+    pub fn get_chosen_kinds(&self) -> [Option<K>; 4] {
+        [self.seat0, self.seat1, self.seat2, self.seat3]
+    }
+
+    pub fn get_kinds(&self, defaults: &[K]) -> Vec<K> {
+        self.get_chosen_kinds()
+            .into_iter()
+            .zip(defaults)
+            .map(|(kind, default)| kind.unwrap_or(*default))
+            .collect()
+    }
+}
+
+/// Who plays in every seat, seats without a choice get the command default.
+#[derive(Debug, Clone, Args)]
+pub struct SeatOptions {
+    #[command(flatten)]
+    kinds: SeatKinds<PlayerKind>,
+    /// Command that starts the bot of every external seat, arguments are
+    /// separated by spaces.
+    #[arg(long)]
+    bot_command: Option<String>,
+    /// Milliseconds an external bot may think about one decision.
+    #[arg(long, default_value_t = DEFAULT_ANSWER_TIMEOUT.as_millis() as u64)]
+    bot_timeout: u64,
+}
+
+impl SeatOptions {
+    pub fn get_player_kinds(&self, defaults: &[PlayerKind]) -> Vec<PlayerKind> {
+        self.kinds.get_kinds(defaults)
+    }
+
+    /// Starts a bot process for every external seat, the processes are
+    /// kept for the whole match. With `quiet` the bots can't write to the
+    /// terminal.
+    pub fn spawn_external_players(
+        &self,
+        player_kinds: &[PlayerKind],
+        quiet: bool,
+    ) -> Result<Vec<Option<ExternalProcessRoundPlayer>>, Box<dyn Error>> {
+        player_kinds
+            .iter()
+            .map(|kind| {
+                if *kind != PlayerKind::External {
+                    return Ok(None);
+                }
+                let mut words = self
+                    .bot_command
+                    .as_deref()
+                    .ok_or("external seats need a --bot-command")?
+                    .split_whitespace();
+                let program = words.next().ok_or("bot command is empty")?;
+                let mut command = ProcessCommand::new(program);
+                command.args(words);
+                if quiet {
+                    command.stderr(Stdio::null());
+                }
+                let timeout = Duration::from_millis(self.bot_timeout);
+                let player = ExternalProcessRoundPlayer::spawn(command, timeout)
+                    .map_err(|error| format!("{} couldn't start: {}", program, error))?;
+                Ok(Some(player))
+            })
+            .collect()
+    }
+}
+
+/// One player per seat, human seats get the player made by `get_human`
+/// and external seats share the bot process started for them.
+pub fn get_seated_round_player(
+    player_kinds: &[PlayerKind],
+    external_players: &[Option<ExternalProcessRoundPlayer>],
+    get_human: impl Fn(Seat) -> Box<dyn RoundPlayer>,
+) -> SeatedRoundPlayer {
+    let players = player_kinds
+        .iter()
+        .enumerate()
+        .map(|(index, kind)| match kind {
+            PlayerKind::Human => get_human(Seat::new(index)),
+            PlayerKind::Heuristic => HeuristicRoundPlayer::boxed(),
+            PlayerKind::Random => Box::new(RandomRoundPlayer),
+            PlayerKind::Search => SearchRoundPlayer::boxed(),
+            PlayerKind::External => external_players[index]
+                .as_ref()
+                .expect("external seat should have a bot process")
+                .boxed(),
+        })
+        .collect();
+
+    SeatedRoundPlayer::new(players)
+}
+
+pub fn get_agent(kind: AgentKind) -> Agent {
+    match kind {
+        AgentKind::Heuristic => Agent::new("heuristic", HeuristicRoundPlayer::boxed),
+        AgentKind::Random => Agent::new("random", || Box::new(RandomRoundPlayer)),
+        AgentKind::Search => Agent::new("search", SearchRoundPlayer::boxed),
+    }
+}
+
+/// One agent for every team, every team gets a heuristic bot when no
+/// agents are chosen.
+pub fn get_agents(agent_kinds: &[AgentKind], number_of_teams: usize) -> Result<Vec<Agent>, String> {
+    let agent_kinds = if agent_kinds.is_empty() {
+        vec![AgentKind::Heuristic; number_of_teams]
+    } else {
+        agent_kinds.to_vec()
+    };
+    if agent_kinds.len() != number_of_teams {
+        return Err(format!(
+            "{} agents are needed, one for every team",
+            number_of_teams
+        ));
+    }
+
+    Ok(agent_kinds.into_iter().map(get_agent).collect())
+}
+
+/// Threads for bot matches, all cores are used when no number is chosen.
+pub fn get_threads(threads: Option<usize>) -> usize {
+    threads.unwrap_or_else(|| {
+        thread::available_parallelism()
+            .map(|threads| threads.get())
+            .unwrap_or(1)
+    })
+}
+
+pub fn get_default_names(number_of_players: usize) -> Vec<String> {
+    ["Beki", "Zvona", "Murko", "Zorka"]
+        .into_iter()
+        .take(number_of_players)
+        .map(String::from)
+        .collect()
+}
+
+/// Parses the visibility of a spectator for clap.
+pub fn parse_visibility(name: &str) -> Result<Visibility, String> {
+    Visibility::from_name(name).ok_or_else(|| "expected `public`, `open` or `seat-N`".to_string())
+}
+
+pub fn read_records(path: &Path) -> Result<Vec<RoundRecord>, Box<dyn Error>> {
+    let text = fs::read_to_string(path)?;
+    let records = RoundRecord::parse_all(&text)?;
+    if records.is_empty() {
+        return Err(format!("{} has no recorded rounds", path.display()).into());
+    }
+
+    Ok(records)
+}
+
+/// Plays a recorded round again, the observer gets every round update.
+pub fn replay_round(record: &RoundRecord, observer: &dyn RoundObserver) -> Round {
+    let mut round = Round::from_deal(&record.table_config, &record.deal);
+    round.play_round(Box::new(ReplayRoundPlayer::new(record.clone())), observer);
+
+    round
+}
+
+pub fn run() -> CommandResult {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Play(args) => play::run(args),
+        Command::Simulate(args) => simulate::run(args),
+        Command::Tournament(args) => tournament::run(args),
+        Command::Ladder(args) => ladder::run(args),
+        Command::Solve(args) => solve::run(args),
+        Command::Replay(args) => replay::run(args),
+        Command::Analyze(args) => analyze::run(args),
+        #[cfg(feature = "net")]
+        Command::Serve(args) => serve::run(args),
+        #[cfg(feature = "net")]
+        Command::Connect(args) => connect::run(args),
+    }
+}
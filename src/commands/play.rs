@@ -0,0 +1,296 @@
+use std::{fs, io, path::PathBuf, time::Duration};
+
+use clap::Args;
+
+use belot_game_optmal::{
+    clients::{
+        cli_round_player::CliRoundPlayer, console_observer::ConsoleObserver,
+        external_process_round_player::ExternalProcessRoundPlayer,
+        spectator_observer::SpectatorObserver,
+    },
+    game::{
+        game_match::{DEFAULT_TARGET_POINTS, Match},
+        record::RoundRecord,
+        round::Round,
+        round_observer::{NullObserver, RoundObserver},
+        table::TableConfig,
+        visibility::Visibility,
+    },
+    render::Renderer,
+    simulation::{
+        analysis::{AnalysisConfig, DEFAULT_ANALYSIS_SAMPLES, analyze_round},
+        hint::DEFAULT_HINT_SAMPLES,
+    },
+};
+
+use super::{
+    CommandResult, DisplayOptions, GameOptions, PlayerKind, SeatOptions, analyze::print_report,
+    get_default_names, get_seated_round_player, parse_visibility,
+};
+
+#[derive(Debug, Args)]
+pub struct PlayArgs {
+    #[command(flatten)]
+    game: GameOptions,
+    #[command(flatten)]
+    seats: SeatOptions,
+    /// Player names in seat order, separated by commas.
+    #[arg(long, value_delimiter = ',')]
+    names: Vec<String>,
+    /// Points a team needs to win the match.
+    #[arg(long, default_value_t = DEFAULT_TARGET_POINTS)]
+    target: usize,
+    /// File the played rounds are recorded to.
+    #[arg(long)]
+    record: Option<PathBuf>,
+    #[command(flatten)]
+    display: DisplayOptions,
+    /// Allow asking for hints with '?' when it's your turn.
+    #[arg(long)]
+    hints: bool,
+    /// Simulated deals every hint is estimated from.
+    #[arg(long, default_value_t = DEFAULT_HINT_SAMPLES)]
+    hint_samples: usize,
+    /// Show the mistakes of every player after each round.
+    #[arg(long)]
+    analyze: bool,
+    /// Watch the table with `public` information only, the hands of
+    /// `seat-N` or every hand `open`.
+    #[arg(long, value_parser = parse_visibility)]
+    watch: Option<Visibility>,
+    /// Milliseconds to wait after every event while watching.
+    #[arg(long, default_value_t = 0)]
+    watch_delay: u64,
+    /// Play in a full screen terminal interface.
+    #[cfg(feature = "tui")]
+    #[arg(long)]
+    tui: bool,
+}
+
+/// Rounds played so far, written to the record file after every round so
+/// nothing is lost when the match is stopped.
+struct MatchRecorder {
+    path: Option<PathBuf>,
+    records: String,
+}
+
+impl MatchRecorder {
+    fn add_round(&mut self, table_config: &TableConfig, round: &Round) -> CommandResult {
+        if let Some(path) = &self.path {
+            self.records += &RoundRecord::new(table_config, round).to_text();
+            fs::write(path, &self.records)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// How a match in the console is shown.
+struct ConsoleOptions {
+    renderer: Renderer,
+    hint_samples: Option<usize>,
+    analyze: bool,
+    /// Shows the table instead of the human players.
+    spectator: Option<SpectatorObserver>,
+}
+
+fn play_in_console(
+    mut game_match: Match,
+    player_kinds: &[PlayerKind],
+    external_players: &[Option<ExternalProcessRoundPlayer>],
+    options: ConsoleOptions,
+    mut recorder: MatchRecorder,
+) -> CommandResult {
+    let ConsoleOptions {
+        renderer,
+        hint_samples,
+        analyze,
+        spectator,
+    } = options;
+    let has_human = player_kinds.contains(&PlayerKind::Human);
+    let console_observer = ConsoleObserver::new(renderer);
+    let observer: &dyn RoundObserver = match &spectator {
+        Some(spectator) => spectator,
+        None if has_human => &console_observer,
+        None => &NullObserver,
+    };
+    while !game_match.is_done() {
+        let round_table_config = game_match.get_next_table_config();
+        let round_player = get_seated_round_player(player_kinds, external_players, |seat| {
+            CliRoundPlayer::boxed(seat, renderer, hint_samples)
+        });
+        let round = game_match.play_round(Box::new(round_player), observer);
+        recorder.add_round(&round_table_config, &round)?;
+
+        let round_number = game_match.round_results.len();
+        println!("{}", renderer.language.round_over(round_number));
+        for team in game_match.get_teams() {
+            let team_name: &str = team.into();
+            let round_points = round.get_result().points.get_points(team);
+            let match_points = game_match.points.get_points(team);
+            println!(
+                "{} {}: +{} = {}",
+                renderer.language.team(),
+                team_name,
+                round_points,
+                match_points
+            );
+        }
+        if analyze {
+            let record = RoundRecord::new(&round_table_config, &round);
+            let config = AnalysisConfig {
+                samples: DEFAULT_ANALYSIS_SAMPLES,
+                seed: game_match.round_results.len() as u64,
+            };
+            print_report(&renderer, &round, &analyze_round(&record, config), false);
+        }
+        if has_human {
+            io::stdin().read_line(&mut String::new())?;
+        }
+    }
+
+    let winner: &str = game_match
+        .get_winner()
+        .expect("finished match should have a winner")
+        .into();
+    println!("{}", renderer.language.won_match(winner));
+
+    Ok(())
+}
+
+#[cfg(feature = "tui")]
+fn play_in_tui(
+    mut game_match: Match,
+    player_kinds: &[PlayerKind],
+    external_players: &[Option<ExternalProcessRoundPlayer>],
+    renderer: Renderer,
+    mut recorder: MatchRecorder,
+) -> CommandResult {
+    use crate::tui::{Tui, TuiObserver, TuiRoundPlayer};
+    use belot_game_optmal::game::table::Seat;
+
+    let human_seats = player_kinds
+        .iter()
+        .enumerate()
+        .filter(|(_, kind)| **kind == PlayerKind::Human)
+        .map(|(index, _)| Seat::new(index))
+        .collect::<Vec<_>>();
+    let [human_seat] = human_seats[..] else {
+        return Err("the terminal interface needs exactly one human seat".into());
+    };
+
+    let tui = Tui::start(human_seat, game_match.target_points, renderer);
+    let teams = game_match.get_teams();
+    while !game_match.is_done() {
+        tui.set_match_points(&teams, &game_match.points);
+        tui.start_round();
+        let round_table_config = game_match.get_next_table_config();
+        let round_player = get_seated_round_player(player_kinds, external_players, |_| {
+            TuiRoundPlayer::boxed(tui.clone())
+        });
+        let observer = TuiObserver::new(tui.clone());
+        let round = game_match.play_round(Box::new(round_player), &observer);
+        recorder.add_round(&round_table_config, &round)?;
+
+        tui.set_match_points(&teams, &game_match.points);
+        let summary = teams
+            .iter()
+            .map(|team| {
+                let team_name: &str = (*team).into();
+                let round_points = round.get_result().points.get_points(*team);
+                format!(
+                    "{} {} +{}",
+                    renderer.language.team(),
+                    team_name,
+                    round_points
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let round_number = game_match.round_results.len();
+        let message = format!(
+            "{}: {}",
+            renderer.language.round_over(round_number),
+            summary
+        );
+        tui.show_message(Some(&round), &message);
+    }
+
+    let winner: &str = game_match
+        .get_winner()
+        .expect("finished match should have a winner")
+        .into();
+    tui.show_message(None, &renderer.language.won_match(winner));
+
+    Ok(())
+}
+
+pub fn run(args: PlayArgs) -> CommandResult {
+    let rules = args.game.get_rules();
+    let number_of_players = rules.variant.get_number_of_players();
+    let names = if args.names.is_empty() {
+        get_default_names(number_of_players)
+    } else {
+        args.names
+    };
+    if names.len() != number_of_players {
+        return Err(format!("{} player names are needed", number_of_players).into());
+    }
+    let mut player_kinds = args.seats.get_player_kinds(&[
+        PlayerKind::Human,
+        PlayerKind::Heuristic,
+        PlayerKind::Heuristic,
+        PlayerKind::Heuristic,
+    ]);
+    player_kinds.truncate(number_of_players);
+
+    let table_config = args.game.get_table_config(names);
+    let game_match = Match::new(table_config, args.target);
+    let renderer = args.display.get_renderer();
+    if let Some(Visibility::Seat(seat)) = args.watch
+        && seat.index() >= number_of_players
+    {
+        return Err(format!("there is no seat {} to watch", seat.index()).into());
+    }
+    let spectator = args.watch.map(|visibility| {
+        SpectatorObserver::new(
+            renderer,
+            visibility,
+            Duration::from_millis(args.watch_delay),
+        )
+    });
+    let recorder = MatchRecorder {
+        path: args.record,
+        records: String::new(),
+    };
+
+    #[cfg(feature = "tui")]
+    if args.tui {
+        if spectator.is_some() {
+            return Err("the terminal interface can't be watched".into());
+        }
+        let external_players = args.seats.spawn_external_players(&player_kinds, true)?;
+        return play_in_tui(
+            game_match,
+            &player_kinds,
+            &external_players,
+            renderer,
+            recorder,
+        );
+    }
+
+    let external_players = args.seats.spawn_external_players(&player_kinds, false)?;
+    let hint_samples = args.hints.then_some(args.hint_samples);
+    play_in_console(
+        game_match,
+        &player_kinds,
+        &external_players,
+        ConsoleOptions {
+            renderer,
+            hint_samples,
+            analyze: args.analyze,
+            spectator,
+        },
+        recorder,
+    )
+}
@@ -0,0 +1,45 @@
+use std::{io, path::PathBuf};
+
+use clap::Args;
+
+use belot_game_optmal::{
+    clients::{console_observer::ConsoleObserver, log_observer::LogObserver},
+    game::round_observer::RoundObserver,
+};
+
+use super::{CommandResult, DisplayOptions, read_records, replay_round};
+
+#[derive(Debug, Args)]
+pub struct ReplayArgs {
+    /// Record file written by `belot play --record`, rules are taken from it.
+    record: PathBuf,
+    /// Print every event on its own line instead of stepping through them.
+    #[arg(long)]
+    log: bool,
+    #[command(flatten)]
+    display: DisplayOptions,
+}
+
+pub fn run(args: ReplayArgs) -> CommandResult {
+    let renderer = args.display.get_renderer();
+    let observer: Box<dyn RoundObserver> = if args.log {
+        Box::new(LogObserver::new(io::stdout(), renderer))
+    } else {
+        Box::new(ConsoleObserver::new(renderer))
+    };
+    for (index, record) in read_records(&args.record)?.iter().enumerate() {
+        println!("Round {}", index + 1);
+        let round = replay_round(record, observer.as_ref());
+        for team in round.players.get_teams() {
+            let team_name: &str = team.into();
+            println!(
+                "{} {}: {}",
+                renderer.language.team(),
+                team_name,
+                round.get_result().points.get_points(team)
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use std::{net::TcpListener, thread, time::Duration};
+
+use clap::{Args, ValueEnum};
+
+use belot_game_optmal::{
+    game::game_match::DEFAULT_TARGET_POINTS,
+    net::{
+        lobby::BotKind,
+        server::{MoveTimeout, Server, ServerConfig, TimeoutFallback},
+    },
+};
+
+use super::{CommandResult, GameOptions, PlayerKind, SeatOptions};
+
+/// What happens when a client runs out of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum FallbackKind {
+    /// Play the lowest card for the client, the bot makes other decisions.
+    LowestCard,
+    /// Hand the seat to the bot until the client resumes.
+    Bot,
+}
+
+#[derive(Debug, Args)]
+pub struct ServeArgs {
+    /// Address the server listens on.
+    #[arg(long, default_value = "127.0.0.1:7878")]
+    listen: String,
+    /// Also serve the browser client and its WebSockets on this address.
+    #[arg(long)]
+    web: Option<String>,
+    #[command(flatten)]
+    game: GameOptions,
+    /// Seats of quick matches are taken by clients unless a bot is chosen
+    /// for them.
+    #[command(flatten)]
+    seats: SeatOptions,
+    /// Points a team needs to win the match.
+    #[arg(long, default_value_t = DEFAULT_TARGET_POINTS)]
+    target: usize,
+    /// Seconds a client may think about one decision, as long as it likes
+    /// without a limit.
+    #[arg(long)]
+    move_timeout: Option<u64>,
+    #[arg(long, value_enum, default_value_t = FallbackKind::LowestCard)]
+    timeout_fallback: FallbackKind,
+    /// Seconds spectators see the table late, so they can't tell the
+    /// players what they see.
+    #[arg(long, default_value_t = 0)]
+    spectator_delay: u64,
+    /// Commit to every deal before it is dealt and reveal it after the
+    /// round, so the clients can check their cards.
+    #[arg(long)]
+    fair_dealing: bool,
+}
+
+pub fn run(args: ServeArgs) -> CommandResult {
+    let rules = args.game.get_rules();
+    let mut player_kinds = args.seats.get_player_kinds(&[PlayerKind::Human; 4]);
+    player_kinds.truncate(rules.variant.get_number_of_players());
+    let bots = player_kinds
+        .into_iter()
+        .map(|kind| match kind {
+            PlayerKind::Human => Ok(None),
+            PlayerKind::Heuristic => Ok(Some(BotKind::Heuristic)),
+            PlayerKind::Random => Ok(Some(BotKind::Random)),
+            PlayerKind::Search => Ok(Some(BotKind::Search)),
+            PlayerKind::External => Err("external bots can only play in `belot play`"),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if bots.iter().all(Option::is_some) {
+        return Err("at least one seat of quick matches has to be left for clients".into());
+    }
+
+    let move_timeout = args.move_timeout.map(|seconds| MoveTimeout {
+        limit: Duration::from_secs(seconds),
+        fallback: match args.timeout_fallback {
+            FallbackKind::LowestCard => TimeoutFallback::LowestCard,
+            FallbackKind::Bot => TimeoutFallback::Bot,
+        },
+    });
+
+    let listener = TcpListener::bind(&args.listen)?;
+    println!("Listening on {}", listener.local_addr()?);
+    let server = Server::new(ServerConfig {
+        rules,
+        target_points: args.target,
+        seed: args.game.seed,
+        bots,
+        move_timeout,
+        spectator_delay: Duration::from_secs(args.spectator_delay),
+        fair_dealing: args.fair_dealing,
+    });
+    if let Some(web) = &args.web {
+        let web_listener = TcpListener::bind(web)?;
+        println!("Browser client on http://{}/", web_listener.local_addr()?);
+        let web_server = server.clone();
+        thread::spawn(move || {
+            if let Err(error) = web_server.serve_web(web_listener) {
+                eprintln!("Browser client stopped: {}", error);
+            }
+        });
+    }
+    server.serve(listener)?;
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use clap::Args;
+
+use belot_game_optmal::{
+    game::{game_match::DEFAULT_TARGET_POINTS, table::TableConfig},
+    simulation::{
+        Agent, Simulation, SimulationConfig,
+        stats::{AgentStats, Proportion},
+    },
+};
+
+use super::{
+    AgentKind, CommandResult, GameOptions, SeatKinds, get_agent, get_default_names, get_threads,
+};
+
+#[derive(Debug, Args)]
+pub struct SimulateArgs {
+    #[command(flatten)]
+    game: GameOptions,
+    #[command(flatten)]
+    seats: SeatKinds<AgentKind>,
+    /// Number of matches to play.
+    #[arg(long, visible_alias = "matches", default_value_t = 100)]
+    games: usize,
+    /// Points a team needs to win a match.
+    #[arg(long, default_value_t = DEFAULT_TARGET_POINTS)]
+    target: usize,
+    /// Threads playing the matches, all cores are used when missing.
+    #[arg(long)]
+    threads: Option<usize>,
+    /// Play every deal once for each seating of the agents.
+    #[arg(long)]
+    duplicate: bool,
+}
+
+/// Agent of every team, the bot chosen for its seats. Seats without a
+/// choice play the bot of their team mates or the heuristic bot, the teams
+/// change seats between matches.
+fn get_team_agents(
+    seats: &SeatKinds<AgentKind>,
+    table_config: &TableConfig,
+) -> Result<Vec<Agent>, String> {
+    let chosen_kinds = seats.get_chosen_kinds();
+    table_config
+        .get_teams()
+        .into_iter()
+        .map(|team| {
+            let mut kinds = table_config
+                .seats
+                .iter()
+                .zip(chosen_kinds)
+                .filter(|(seat_config, _)| seat_config.team == team)
+                .filter_map(|(_, kind)| kind)
+                .collect::<Vec<_>>();
+            kinds.dedup();
+            match kinds[..] {
+                [] => Ok(get_agent(AgentKind::Heuristic)),
+                [kind] => Ok(get_agent(kind)),
+                _ => Err(format!(
+                    "both seats of team {} need the same bot, every team plays one",
+                    <&str>::from(team)
+                )),
+            }
+        })
+        .collect()
+}
+
+fn format_percent(proportion: &Proportion) -> String {
+    let (low, high) = proportion.get_confidence_interval();
+    format!(
+        "{:.1}% [{:.1}%, {:.1}%]",
+        proportion.get_rate() * 100.0,
+        low * 100.0,
+        high * 100.0
+    )
+}
+
+fn print_stats(index: usize, stats: &AgentStats) {
+    println!("Agent {} ({})", index + 1, stats.name);
+    println!(
+        "  matches won:      {} of {}, {}",
+        stats.matches_won.successes,
+        stats.matches_won.trials,
+        format_percent(&stats.matches_won)
+    );
+    println!(
+        "  points per round: {:.1} ± {:.1}",
+        stats.round_points.get_mean(),
+        stats.round_points.get_margin()
+    );
+    println!(
+        "  fell as caller:   {} of {}, {}",
+        stats.falls.successes,
+        stats.falls.trials,
+        format_percent(&stats.falls)
+    );
+    println!(
+        "  štiglje:          {} of {}, {}",
+        stats.stiglje.successes,
+        stats.stiglje.trials,
+        format_percent(&stats.stiglje)
+    );
+}
+
+pub fn run(args: SimulateArgs) -> CommandResult {
+    let number_of_players = args.game.get_rules().variant.get_number_of_players();
+    let table_config = args
+        .game
+        .get_table_config(get_default_names(number_of_players));
+    let agents = get_team_agents(&args.seats, &table_config)?;
+    let seed = args.game.seed.unwrap_or_else(rand::random);
+    let config = SimulationConfig {
+        table_config,
+        number_of_matches: args.games,
+        target_points: args.target,
+        seed,
+        threads: get_threads(args.threads),
+        duplicate_deals: args.duplicate,
+    };
+    let stats = Simulation::new(config, agents).run().agent_stats;
+
+    let matches_played = stats[0].matches_won.trials;
+    println!("{} matches played with seed {}", matches_played, seed);
+    for (index, agent_stats) in stats.iter().enumerate() {
+        print_stats(index, agent_stats);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use belot_game_optmal::{
+    clients::heuristic_round_player::HeuristicRoundPlayer,
+    game::{
+        deck::get_card_names, round::Round, round_player::RoundPlayer, solver::DoubleDummySolver,
+        trump::Trump,
+    },
+};
+
+use super::{CommandResult, GameOptions, get_default_names, read_records};
+
+#[derive(Debug, Args)]
+pub struct SolveArgs {
+    #[command(flatten)]
+    game: GameOptions,
+    /// Record file with the deal, a new deal is made without it.
+    deal: Option<PathBuf>,
+    /// Round of the record to solve, counting from 1.
+    #[arg(long, default_value_t = 1)]
+    round: usize,
+}
+
+fn get_round(args: &SolveArgs) -> Result<Round, Box<dyn std::error::Error>> {
+    if let Some(path) = &args.deal {
+        let records = read_records(path)?;
+        let record = records.get(args.round.saturating_sub(1)).ok_or(format!(
+            "{} has only {} rounds",
+            path.display(),
+            records.len()
+        ))?;
+        let mut round = Round::from_deal(&record.table_config, &record.deal);
+        round.trump = record.trump.clone();
+
+        return Ok(round);
+    }
+
+    let rules = args.game.get_rules();
+    let names = get_default_names(rules.variant.get_number_of_players());
+    let mut round = Round::new(&args.game.get_table_config(names));
+    let seat = round.seat_turn;
+    let kind = HeuristicRoundPlayer.must_call_contract(&round, seat);
+    round.trump = Trump { seat, kind };
+
+    Ok(round)
+}
+
+pub fn run(args: SolveArgs) -> CommandResult {
+    let round = get_round(&args)?;
+    for player in &round.players {
+        println!(
+            "{:>8}: {}",
+            player.name,
+            get_card_names(player.hand.cards())
+        );
+    }
+    let caller = round.get_player(round.trump.seat);
+    println!(
+        "Contract {} called by {}",
+        round.trump.kind.get_name(),
+        caller.name
+    );
+
+    for team in round.players.get_teams() {
+        let team_name: &str = team.into();
+        let mut solver = DoubleDummySolver::new(&round, team);
+        println!(
+            "TEAM {} takes {} of {} card points",
+            team_name,
+            solver.solve(),
+            solver.get_remaining_points()
+        );
+    }
+
+    let leader = round.get_player(round.current_trick.get_seat_turn());
+    println!("Cards for {} to lead:", leader.name);
+    let mut solver = DoubleDummySolver::new(&round, leader.get_team());
+    for solved_card in solver.solve_cards() {
+        println!(
+            "{:>14}: {}",
+            solved_card.card.get_name(),
+            solved_card.points
+        );
+    }
+
+    Ok(())
+}
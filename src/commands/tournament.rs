@@ -0,0 +1,76 @@
+use clap::Args;
+
+use belot_game_optmal::simulation::tournament::{Tournament, TournamentConfig};
+
+use super::{AgentKind, CommandResult, GameOptions, get_agents, get_default_names, get_threads};
+
+#[derive(Debug, Args)]
+pub struct TournamentArgs {
+    #[command(flatten)]
+    game: GameOptions,
+    /// Bot playing for every team, separated by commas. Every team gets a
+    /// heuristic bot when missing.
+    #[arg(long, value_enum, value_delimiter = ',')]
+    agents: Vec<AgentKind>,
+    /// Number of deals every agent plays from every seat.
+    #[arg(long, default_value_t = 100)]
+    boards: usize,
+    /// Threads playing the boards, all cores are used when missing.
+    #[arg(long)]
+    threads: Option<usize>,
+    /// Print the result of every board.
+    #[arg(long)]
+    show_boards: bool,
+}
+
+pub fn run(args: TournamentArgs) -> CommandResult {
+    let number_of_players = args.game.get_rules().variant.get_number_of_players();
+    let table_config = args
+        .game
+        .get_table_config(get_default_names(number_of_players));
+    let agents = get_agents(&args.agents, table_config.get_teams().len())?;
+    let seed = args.game.seed.unwrap_or_else(rand::random);
+    let config = TournamentConfig {
+        table_config,
+        number_of_boards: args.boards,
+        seed,
+        threads: get_threads(args.threads),
+    };
+    let result = Tournament::new(config, agents).run();
+
+    if args.show_boards {
+        for (index, board) in result.boards.iter().enumerate() {
+            let scores = result
+                .standings
+                .iter()
+                .enumerate()
+                .map(|(agent_index, standing)| {
+                    format!(
+                        "{} {} ({:+} IMP)",
+                        standing.name, board.points[agent_index], board.imps[agent_index]
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            println!("Board {}: {}", index + 1, scores);
+        }
+        println!();
+    }
+
+    println!("{} boards played with seed {}", args.boards, seed);
+    for (index, standing) in result.standings.iter().enumerate() {
+        let imps_per_board = standing.imps as f64 / args.boards.max(1) as f64;
+        println!(
+            "Agent {} ({}): {:+} IMP, {:+.2} per board, {:+} points, {} boards won, {} lost",
+            index + 1,
+            standing.name,
+            standing.imps,
+            imps_per_board,
+            standing.swing,
+            standing.boards_won,
+            standing.boards_lost
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use strum::IntoEnumIterator;
+
+use super::{deck::CardSuit, kontra::Doubling, table::Seat, team::Team, trump::ContractKind};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Bid {
+    Pass,
+    Contract(ContractKind),
+    /// Coinche, the opponents of the highest bidder double the contract.
+    Double,
+    /// Surcoinche, the highest bidder's team answers a double.
+    Redouble,
+}
+
+impl Bid {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Pass => "Dalje",
+            Self::Contract(kind) => kind.get_name(),
+            Self::Double => "Kontra",
+            Self::Redouble => "Rekontra",
+        }
+    }
+
+    /// Bid with the name given by [`Bid::get_name`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::Pass, Self::Double, Self::Redouble]
+            .into_iter()
+            .chain(get_all_contract_kinds().into_iter().map(Self::Contract))
+            .find(|bid| bid.get_name() == name)
+    }
+}
+
+/// Times the cards are dealt again after everybody passed, once the
+/// auction of the last redeal is passed too the dealer has to call the
+/// contract.
+pub const MAX_REDEALS: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct BidWithPlayerInfo {
+    pub bid: Bid,
+    pub seat: Seat,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contract {
+    pub kind: ContractKind,
+    pub seat: Seat,
+    pub doubling: Doubling,
+}
+
+/// Every contract from the lowest bid to the highest.
+pub fn get_all_contract_kinds() -> Vec<ContractKind> {
+    let mut kinds: Vec<_> = CardSuit::iter()
+        .map(ContractKind::Suit)
+        .chain([ContractKind::NoTrumps, ContractKind::AllTrumps])
+        .collect();
+    kinds.sort_by_key(ContractKind::get_bid_rank);
+
+    kinds
+}
+
+/// Turns a call-or-pass style contract choice into a bid, passing when
+/// the contract can't be bid anymore.
+pub fn get_bid_for_contract_call(contract: Option<ContractKind>, valid_bids: &[Bid]) -> Bid {
+    match contract {
+        Some(kind) if valid_bids.contains(&Bid::Contract(kind.clone())) => Bid::Contract(kind),
+        _ => Bid::Pass,
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Auction {
+    /// Team of every seat at the table.
+    teams: Vec<Team>,
+    seat_turn: Seat,
+    pub bids: Vec<BidWithPlayerInfo>,
+    highest_bid: Option<(Seat, ContractKind)>,
+    doubling: Doubling,
+    consecutive_passes: usize,
+}
+
+impl Auction {
+    pub fn new(first_seat: Seat, teams: Vec<Team>) -> Self {
+        Self {
+            teams,
+            seat_turn: first_seat,
+            ..Default::default()
+        }
+    }
+
+    pub fn get_seat_turn(&self) -> Seat {
+        self.seat_turn
+    }
+
+    /// Auction closes once every other player passes after a contract bid,
+    /// or when every player passes without bidding.
+    pub fn is_done(&self) -> bool {
+        let number_of_players = self.teams.len();
+        match self.highest_bid {
+            Some(_) => self.consecutive_passes >= number_of_players - 1,
+            None => self.consecutive_passes >= number_of_players,
+        }
+    }
+
+    pub fn get_valid_bids(&self) -> Vec<Bid> {
+        let mut valid_bids = vec![Bid::Pass];
+        if self.is_done() {
+            return valid_bids;
+        }
+
+        let higher_contracts = get_all_contract_kinds()
+            .into_iter()
+            .filter(|kind| match &self.highest_bid {
+                Some((_, highest_kind)) => kind.get_bid_rank() > highest_kind.get_bid_rank(),
+                None => true,
+            })
+            .map(Bid::Contract);
+        valid_bids.extend(higher_contracts);
+
+        if let Some((bidder_seat, _)) = &self.highest_bid {
+            let is_bidder_team =
+                self.teams[bidder_seat.index()] == self.teams[self.seat_turn.index()];
+            match self.doubling {
+                Doubling::None if !is_bidder_team => valid_bids.push(Bid::Double),
+                Doubling::Kontra if is_bidder_team => valid_bids.push(Bid::Redouble),
+                _ => {}
+            }
+        }
+
+        valid_bids
+    }
+
+    pub fn make_bid(&mut self, bid: Bid) {
+        assert!(
+            self.get_valid_bids().contains(&bid),
+            "bid should be one of the valid bids"
+        );
+
+        let seat = self.seat_turn;
+        match &bid {
+            Bid::Pass => self.consecutive_passes += 1,
+            Bid::Contract(kind) => {
+                self.highest_bid = Some((seat, kind.clone()));
+                self.doubling = Doubling::None;
+                self.consecutive_passes = 0;
+            }
+            Bid::Double => {
+                self.doubling = Doubling::Kontra;
+                self.consecutive_passes = 0;
+            }
+            Bid::Redouble => {
+                self.doubling = Doubling::Rekontra;
+                self.consecutive_passes = 0;
+            }
+        }
+
+        self.bids.push(BidWithPlayerInfo { bid, seat });
+        self.seat_turn = self.seat_turn.next(self.teams.len());
+    }
+
+    /// Contract won in the auction, `None` while bidding goes on or when
+    /// every player passed.
+    pub fn get_contract(&self) -> Option<Contract> {
+        if !self.is_done() {
+            return None;
+        }
+        let (seat, kind) = self.highest_bid.clone()?;
+
+        Some(Contract {
+            kind,
+            seat,
+            doubling: self.doubling,
+        })
+    }
+}
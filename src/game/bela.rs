@@ -0,0 +1,27 @@
+use super::{
+    deck::{Card, CardValue},
+    table::Seat,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BelaAnnouncement {
+    pub seat: Seat,
+    /// Card that was played when bela was announced.
+    pub card: Card,
+    pub trick_index: usize,
+}
+
+impl BelaAnnouncement {
+    pub fn get_other_card(&self) -> Card {
+        get_other_bela_card(&self.card)
+    }
+}
+
+pub fn get_other_bela_card(card: &Card) -> Card {
+    let value = match card.value {
+        CardValue::Queen => CardValue::King,
+        _ => CardValue::Queen,
+    };
+
+    Card::new(card.suit.clone(), value)
+}
@@ -1,12 +1,13 @@
 use super::player::Players;
 use crate::game::trump::Trump;
-use rand::random_range;
+use rand::Rng;
 use strum::IntoEnumIterator;
 use strum_macros::{EnumCount, EnumIter, IntoStaticStr};
 
 #[derive(
     Debug, EnumIter, Clone, EnumCount, PartialEq, PartialOrd, Eq, Ord, Hash, IntoStaticStr,
 )]
+#[allow(clippy::upper_case_acronyms)]
 pub enum CardValue {
     VII,
     VIII,
@@ -41,12 +42,63 @@ impl Card {
     }
 
     pub fn is_bela_card(&self, trump: &Trump) -> bool {
-        if self.suit != trump.trump_suit {
+        if !trump.kind.is_trump_suit(&self.suit) {
             return false;
         }
 
         self.value == CardValue::Queen || self.value == CardValue::King
     }
+
+    /// Name used in round records, for example `Herz-Jack`.
+    pub fn get_name(&self) -> String {
+        let suit: &str = self.suit.clone().into();
+        let value: &str = self.value.clone().into();
+        format!("{}-{}", suit, value)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let (suit_name, value_name) = name.split_once('-')?;
+        let suit = CardSuit::iter().find(|suit| {
+            let name: &str = suit.clone().into();
+            name == suit_name
+        })?;
+        let value = CardValue::iter().find(|value| {
+            let name: &str = value.clone().into();
+            name == value_name
+        })?;
+
+        Some(Self::new(suit, value))
+    }
+}
+
+/// Card names separated by spaces, see [`Card::get_name`].
+pub fn get_card_names(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(Card::get_name)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Cards of every seat right after dealing, together with the talon.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Deal {
+    pub hands: Vec<Vec<Card>>,
+    pub talon: Vec<Card>,
+}
+
+impl Deal {
+    pub fn new(players: &Players, talon: &[Card]) -> Self {
+        let hands = players
+            .into_iter()
+            .map(|player| player.get_hand().cards().clone())
+            .collect();
+
+        Self {
+            hands,
+            talon: talon.to_vec(),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -54,6 +106,12 @@ pub struct Deck {
     pub deck: Vec<Card>,
 }
 
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Deck {
     pub fn new() -> Self {
         let mut deck: Vec<Card> = vec![];
@@ -67,7 +125,7 @@ impl Deck {
         Self { deck }
     }
 
-    fn deal_card(&mut self) -> Option<Card> {
+    fn deal_card(&mut self, rng: &mut impl Rng) -> Option<Card> {
         let len = self.deck.len();
         if len == 0 {
             return None;
@@ -76,20 +134,30 @@ impl Deck {
             let card = self.deck.pop().expect("should be 1 card in deck");
             return Some(card);
         }
-        let random_index = random_range(0..len);
+        let random_index = rng.random_range(0..len);
         let card = self.deck[random_index].clone();
         self.deck[random_index] = self.deck.last().unwrap().clone();
         self.deck.pop().expect("should be at least 1 card in deck");
         Some(card)
     }
 
-    pub fn shuffle_deal(&mut self, players: &mut Players) {
-        while self.deck.len() > 0 {
-            let card = self.deal_card();
+    /// Deals a full hand to every player and returns the cards left over
+    /// as the talon.
+    pub fn shuffle_deal(&mut self, players: &mut Players, rng: &mut impl Rng) -> Vec<Card> {
+        let hand_size = players.get_variant().get_hand_size();
+        let cards_to_deal = hand_size * players.get_number_of_players();
+        for _ in 0..cards_to_deal {
+            let card = self.deal_card(rng);
             if let Some(card) = card {
                 players.give_card_to_next_player(card);
             }
         }
+
+        let mut talon = vec![];
+        while let Some(card) = self.deal_card(rng) {
+            talon.push(card);
+        }
+
+        talon
     }
 }
-
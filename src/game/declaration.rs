@@ -5,7 +5,9 @@ use strum::{EnumCount, IntoEnumIterator};
 
 use super::{
     deck::{Card, CardSuit, CardValue},
-    player::Hand, team::Team,
+    player::Hand,
+    table::Seat,
+    team::Team,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -95,7 +97,7 @@ fn get_scales_by_suit(suit: &CardSuit, hand: &Hand) -> Vec<Declaration> {
     let mut result_declarations: Vec<Declaration> = vec![];
     let mut cards = hand
         .cards()
-        .into_iter()
+        .iter()
         .filter(|card| card.suit == *suit)
         .collect::<Vec<_>>();
     cards.sort_by_key(|card| get_scale_index(&card.value));
@@ -120,9 +122,8 @@ fn get_scales_by_suit(suit: &CardSuit, hand: &Hand) -> Vec<Declaration> {
         }
 
         let declaration_cards = cards[i..j]
-            .to_vec()
-            .into_iter()
-            .map(|card| card.clone())
+            .iter()
+            .map(|&card| card.clone())
             .collect::<Vec<_>>();
         let declaration = Declaration {
             points,
@@ -152,7 +153,7 @@ pub fn get_possible_declarations(hand: &Hand) -> Vec<Declaration> {
 
     scale_declarations
         .into_iter()
-        .chain(four_of_a_kind_declarations.into_iter())
+        .chain(four_of_a_kind_declarations)
         .collect()
 }
 
@@ -164,7 +165,7 @@ pub struct TeamDeclarations {
 #[derive(Debug, Clone)]
 pub struct DeclaratonWithPlayerInfo {
     pub declaration: Declaration,
-    pub player_index: usize,
+    pub seat: Seat,
 }
 
 impl TeamDeclarations {
@@ -173,7 +174,7 @@ impl TeamDeclarations {
         let team_index = player_team.to_index();
         let declaraiton_with_player_info = DeclaratonWithPlayerInfo {
             declaration,
-            player_index: player.get_index(),
+            seat: player.get_seat(),
         };
         self.declarations[team_index].push(declaraiton_with_player_info);
     }
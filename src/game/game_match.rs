@@ -0,0 +1,116 @@
+use super::{
+    deck::Deal,
+    round::Round,
+    round_observer::RoundObserver,
+    round_player::RoundPlayer,
+    round_result::RoundResult,
+    table::TableConfig,
+    team::{Team, TeamPoints},
+};
+
+/// Points a team needs to win a match.
+pub const DEFAULT_TARGET_POINTS: usize = 1001;
+
+/// Series of rounds played until a team reaches the target points. The deal
+/// moves clockwise after every round.
+#[derive(Debug, Clone)]
+pub struct Match {
+    table_config: TableConfig,
+    pub target_points: usize,
+    pub points: TeamPoints,
+    pub round_results: Vec<RoundResult>,
+}
+
+impl Match {
+    pub fn new(table_config: TableConfig, target_points: usize) -> Self {
+        Self {
+            table_config,
+            target_points,
+            points: TeamPoints::default(),
+            round_results: vec![],
+        }
+    }
+
+    pub fn get_teams(&self) -> Vec<Team> {
+        self.table_config.get_teams()
+    }
+
+    /// Table of the next round, with the next dealer and, when the match is
+    /// seeded, a seed of its own so every round gets a different deal.
+    pub fn get_next_table_config(&self) -> TableConfig {
+        self.get_round_table_config(self.round_results.len())
+    }
+
+    /// Table of the round with the given index, counting from 0. Matches
+    /// with the same table and seed get the same deals in every round.
+    pub fn get_round_table_config(&self, round_index: usize) -> TableConfig {
+        let number_of_players = self.table_config.get_number_of_players();
+        let mut table_config = self.table_config.clone();
+        table_config.dealer = table_config.dealer.offset(round_index, number_of_players);
+        table_config.seed = table_config
+            .seed
+            .map(|seed| seed.wrapping_add(round_index as u64));
+
+        table_config
+    }
+
+    /// Plays the next round and returns it once it's finished.
+    pub fn play_round(
+        &mut self,
+        round_player: Box<dyn RoundPlayer>,
+        observer: &dyn RoundObserver,
+    ) -> Round {
+        let round = Round::new(&self.get_next_table_config());
+        self.finish_round(round, round_player, observer)
+    }
+
+    /// Plays the next round with the cards of `deal` instead of a shuffled
+    /// deck, a redeal comes from `seed`.
+    pub fn play_round_with_deal(
+        &mut self,
+        deal: &Deal,
+        seed: u64,
+        round_player: Box<dyn RoundPlayer>,
+        observer: &dyn RoundObserver,
+    ) -> Round {
+        let table_config = self.get_next_table_config().with_seed(Some(seed));
+        let round = Round::from_deal(&table_config, deal);
+        self.finish_round(round, round_player, observer)
+    }
+
+    fn finish_round(
+        &mut self,
+        mut round: Round,
+        round_player: Box<dyn RoundPlayer>,
+        observer: &dyn RoundObserver,
+    ) -> Round {
+        round.play_round(round_player, observer);
+
+        let round_result = round.get_result();
+        for team in self.get_teams() {
+            self.points
+                .add_points(team, round_result.points.get_points(team));
+        }
+        self.round_results.push(round_result);
+
+        round
+    }
+
+    /// Team with the most points once somebody reached the target, there is
+    /// no winner while the leading teams are tied.
+    pub fn get_winner(&self) -> Option<Team> {
+        let mut teams = self.get_teams();
+        teams.sort_by_key(|team| std::cmp::Reverse(self.points.get_points(*team)));
+        let leader = teams[0];
+        let leader_points = self.points.get_points(leader);
+        let is_tied = teams[1..]
+            .iter()
+            .any(|team| self.points.get_points(*team) == leader_points);
+
+        (leader_points >= self.target_points && !is_tied).then_some(leader)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.get_winner().is_some()
+    }
+}
@@ -0,0 +1,19 @@
+use strum_macros::{EnumIter, IntoStaticStr};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumIter, IntoStaticStr)]
+pub enum Doubling {
+    #[default]
+    None,
+    Kontra,
+    Rekontra,
+}
+
+impl Doubling {
+    pub fn get_multiplier(self) -> usize {
+        match self {
+            Self::None => 1,
+            Self::Kontra => 2,
+            Self::Rekontra => 4,
+        }
+    }
+}
@@ -1,15 +1,57 @@
+pub mod auction;
+pub mod bela;
 pub mod deck;
+pub mod declaration;
+pub mod game_match;
+pub mod kontra;
 pub mod player;
-pub mod round;
-pub mod trick;
+pub mod player_view;
 pub mod points;
-pub mod declaration;
+pub mod record;
+pub mod round;
+pub mod round_observer;
 pub mod round_player;
-pub mod trump;
+pub mod round_result;
+pub mod rules;
+pub mod solver;
+pub mod table;
 pub mod team;
+pub mod trick;
+pub mod trump;
+pub mod visibility;
+
+#[cfg(test)]
+mod test_utils;
 
 #[cfg(test)]
 mod test_declaration;
 
 #[cfg(test)]
 mod test_trick;
+
+#[cfg(test)]
+mod test_points;
+
+#[cfg(test)]
+mod test_bela;
+
+#[cfg(test)]
+mod test_kontra;
+
+#[cfg(test)]
+mod test_auction;
+
+#[cfg(test)]
+mod test_three_players;
+
+#[cfg(test)]
+mod test_table;
+
+#[cfg(test)]
+mod test_solver;
+
+#[cfg(test)]
+mod test_record;
+
+#[cfg(test)]
+mod test_visibility;
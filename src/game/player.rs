@@ -1,9 +1,9 @@
-use crate::game::trump::Trump;
 use super::{
-    deck::{Card, CardValue}, team::Team,
+    deck::{Card, CardSuit, CardValue},
+    rules::GameVariant,
+    table::{Seat, SeatConfig},
+    team::Team,
 };
-pub const NUMBER_OF_PLAYERS: usize = 4;
-
 
 #[derive(Default, Debug, Clone)]
 pub struct Hand {
@@ -33,7 +33,7 @@ impl Hand {
     }
 
     pub fn empty(&self) -> bool {
-        self.cards().len() == 0
+        self.cards().is_empty()
     }
 
     pub fn remove_card(&mut self, card: &Card) -> Option<Card> {
@@ -43,17 +43,12 @@ impl Hand {
         Some(removed_card)
     }
 
-    pub fn has_bela(&self, trump: &Trump) -> bool {
-        let trump_cards = self
-            .cards()
-            .iter()
-            .filter(|card| card.suit == trump.trump_suit);
-        trump_cards
+    pub fn has_bela(&self, suit: &CardSuit) -> bool {
+        let suit_cards = self.cards().iter().filter(|card| card.suit == *suit);
+        suit_cards
             .clone()
             .any(|card| card.value == CardValue::Queen)
-            && trump_cards
-                .clone()
-                .any(|card| card.value == CardValue::King)
+            && suit_cards.clone().any(|card| card.value == CardValue::King)
     }
 }
 
@@ -61,7 +56,8 @@ impl Hand {
 pub struct Player {
     pub name: String,
     pub hand: Hand,
-    pub index: usize,
+    pub seat: Seat,
+    pub team: Team,
 }
 
 impl Player {
@@ -82,15 +78,11 @@ impl Player {
     }
 
     pub fn get_team(&self) -> Team {
-        if self.index % 2 == 0 {
-            Team::A
-        } else {
-            Team::B
-        }
+        self.team
     }
 
-    pub fn get_index(&self) -> usize {
-        self.index
+    pub fn get_seat(&self) -> Seat {
+        self.seat
     }
 
     pub fn has_cards(&self) -> bool {
@@ -102,22 +94,74 @@ impl Player {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct Players {
     player_turn: usize,
-    pub players: [Player; NUMBER_OF_PLAYERS],
+    variant: GameVariant,
+    pub players: Vec<Player>,
+}
+
+impl Default for Players {
+    fn default() -> Self {
+        let variant = GameVariant::default();
+        let seats = (0..variant.get_number_of_players())
+            .map(|index| SeatConfig {
+                name: String::new(),
+                team: variant.get_team(index),
+            })
+            .collect::<Vec<_>>();
+
+        Self::new(&seats, variant)
+    }
 }
 
 impl Players {
-    pub fn new(player_names: [&'static str; NUMBER_OF_PLAYERS]) -> Self {
-        let mut players = Players::default();
-        players.set_turn(0);
-        for (index, player) in players.players.iter_mut().enumerate() {
-            player.index = index;
-            player.name = player_names[index].to_owned()
+    pub fn new(seats: &[SeatConfig], variant: GameVariant) -> Self {
+        assert_eq!(seats.len(), variant.get_number_of_players());
+        let players = seats
+            .iter()
+            .enumerate()
+            .map(|(index, seat_config)| Player {
+                name: seat_config.name.clone(),
+                hand: Hand::default(),
+                seat: Seat::new(index),
+                team: seat_config.team,
+            })
+            .collect();
+
+        Players {
+            player_turn: 0,
+            variant,
+            players,
         }
+    }
+
+    pub fn get_number_of_players(&self) -> usize {
+        self.players.len()
+    }
+
+    pub fn get_variant(&self) -> GameVariant {
+        self.variant
+    }
+
+    pub fn get_team(&self, seat: Seat) -> Team {
+        self.players[seat.index()].get_team()
+    }
 
-        players
+    /// Team of every seat, indexed by seat.
+    pub fn get_seat_teams(&self) -> Vec<Team> {
+        self.players.iter().map(Player::get_team).collect()
+    }
+
+    /// Distinct teams playing at the table, in seat order.
+    pub fn get_teams(&self) -> Vec<Team> {
+        let mut teams = vec![];
+        for team in self.get_seat_teams() {
+            if !teams.contains(&team) {
+                teams.push(team);
+            }
+        }
+        teams
     }
 
     pub fn give_card_to_next_player(&mut self, card: Card) {
@@ -126,23 +170,27 @@ impl Players {
     }
 
     pub fn set_turn(&mut self, turn: usize) {
-        assert!(turn < NUMBER_OF_PLAYERS);
+        assert!(turn < self.get_number_of_players());
         self.player_turn = turn;
     }
 
     pub fn increment_turn(&mut self) {
         self.player_turn += 1;
-        self.player_turn %= NUMBER_OF_PLAYERS;
+        self.player_turn %= self.get_number_of_players();
     }
 
     pub fn sort_hands(&mut self) {
-        for player in self.players.as_mut() {
+        for player in self.players.iter_mut() {
             player.sort_hand();
         }
     }
 
-    pub fn get(&self, index: usize) -> Option<&Player> {
-        self.players.get(index)
+    pub fn get(&self, seat: Seat) -> Option<&Player> {
+        self.players.get(seat.index())
+    }
+
+    pub fn get_mut(&mut self, seat: Seat) -> Option<&mut Player> {
+        self.players.get_mut(seat.index())
     }
 
     pub fn have_cards(&self) -> bool {
@@ -0,0 +1,81 @@
+use super::{
+    auction::BidWithPlayerInfo,
+    bela::BelaAnnouncement,
+    deck::Card,
+    declaration::TeamDeclarations,
+    player::Hand,
+    round::Round,
+    table::Seat,
+    trick::{Trick, TrickHistoryItem},
+    trump::Trump,
+};
+
+/// Everything a single player is allowed to know about the round.
+#[derive(Debug, Clone)]
+pub struct PlayerView {
+    pub seat: Seat,
+    pub hand: Hand,
+    pub trump: Trump,
+    pub auction_bids: Vec<BidWithPlayerInfo>,
+    pub current_trick: Trick,
+    pub trick_history: Vec<TrickHistoryItem>,
+    pub team_declarations: TeamDeclarations,
+    pub bela_announcements: Vec<BelaAnnouncement>,
+}
+
+impl PlayerView {
+    pub fn new(round_state: &Round, seat: Seat) -> Self {
+        let hand = round_state.get_player(seat).hand.clone();
+
+        Self {
+            seat,
+            hand,
+            trump: round_state.trump.clone(),
+            auction_bids: round_state.auction.bids.clone(),
+            current_trick: round_state.current_trick.clone(),
+            trick_history: round_state.trick_history.clone(),
+            team_declarations: round_state.team_declarations.clone(),
+            bela_announcements: round_state.bela_announcements.clone(),
+        }
+    }
+
+    pub fn is_card_played(&self, card: &Card) -> bool {
+        self.current_trick.cards_on_table.contains(card)
+            || self
+                .trick_history
+                .iter()
+                .any(|item| item.trick.cards_on_table.contains(card))
+    }
+
+    /// Cards that other players revealed through declarations or bela and
+    /// still hold, paired with the index of the player holding them.
+    pub fn get_known_cards(&self) -> Vec<(Seat, Card)> {
+        let declared_cards = self
+            .team_declarations
+            .declarations
+            .iter()
+            .flatten()
+            .flat_map(|info| {
+                info.declaration
+                    .cards
+                    .iter()
+                    .map(|card| (info.seat, card.clone()))
+            });
+        let bela_cards = self
+            .bela_announcements
+            .iter()
+            .map(|announcement| (announcement.seat, announcement.get_other_card()));
+
+        let mut known_cards: Vec<(Seat, Card)> = vec![];
+        for (seat, card) in declared_cards.chain(bela_cards) {
+            if seat == self.seat || self.is_card_played(&card) {
+                continue;
+            }
+            if !known_cards.contains(&(seat, card.clone())) {
+                known_cards.push((seat, card));
+            }
+        }
+
+        known_cards
+    }
+}
@@ -1,4 +1,7 @@
-use super::deck::{Card, CardSuit, CardValue};
+use super::{
+    deck::{Card, CardSuit, CardValue},
+    trump::ContractKind,
+};
 
 pub fn get_normal_points(card_value: &CardValue) -> usize {
     match card_value {
@@ -23,6 +26,42 @@ pub fn get_trump_points(card_value: &CardValue) -> usize {
     }
 }
 
+/// Points without trumps, the ace is worth more so a round still has 162
+/// points. Cards rank as in a non-trump suit.
+pub fn get_no_trumps_points(card_value: &CardValue) -> usize {
+    match card_value {
+        CardValue::VII | CardValue::VIII | CardValue::IX => 0,
+        CardValue::X => 10,
+        CardValue::Jack => 2,
+        CardValue::Queen => 3,
+        CardValue::King => 4,
+        CardValue::Kec => 19,
+    }
+}
+
+/// Points when every suit is trump, scaled down so a round still has 162
+/// points. Cards rank as in a trump suit.
+pub fn get_all_trumps_points(card_value: &CardValue) -> usize {
+    match card_value {
+        CardValue::VII | CardValue::VIII => 0,
+        CardValue::Queen => 1,
+        CardValue::King => 3,
+        CardValue::X => 5,
+        CardValue::Kec => 6,
+        CardValue::IX => 9,
+        CardValue::Jack => 14,
+    }
+}
+
+pub fn get_card_points(card: &Card, contract: &ContractKind) -> usize {
+    match contract {
+        ContractKind::Suit(trump) if *trump == card.suit => get_trump_points(&card.value),
+        ContractKind::Suit(_) => get_normal_points(&card.value),
+        ContractKind::NoTrumps => get_no_trumps_points(&card.value),
+        ContractKind::AllTrumps => get_all_trumps_points(&card.value),
+    }
+}
+
 pub fn better_than_normal(a: &Card, b: &Card) -> bool {
     let a_points = get_normal_points(&a.value);
     let b_points = get_normal_points(&b.value);
@@ -45,8 +84,8 @@ pub fn better_than_trump(a: &Card, b: &Card) -> bool {
     a_points > b_points
 }
 
-pub fn get_best_normal(cards: &Vec<Card>) -> Option<Card> {
-    let first_card = cards.get(0)?;
+pub fn get_best_normal(cards: &[Card]) -> Option<Card> {
+    let first_card = cards.first()?;
     let normal_suit = &first_card.suit;
     let best_card = cards
         .iter()
@@ -63,10 +102,10 @@ pub fn get_best_normal(cards: &Vec<Card>) -> Option<Card> {
     Some(best_card.clone())
 }
 
-pub fn get_best_trump(cards: &Vec<Card>, trump_color: CardSuit) -> Option<Card> {
+pub fn get_best_trump(cards: &[Card], trump_color: CardSuit) -> Option<Card> {
     cards
         .iter()
         .filter(|card| card.suit == trump_color)
         .reduce(|a, b| if better_than_trump(a, b) { a } else { b })
-        .map(|card| card.clone())
+        .cloned()
 }
@@ -0,0 +1,346 @@
+use std::{error::Error, fmt};
+
+use strum::IntoEnumIterator;
+
+use super::{
+    auction::{Bid, BidWithPlayerInfo},
+    deck::{Card, Deal, get_card_names},
+    declaration::{Declaration, DeclaratonWithPlayerInfo},
+    kontra::Doubling,
+    round::Round,
+    rules::{BelaAnnouncementRule, GameVariant, RuleSet},
+    table::{Seat, SeatConfig, TableConfig},
+    team::Team,
+    trump::{ContractKind, Trump},
+};
+
+/// Everything needed to replay a round: the table, the dealt cards and
+/// every decision that was made. Records are stored as plain text with one
+/// fact per line, so they are easy to read and to diff.
+#[derive(Debug, Clone)]
+pub struct RoundRecord {
+    pub table_config: TableConfig,
+    pub deal: Deal,
+    pub bids: Vec<BidWithPlayerInfo>,
+    pub trump: Trump,
+    pub doubling: Doubling,
+    pub declarations: Vec<DeclaratonWithPlayerInfo>,
+    pub bela_seats: Vec<Seat>,
+    pub plays: Vec<(Seat, Card)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "record line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for RecordError {}
+
+fn find_by_name<T: IntoEnumIterator + Into<&'static str> + Clone>(name: &str) -> Option<T> {
+    T::iter().find(|item| {
+        let item_name: &'static str = item.clone().into();
+        item_name == name
+    })
+}
+
+impl RoundRecord {
+    /// Records a finished round that was played at the given table.
+    pub fn new(table_config: &TableConfig, round_state: &Round) -> Self {
+        let plays = round_state
+            .trick_history
+            .iter()
+            .flat_map(|trick_item| {
+                let trick = &trick_item.trick;
+                let number_of_players = round_state.get_number_of_players();
+                trick
+                    .cards_on_table
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, card)| {
+                        let seat = trick.get_first_seat().offset(index, number_of_players);
+                        (seat, card.clone())
+                    })
+            })
+            .collect();
+
+        Self {
+            table_config: table_config.clone(),
+            deal: round_state.deal.clone(),
+            bids: round_state.auction.bids.clone(),
+            trump: round_state.trump.clone(),
+            doubling: round_state.doubling,
+            declarations: round_state
+                .team_declarations
+                .declarations
+                .iter()
+                .flatten()
+                .cloned()
+                .collect(),
+            bela_seats: round_state
+                .bela_announcements
+                .iter()
+                .map(|announcement| announcement.seat)
+                .collect(),
+            plays,
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let table_config = &self.table_config;
+        let rules = &table_config.rules;
+        let names = table_config
+            .seats
+            .iter()
+            .map(|seat_config| seat_config.name.as_str())
+            .collect::<Vec<_>>();
+        let teams = table_config
+            .seats
+            .iter()
+            .map(|seat_config| seat_config.team.into())
+            .collect::<Vec<&str>>();
+        let variant: &str = rules.variant.into();
+        let bela_announcement: &str = rules.bela_announcement.into();
+        let doubling: &str = self.doubling.into();
+
+        let mut lines = vec![
+            "round".to_string(),
+            format!("names {}", names.join(",")),
+            format!("teams {}", teams.join(",")),
+            format!("dealer {}", table_config.dealer.index()),
+            format!("variant {}", variant),
+            format!("bela_announcement {}", bela_announcement),
+            format!("kontra {}", rules.kontra),
+            format!("extended_contracts {}", rules.extended_contracts),
+            format!("auction {}", rules.auction),
+        ];
+        if let Some(seed) = table_config.seed {
+            lines.push(format!("seed {}", seed));
+        }
+        for (index, hand) in self.deal.hands.iter().enumerate() {
+            lines.push(format!("hand {} {}", index, get_card_names(hand)));
+        }
+        lines.push(format!("talon {}", get_card_names(&self.deal.talon)));
+        for bid in &self.bids {
+            lines.push(format!("bid {} {}", bid.seat.index(), bid.bid.get_name()));
+        }
+        lines.push(format!(
+            "trump {} {}",
+            self.trump.seat.index(),
+            self.trump.kind.get_name()
+        ));
+        lines.push(format!("doubling {}", doubling));
+        for info in &self.declarations {
+            lines.push(format!(
+                "declaration {} {} {}",
+                info.seat.index(),
+                info.declaration.points,
+                get_card_names(&info.declaration.cards)
+            ));
+        }
+        for seat in &self.bela_seats {
+            lines.push(format!("bela {}", seat.index()));
+        }
+        for (seat, card) in &self.plays {
+            lines.push(format!("play {} {}", seat.index(), card.get_name()));
+        }
+        lines.push("end".to_string());
+
+        lines.join("\n") + "\n"
+    }
+
+    /// Parses records written by [`RoundRecord::to_text`], a file can hold
+    /// any number of rounds.
+    pub fn parse_all(text: &str) -> Result<Vec<Self>, RecordError> {
+        let mut records = vec![];
+        let mut parser: Option<RecordParser> = None;
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            match (key, parser.as_mut()) {
+                ("round", None) => parser = Some(RecordParser::default()),
+                ("end", Some(round_parser)) => {
+                    records.push(round_parser.finish(line_number)?);
+                    parser = None;
+                }
+                (_, Some(round_parser)) => round_parser.parse_line(key, value, line_number)?,
+                (_, None) => {
+                    return Err(RecordError {
+                        line: line_number,
+                        message: format!("expected 'round' but found '{}'", key),
+                    });
+                }
+            }
+        }
+        if parser.is_some() {
+            return Err(RecordError {
+                line: text.lines().count(),
+                message: "last round is missing 'end'".to_string(),
+            });
+        }
+
+        Ok(records)
+    }
+}
+
+#[derive(Default)]
+struct RecordParser {
+    names: Vec<String>,
+    teams: Vec<Team>,
+    dealer: Option<Seat>,
+    rules: RuleSet,
+    seed: Option<u64>,
+    deal: Deal,
+    bids: Vec<BidWithPlayerInfo>,
+    trump: Option<Trump>,
+    doubling: Doubling,
+    declarations: Vec<DeclaratonWithPlayerInfo>,
+    bela_seats: Vec<Seat>,
+    plays: Vec<(Seat, Card)>,
+}
+
+impl RecordParser {
+    fn parse_line(&mut self, key: &str, value: &str, line: usize) -> Result<(), RecordError> {
+        let error = |message: String| RecordError { line, message };
+        let parse_seat = |value: &str| {
+            value
+                .parse::<usize>()
+                .map(Seat::new)
+                .map_err(|_| error(format!("invalid seat '{}'", value)))
+        };
+        let parse_bool = |value: &str| {
+            value
+                .parse::<bool>()
+                .map_err(|_| error(format!("invalid flag '{}'", value)))
+        };
+        let parse_cards = |value: &str| {
+            value
+                .split_whitespace()
+                .map(|name| Card::from_name(name).ok_or(error(format!("invalid card '{}'", name))))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let (first, rest) = value.split_once(' ').unwrap_or((value, ""));
+
+        match key {
+            "names" => self.names = value.split(',').map(String::from).collect(),
+            "teams" => {
+                self.teams = value
+                    .split(',')
+                    .map(|name| find_by_name(name).ok_or(error(format!("invalid team '{}'", name))))
+                    .collect::<Result<_, _>>()?;
+            }
+            "dealer" => self.dealer = Some(parse_seat(value)?),
+            "variant" => {
+                self.rules.variant = find_by_name::<GameVariant>(value)
+                    .ok_or(error(format!("invalid variant '{}'", value)))?;
+            }
+            "bela_announcement" => {
+                self.rules.bela_announcement = find_by_name::<BelaAnnouncementRule>(value)
+                    .ok_or(error(format!("invalid bela announcement '{}'", value)))?;
+            }
+            "kontra" => self.rules.kontra = parse_bool(value)?,
+            "extended_contracts" => self.rules.extended_contracts = parse_bool(value)?,
+            "auction" => self.rules.auction = parse_bool(value)?,
+            "seed" => {
+                let seed = value
+                    .parse::<u64>()
+                    .map_err(|_| error(format!("invalid seed '{}'", value)))?;
+                self.seed = Some(seed);
+            }
+            "hand" => {
+                if parse_seat(first)?.index() != self.deal.hands.len() {
+                    return Err(error("hands should be listed in seat order".to_string()));
+                }
+                self.deal.hands.push(parse_cards(rest)?);
+            }
+            "talon" => self.deal.talon = parse_cards(value)?,
+            "bid" => {
+                let bid = Bid::from_name(rest).ok_or(error(format!("invalid bid '{}'", rest)))?;
+                let seat = parse_seat(first)?;
+                self.bids.push(BidWithPlayerInfo { bid, seat });
+            }
+            "trump" => {
+                let kind = ContractKind::from_name(rest)
+                    .ok_or(error(format!("invalid contract '{}'", rest)))?;
+                let seat = parse_seat(first)?;
+                self.trump = Some(Trump { seat, kind });
+            }
+            "doubling" => {
+                self.doubling = find_by_name::<Doubling>(value)
+                    .ok_or(error(format!("invalid doubling '{}'", value)))?;
+            }
+            "declaration" => {
+                let seat = parse_seat(first)?;
+                let (points, cards) = rest.split_once(' ').unwrap_or((rest, ""));
+                let points = points
+                    .parse::<usize>()
+                    .map_err(|_| error(format!("invalid points '{}'", points)))?;
+                let declaration = Declaration {
+                    points,
+                    cards: parse_cards(cards)?,
+                };
+                self.declarations
+                    .push(DeclaratonWithPlayerInfo { declaration, seat });
+            }
+            "bela" => self.bela_seats.push(parse_seat(value)?),
+            "play" => {
+                let seat = parse_seat(first)?;
+                let card =
+                    Card::from_name(rest).ok_or(error(format!("invalid card '{}'", rest)))?;
+                self.plays.push((seat, card));
+            }
+            _ => return Err(error(format!("unknown key '{}'", key))),
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self, line: usize) -> Result<RoundRecord, RecordError> {
+        let parser = std::mem::take(self);
+        let error = |message: &str| RecordError {
+            line,
+            message: message.to_string(),
+        };
+        let number_of_players = parser.rules.variant.get_number_of_players();
+        if parser.names.len() != number_of_players || parser.teams.len() != number_of_players {
+            return Err(error("names and teams should be given for every seat"));
+        }
+        if parser.deal.hands.len() != number_of_players {
+            return Err(error("a hand should be given for every seat"));
+        }
+        let trump = parser.trump.ok_or(error("trump is missing"))?;
+        let seats = parser
+            .names
+            .into_iter()
+            .zip(parser.teams)
+            .map(|(name, team)| SeatConfig { name, team })
+            .collect();
+        let table_config = TableConfig {
+            seats,
+            dealer: parser.dealer.ok_or(error("dealer is missing"))?,
+            rules: parser.rules,
+            seed: parser.seed,
+        };
+
+        Ok(RoundRecord {
+            table_config,
+            deal: parser.deal,
+            bids: parser.bids,
+            trump,
+            doubling: parser.doubling,
+            declarations: parser.declarations,
+            bela_seats: parser.bela_seats,
+            plays: parser.plays,
+        })
+    }
+}
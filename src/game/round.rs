@@ -1,184 +1,411 @@
-use strum::IntoEnumIterator;
-
-use crate::game::team::Team;
+use crate::game::auction::{Auction, Bid, Contract, MAX_REDEALS};
+use crate::game::bela::{BelaAnnouncement, get_other_bela_card};
 use crate::game::declaration::DeclaratonWithPlayerInfo;
+use crate::game::kontra::Doubling;
+use crate::game::player_view::PlayerView;
+use crate::game::round_observer::RoundObserver;
 use crate::game::round_player::RoundPlayer;
+use crate::game::round_result::RoundResult;
+use crate::game::rules::{BelaAnnouncementRule, RuleSet};
+use crate::game::table::{Seat, TableConfig};
+use crate::game::team::Team;
+
+use rand::rngs::StdRng;
 
 use super::{
-    deck::{Card, Deck}, declaration::{get_possible_declarations, Declaration, TeamDeclarations}, player::{Player, Players, NUMBER_OF_PLAYERS}, team::TeamPoints, trick::{Trick, TrickHistoryItem}, trump::Trump 
+    deck::{Card, Deal, Deck},
+    declaration::{Declaration, TeamDeclarations, get_possible_declarations},
+    player::{Player, Players},
+    points::get_card_points,
+    team::TeamPoints,
+    trick::{Trick, TrickHistoryItem},
+    trump::{ContractKind, Trump},
 };
 
+#[derive(Clone)]
+#[non_exhaustive]
 pub enum RoundUpdateEvent<'a> {
     CardPlayed {
-        player_index: usize,
+        seat: Seat,
         card: Card,
     },
     TrumpCallEvent {
-        player_index: usize,
+        seat: Seat,
         trump: Option<&'a Trump>,
     },
+    BidMade {
+        seat: Seat,
+        bid: Bid,
+    },
+    Redealt,
     DeclarationsCalled(&'a Vec<DeclaratonWithPlayerInfo>),
-    BelaDeclared {
-        player_index: usize,
+    KontraCalled {
+        seat: Seat,
     },
+    RekontraCalled {
+        seat: Seat,
+    },
+    BelaDeclared(&'a BelaAnnouncement),
     TrickDone(TrickHistoryItem),
 }
 
 #[derive(Debug, Clone)]
 pub struct Round {
     pub players: Players,
-    pub player_turn_index: usize,
+    pub seat_turn: Seat,
     pub current_trick: Trick,
     pub trick_history: Vec<TrickHistoryItem>,
     pub trump: Trump,
     pub points: TeamPoints,
     pub final_points: TeamPoints,
     pub team_declarations: TeamDeclarations,
-    pub bela_declared: Option<Team>,
+    pub bela_announcements: Vec<BelaAnnouncement>,
+    pub doubling: Doubling,
+    pub auction: Auction,
+    /// Cards left over after the deal, only the three player variant has them.
+    pub talon: Vec<Card>,
+    /// Cards as they were dealt, before any of them was played.
+    pub deal: Deal,
+    pub rules: RuleSet,
+    rng: StdRng,
 }
 
 impl Round {
-    pub fn new(first_player_index: usize, player_names: [&'static str; NUMBER_OF_PLAYERS]) -> Self {
-        let player_turn_index = 0;
-        let mut deck = Deck::new();
-        let mut players = Players::new(player_names);
-        deck.shuffle_deal(&mut players);
+    pub fn new(table_config: &TableConfig) -> Self {
+        let mut rng = table_config.get_rng();
+        let mut players = Players::new(&table_config.seats, table_config.rules.variant);
+        let talon = Deck::new().shuffle_deal(&mut players, &mut rng);
+        players.sort_hands();
+
+        Self::with_players(table_config, players, talon, rng)
+    }
+
+    /// Sets up a round with already known cards, for example from a record.
+    pub fn from_deal(table_config: &TableConfig, deal: &Deal) -> Self {
+        let mut players = Players::new(&table_config.seats, table_config.rules.variant);
+        for (player, hand) in (&mut players).into_iter().zip(&deal.hands) {
+            player.recieve_cards(hand.clone());
+        }
         players.sort_hands();
 
+        Self::with_players(
+            table_config,
+            players,
+            deal.talon.clone(),
+            table_config.get_rng(),
+        )
+    }
+
+    fn with_players(
+        table_config: &TableConfig,
+        players: Players,
+        talon: Vec<Card>,
+        rng: StdRng,
+    ) -> Self {
+        let seat_turn = table_config.get_first_seat();
+        let number_of_players = players.get_number_of_players();
+        let deal = Deal::new(&players, &talon);
+
         Round {
             players,
-            player_turn_index: first_player_index,
-            current_trick: Trick::new(player_turn_index),
+            seat_turn,
+            current_trick: Trick::new(seat_turn, number_of_players),
             trick_history: vec![],
             trump: Trump::default(),
             points: TeamPoints::default(),
             final_points: TeamPoints::default(),
             team_declarations: TeamDeclarations::default(),
-            bela_declared: None,
+            bela_announcements: vec![],
+            doubling: Doubling::default(),
+            auction: Auction::default(),
+            talon,
+            deal,
+            rules: table_config.rules.clone(),
+            rng,
         }
     }
 
-    pub fn get_player_by_index(&self, player_index: usize) -> &Player {
+    pub fn get_number_of_players(&self) -> usize {
+        self.players.get_number_of_players()
+    }
+
+    pub fn get_player(&self, seat: Seat) -> &Player {
+        self.players.get(seat).expect("seat should be valid index")
+    }
+
+    pub fn get_player_view(&self, seat: Seat) -> PlayerView {
+        PlayerView::new(self, seat)
+    }
+
+    pub fn has_player_played_card(&self, seat: Seat, card: &Card) -> bool {
+        self.current_trick.has_player_played_card(seat, card)
+            || self
+                .trick_history
+                .iter()
+                .any(|item| item.trick.has_player_played_card(seat, card))
+    }
+
+    /// Team that took every trick of the round, if there is one.
+    pub fn is_stigl(&self) -> Option<Team> {
+        let first_winner = self.trick_history.first()?.team_winner;
+        let won_all_tricks = self
+            .trick_history
+            .iter()
+            .all(|trick_item| trick_item.team_winner == first_winner);
+
+        won_all_tricks.then_some(first_winner)
+    }
+
+    fn get_opponent_teams(&self, team: Team) -> Vec<Team> {
         self.players
-            .get(player_index)
-            .expect("player_index should be valid index")
+            .get_teams()
+            .into_iter()
+            .filter(|other_team| *other_team != team)
+            .collect()
     }
 
-    fn is_stigl(&self) -> Option<Team> {
-        let trick_history = &self.trick_history;
-        let team_a_trick_count: usize = trick_history.into_iter().fold(0, |acc, curr| {
-            if curr.team_winner == Team::A {
-                acc + 1
+    /// Contract the seat has to call when nobody else called one.
+    fn get_forced_contract(&self, round_player: &dyn RoundPlayer, seat: Seat) -> ContractKind {
+        if self.rules.extended_contracts {
+            round_player.must_call_contract(self, seat)
+        } else {
+            ContractKind::Suit(round_player.must_call_trump(self, seat))
+        }
+    }
+
+    fn get_trump(&mut self, round_player: &dyn RoundPlayer, observer: &dyn RoundObserver) -> Trump {
+        let number_of_players = self.get_number_of_players();
+        let last_offset = number_of_players - 1;
+        for offset in 0..last_offset {
+            let seat = self.seat_turn.offset(offset, number_of_players);
+            let contract = if self.rules.extended_contracts {
+                round_player.try_call_contract(self, seat)
             } else {
-                acc
+                round_player
+                    .try_call_trump(self, seat)
+                    .map(ContractKind::Suit)
+            };
+            if let Some(kind) = contract {
+                return Trump { kind, seat };
             }
-        });
 
-        const ALL_TRICKS_COUNT: usize = 8;
-        const NONE_TRICKS_COUNT: usize = 0;
+            observer.on_update(self, RoundUpdateEvent::TrumpCallEvent { seat, trump: None });
+        }
 
-        match team_a_trick_count {
-            ALL_TRICKS_COUNT => Some(Team::A),
-            NONE_TRICKS_COUNT => Some(Team::B),
-            _ => None,
+        let last_player = self.seat_turn.offset(last_offset, number_of_players);
+        Trump {
+            kind: self.get_forced_contract(round_player, last_player),
+            seat: last_player,
         }
     }
 
-    fn get_trump(&mut self, round_player: &Box<dyn RoundPlayer>) -> Trump {
-        let last_player_index = NUMBER_OF_PLAYERS - 1;
-        for i in 0..last_player_index {
-            let player_index = (i + self.player_turn_index) % NUMBER_OF_PLAYERS;
-            if let Some(suit) = round_player.try_call_trump(self, player_index) {
-                return Trump {
-                    trump_suit: suit,
-                    player_index,
-                };
-            }
-
-            round_player.on_update(
-                &self,
-                RoundUpdateEvent::TrumpCallEvent {
-                    player_index,
-                    trump: None,
-                },
-            );
+    fn redeal(&mut self) {
+        for player in &mut self.players {
+            player.recieve_cards(vec![]);
         }
+        self.talon = Deck::new().shuffle_deal(&mut self.players, &mut self.rng);
+        self.players.sort_hands();
+        self.deal = Deal::new(&self.players, &self.talon);
+    }
 
-        let last_player = (last_player_index + self.player_turn_index) % NUMBER_OF_PLAYERS;
-        let suit = round_player.must_call_trump(self, last_player);
+    fn run_auction(
+        &mut self,
+        round_player: &dyn RoundPlayer,
+        observer: &dyn RoundObserver,
+    ) -> Contract {
+        for redeal in 0..=MAX_REDEALS {
+            if redeal > 0 {
+                // everybody passed, so cards are dealt again
+                self.redeal();
+                observer.on_update(self, RoundUpdateEvent::Redealt);
+            }
+            self.auction = Auction::new(self.seat_turn, self.players.get_seat_teams());
+            while !self.auction.is_done() {
+                let seat = self.auction.get_seat_turn();
+                let valid_bids = self.auction.get_valid_bids();
+                let bid = round_player.make_bid(self, seat, valid_bids);
+                self.auction.make_bid(bid.clone());
+                observer.on_update(self, RoundUpdateEvent::BidMade { seat, bid });
+            }
+            if let Some(contract) = self.auction.get_contract() {
+                return contract;
+            }
+        }
 
-        Trump {
-            trump_suit: suit,
-            player_index: last_player,
+        // agents that never bid would pass forever, the dealer calls like
+        // without an auction
+        let number_of_players = self.get_number_of_players();
+        let dealer = self
+            .seat_turn
+            .offset(number_of_players - 1, number_of_players);
+        Contract {
+            kind: self.get_forced_contract(round_player, dealer),
+            seat: dealer,
+            doubling: Doubling::None,
         }
     }
 
-    fn play_trick(&mut self, round_player: &Box<dyn RoundPlayer>) -> TrickHistoryItem {
+    fn get_doubling(
+        &self,
+        round_player: &dyn RoundPlayer,
+        observer: &dyn RoundObserver,
+    ) -> Doubling {
+        let number_of_players = self.get_number_of_players();
+        let caller_seat = self.trump.seat;
+        let caller_team = self.players.get_team(caller_seat);
+        let (caller_seats, defender_seats): (Vec<Seat>, Vec<Seat>) = (0..number_of_players)
+            .map(|offset| caller_seat.offset(offset, number_of_players))
+            .partition(|&seat| self.players.get_team(seat) == caller_team);
+        let kontra_player = defender_seats
+            .into_iter()
+            .find(|&seat| round_player.will_call_kontra(self, seat));
+        let Some(kontra_player) = kontra_player else {
+            return Doubling::None;
+        };
+        observer.on_update(
+            self,
+            RoundUpdateEvent::KontraCalled {
+                seat: kontra_player,
+            },
+        );
+
+        let rekontra_player = caller_seats
+            .into_iter()
+            .find(|&seat| round_player.will_call_rekontra(self, seat));
+        let Some(rekontra_player) = rekontra_player else {
+            return Doubling::Kontra;
+        };
+        observer.on_update(
+            self,
+            RoundUpdateEvent::RekontraCalled {
+                seat: rekontra_player,
+            },
+        );
+
+        Doubling::Rekontra
+    }
+
+    fn play_trick(
+        &mut self,
+        round_player: &dyn RoundPlayer,
+        observer: &dyn RoundObserver,
+    ) -> TrickHistoryItem {
         while !self.current_trick.is_done() {
             let avaliable_cards = self
                 .current_trick
-                .get_playeble_cards(&self.players, &self.trump.trump_suit);
-            let player_index = self.current_trick.get_player_index_turn();
-            let played_card = round_player.play_card(&self, player_index, avaliable_cards);
-            let player = &mut self.players.players[player_index];
-            let has_bela = player.hand.has_bela(&self.trump);
+                .get_playeble_cards(&self.players, &self.trump.kind);
+            let seat = self.current_trick.get_seat_turn();
+            let played_card = round_player.play_card(self, seat, avaliable_cards);
+            let player = self
+                .players
+                .get_mut(seat)
+                .expect("seat should be valid index");
+            let has_bela = player.hand.has_bela(&played_card.suit);
             let played_card = player
                 .remove_card(&played_card)
                 .expect("Player to have card that needs to be removed");
-            if has_bela
-                && played_card.is_bela_card(&self.trump)
-                && round_player.will_declare_bella(&self, player_index)
-            {
-                self.bela_declared = Some(Team::from_player_index(player_index));
-                let bela_event = RoundUpdateEvent::BelaDeclared { player_index };
-                round_player.on_update(&self, bela_event);
-            }
+            self.try_announce_bela(round_player, observer, seat, &played_card, has_bela);
 
             self.current_trick.play_card(played_card.clone());
-            round_player.on_update(
-                &self,
+            observer.on_update(
+                self,
                 RoundUpdateEvent::CardPlayed {
                     card: played_card,
-                    player_index,
+                    seat,
                 },
             );
         }
-        let trick_history_item = TrickHistoryItem::new(&self, self.current_trick.clone());
+        let trick_history_item = TrickHistoryItem::new(self, self.current_trick.clone());
         self.trick_history.push(trick_history_item.clone());
-        self.player_turn_index = trick_history_item.player_index_winner;
-        self.current_trick = Trick::new(self.player_turn_index);
+        self.seat_turn = trick_history_item.winner_seat;
+        self.current_trick = Trick::new(self.seat_turn, self.get_number_of_players());
 
         trick_history_item
     }
 
-    fn try_publish_declaration_event(&self, round_player: &Box<dyn RoundPlayer>) {
+    fn try_announce_bela(
+        &mut self,
+        round_player: &dyn RoundPlayer,
+        observer: &dyn RoundObserver,
+        seat: Seat,
+        played_card: &Card,
+        had_bela: bool,
+    ) {
+        if !played_card.is_bela_card(&self.trump) {
+            return;
+        }
+        let can_announce = match self.rules.bela_announcement {
+            BelaAnnouncementRule::OnFirstCard => had_bela,
+            BelaAnnouncementRule::OnSecondCard => {
+                self.has_player_played_card(seat, &get_other_bela_card(played_card))
+            }
+        };
+        if !can_announce || !round_player.will_declare_bella(self, seat) {
+            return;
+        }
+
+        self.bela_announcements.push(BelaAnnouncement {
+            seat,
+            card: played_card.clone(),
+            trick_index: self.trick_history.len(),
+        });
+        let announcement = self
+            .bela_announcements
+            .last()
+            .expect("bela announcement was just added");
+        observer.on_update(self, RoundUpdateEvent::BelaDeclared(announcement));
+    }
+
+    fn try_publish_declaration_event(&self, observer: &dyn RoundObserver) {
         for team_declaration in self
             .team_declarations
             .declarations
             .iter()
-            .filter(|vec| vec.len() > 0)
+            .filter(|vec| !vec.is_empty())
         {
-            let round_event = RoundUpdateEvent::DeclarationsCalled(&team_declaration);
-            round_player.on_update(self, round_event);
+            let round_event = RoundUpdateEvent::DeclarationsCalled(team_declaration);
+            observer.on_update(self, round_event);
         }
     }
 
-    pub fn play_round(&mut self, round_player: Box<dyn RoundPlayer>) {
-        self.trump = self.get_trump(&round_player);
+    /// Plays the round to the end, the agent makes every decision and the
+    /// observer is told about everything that happens.
+    pub fn play_round(&mut self, round_player: Box<dyn RoundPlayer>, observer: &dyn RoundObserver) {
+        if self.rules.auction {
+            let contract = self.run_auction(round_player.as_ref(), observer);
+            self.trump = Trump {
+                kind: contract.kind,
+                seat: contract.seat,
+            };
+            self.doubling = contract.doubling;
+        } else {
+            self.trump = self.get_trump(round_player.as_ref(), observer);
+        }
         let trump_event = RoundUpdateEvent::TrumpCallEvent {
-            player_index: self.trump.player_index,
+            seat: self.trump.seat,
             trump: Some(&self.trump),
         };
-        round_player.on_update(&self, trump_event);
+        observer.on_update(self, trump_event);
+
+        if self.rules.kontra && !self.rules.auction {
+            self.doubling = self.get_doubling(round_player.as_ref(), observer);
+        }
 
-        self.team_declarations = self.get_declarations(&round_player);
-        self.try_publish_declaration_event(&round_player);
+        self.team_declarations = self.get_declarations(round_player.as_ref());
+        self.try_publish_declaration_event(observer);
 
+        self.finish_round(round_player.as_ref(), observer);
+    }
+
+    /// Plays the tricks that are left and scores the round. Continues a
+    /// round from any point of the card play, also from the middle of a
+    /// trick.
+    pub fn finish_round(&mut self, round_player: &dyn RoundPlayer, observer: &dyn RoundObserver) {
         while self.players.have_cards() {
-            let played_trick = self.play_trick(&round_player);
+            let played_trick = self.play_trick(round_player, observer);
             self.points
                 .add_points(played_trick.team_winner, played_trick.points);
-            round_player.on_update(&self, RoundUpdateEvent::TrickDone(played_trick));
+            observer.on_update(self, RoundUpdateEvent::TrickDone(played_trick));
         }
         let last_winner = &self
             .trick_history
@@ -186,19 +413,24 @@ impl Round {
             .expect("trick history should have all tricks so last trick must be present")
             .team_winner;
         const LAST_WINNER_ADDITIONAL_POINTS: usize = 10;
+        let talon_points = self
+            .talon
+            .iter()
+            .fold(0, |acc, card| acc + get_card_points(card, &self.trump.kind));
 
         self.points
-            .add_points(last_winner.clone(), LAST_WINNER_ADDITIONAL_POINTS);
+            .add_points(*last_winner, LAST_WINNER_ADDITIONAL_POINTS + talon_points);
 
         self.final_points = self.points.clone();
-        for team in Team::iter() {
+        for team in self.players.get_teams() {
             self.final_points
-                .add_points(team.clone(), self.team_declarations.get_points_sum(&team));
+                .add_points(team, self.team_declarations.get_points_sum(&team));
         }
 
-        if let Some(bela_team) = self.bela_declared {
+        for announcement in self.bela_announcements.iter() {
             const BELA_POINTS: usize = 20;
-            self.final_points.add_points(bela_team, BELA_POINTS);
+            let team = self.players.get_team(announcement.seat);
+            self.final_points.add_points(team, BELA_POINTS);
         }
 
         if let Some(stigl_team) = self.is_stigl() {
@@ -207,44 +439,47 @@ impl Round {
         }
 
         if self.has_trump_caller_failed() {
+            let caller_team = self.players.get_team(self.trump.seat);
+            let opponent_teams = self.get_opponent_teams(caller_team);
             self.final_points
-                .all_points_to_team(self.trump.get_caller_team().get_enemy_team());
+                .give_points_to_opponents(caller_team, &opponent_teams);
         }
     }
 
-    fn has_trump_caller_failed(&self) -> bool {
+    pub fn get_result(&self) -> RoundResult {
+        RoundResult::new(self.final_points.clone(), self.doubling)
+    }
+
+    pub fn has_trump_caller_failed(&self) -> bool {
         let player = self
             .players
-            .get(self.trump.player_index)
-            .expect("for player to exist with trump_player_index");
+            .get(self.trump.seat)
+            .expect("for player to exist with trump_seat");
         let team = player.get_team();
 
         !self.final_points.has_bigger_points(team)
     }
 
-    // pub fn increment_player_index(&mut self) {
-    //     self.player_turn_index += 1;
-    //     self.player_turn_index %= NUMBER_OF_PLAYERS;
-    // }
-
-    fn get_declarations(&self, round_player: &Box<dyn RoundPlayer>) -> TeamDeclarations {
+    fn get_declarations(&self, round_player: &dyn RoundPlayer) -> TeamDeclarations {
+        if self.trump.kind == ContractKind::NoTrumps {
+            return TeamDeclarations::default();
+        }
         let mut best_declaration_result: Option<Declaration> = None;
         let mut best_declaration_player: Option<Player> = None;
         let mut team_declarations = TeamDeclarations::default();
-        for index in 0..NUMBER_OF_PLAYERS {
-            let player_index = (index + self.player_turn_index) % NUMBER_OF_PLAYERS;
-            let player = &self.players.players[player_index];
+        let number_of_players = self.get_number_of_players();
+        for offset in 0..number_of_players {
+            let seat = self.seat_turn.offset(offset, number_of_players);
+            let player = self.get_player(seat);
             let possible_declarations = get_possible_declarations(&player.hand);
             let approved_declarations = possible_declarations
                 .into_iter()
-                .filter(|declaration| {
-                    round_player.call_declaration(&self, player_index, declaration)
-                })
+                .filter(|declaration| round_player.call_declaration(self, seat, declaration))
                 .collect::<Vec<_>>();
 
             for declaration in approved_declarations.iter() {
                 if let Some(best_result) = &best_declaration_result {
-                    if declaration.is_better_than(&best_result) {
+                    if declaration.is_better_than(best_result) {
                         best_declaration_result = Some(declaration.clone());
                         best_declaration_player = Some(player.clone());
                     }
@@ -258,10 +493,9 @@ impl Round {
         }
         if let Some(best_declaration_player) = best_declaration_player {
             let best_player_team = best_declaration_player.get_team();
-            match best_player_team {
-                Team::A => team_declarations.delete_declarations_for_team(&Team::B),
-                Team::B => team_declarations.delete_declarations_for_team(&Team::A),
-            };
+            for team in self.get_opponent_teams(best_player_team) {
+                team_declarations.delete_declarations_for_team(&team);
+            }
         }
 
         team_declarations
@@ -0,0 +1,16 @@
+use super::round::{Round, RoundUpdateEvent};
+
+/// Gets told about everything that happens in a round, without making any
+/// decisions. Presentation lives in observers, so any agent can be combined
+/// with any way of showing the round.
+pub trait RoundObserver {
+    fn on_update(&self, round_state: &Round, round_event: RoundUpdateEvent);
+}
+
+/// Observer for rounds nobody watches, like bot matches.
+#[derive(Debug, Default)]
+pub struct NullObserver;
+
+impl RoundObserver for NullObserver {
+    fn on_update(&self, _round_state: &Round, _round_event: RoundUpdateEvent) {}
+}
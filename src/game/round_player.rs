@@ -1,20 +1,33 @@
-use super::{deck::{Card, CardSuit}, declaration::Declaration, round::{Round, RoundUpdateEvent}};
+use super::{
+    auction::{Bid, get_bid_for_contract_call},
+    deck::{Card, CardSuit},
+    declaration::Declaration,
+    round::Round,
+    table::Seat,
+    trump::ContractKind,
+};
 
 pub trait RoundPlayer {
-    fn try_call_trump(&self, round_state: &Round, player_index: usize) -> Option<CardSuit>;
-    fn must_call_trump(&self, round_state: &Round, player_index: usize) -> CardSuit;
-    fn play_card(
-        &self,
-        round_state: &Round,
-        player_index: usize,
-        available_cards: Vec<Card>,
-    ) -> Card;
-    fn call_declaration(
-        &self,
-        round_state: &Round,
-        player_index: usize,
-        declaration: &Declaration,
-    ) -> bool;
-    fn will_declare_bella(&self, round_state: &Round, player_index: usize) -> bool;
-    fn on_update(&self, round_state: &Round, round_event: RoundUpdateEvent);
+    fn try_call_trump(&self, round_state: &Round, seat: Seat) -> Option<CardSuit>;
+    fn must_call_trump(&self, round_state: &Round, seat: Seat) -> CardSuit;
+    fn try_call_contract(&self, round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        self.try_call_trump(round_state, seat)
+            .map(ContractKind::Suit)
+    }
+    fn must_call_contract(&self, round_state: &Round, seat: Seat) -> ContractKind {
+        ContractKind::Suit(self.must_call_trump(round_state, seat))
+    }
+    fn make_bid(&self, round_state: &Round, seat: Seat, valid_bids: Vec<Bid>) -> Bid {
+        let contract = self.try_call_contract(round_state, seat);
+        get_bid_for_contract_call(contract, &valid_bids)
+    }
+    fn play_card(&self, round_state: &Round, seat: Seat, available_cards: Vec<Card>) -> Card;
+    fn call_declaration(&self, round_state: &Round, seat: Seat, declaration: &Declaration) -> bool;
+    fn will_declare_bella(&self, round_state: &Round, seat: Seat) -> bool;
+    fn will_call_kontra(&self, _round_state: &Round, _seat: Seat) -> bool {
+        false
+    }
+    fn will_call_rekontra(&self, _round_state: &Round, _seat: Seat) -> bool {
+        false
+    }
 }
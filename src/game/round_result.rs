@@ -0,0 +1,23 @@
+use super::{kontra::Doubling, team::TeamPoints};
+
+/// Points a round is worth once game rules like kontra are applied.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RoundResult {
+    pub final_points: TeamPoints,
+    pub doubling: Doubling,
+    pub points: TeamPoints,
+}
+
+impl RoundResult {
+    pub fn new(final_points: TeamPoints, doubling: Doubling) -> Self {
+        let mut points = final_points.clone();
+        points.multiply(doubling.get_multiplier());
+
+        Self {
+            final_points,
+            doubling,
+            points,
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use strum_macros::{EnumIter, IntoStaticStr};
+
+use super::team::Team;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumIter, IntoStaticStr)]
+#[non_exhaustive]
+pub enum BelaAnnouncementRule {
+    /// Bela is announced together with the first played card of the pair.
+    #[default]
+    OnFirstCard,
+    /// Bela is announced only when the second card of the pair is played.
+    OnSecondCard,
+}
+
+/// Table layout of the game.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumIter, IntoStaticStr)]
+#[non_exhaustive]
+pub enum GameVariant {
+    /// Two teams of two partners sitting across each other.
+    #[default]
+    FourPlayers,
+    /// Each player plays for themselves with 10 cards, the two remaining
+    /// cards form a talon that goes to the winner of the last trick.
+    ThreePlayers,
+}
+
+impl GameVariant {
+    pub fn get_number_of_players(self) -> usize {
+        match self {
+            Self::FourPlayers => 4,
+            Self::ThreePlayers => 3,
+        }
+    }
+
+    pub fn get_hand_size(self) -> usize {
+        match self {
+            Self::FourPlayers => 8,
+            Self::ThreePlayers => 10,
+        }
+    }
+
+    pub fn get_teams(self) -> Vec<Team> {
+        let number_of_teams = match self {
+            Self::FourPlayers => 2,
+            Self::ThreePlayers => 3,
+        };
+
+        (0..number_of_teams).map(Team::from_index).collect()
+    }
+
+    pub fn get_team(self, seat: usize) -> Team {
+        let teams = self.get_teams();
+
+        teams[seat % teams.len()]
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    pub variant: GameVariant,
+    pub bela_announcement: BelaAnnouncementRule,
+    /// Defenders can double the round with kontra after trump is called,
+    /// and the caller's team can answer with rekontra.
+    pub kontra: bool,
+    /// Besides a trump suit, no trumps and all trumps contracts can be called.
+    pub extended_contracts: bool,
+    /// Trump is chosen in a Bulgarian style auction with doubles and
+    /// redoubles instead of the call-or-pass round, every contract kind can
+    /// be bid and the kontra phase is skipped. When nobody bids after
+    /// [`MAX_REDEALS`](super::auction::MAX_REDEALS) redeals the dealer has to
+    /// call the contract.
+    pub auction: bool,
+}
+
+/// Rules as the switches people choose them with, every one is off by
+/// default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RuleFlags {
+    pub three_players: bool,
+    pub kontra: bool,
+    pub extended_contracts: bool,
+    pub auction: bool,
+    pub bela_on_second_card: bool,
+}
+
+impl RuleSet {
+    pub fn from_flags(flags: RuleFlags) -> Self {
+        Self {
+            variant: if flags.three_players {
+                GameVariant::ThreePlayers
+            } else {
+                GameVariant::FourPlayers
+            },
+            bela_announcement: if flags.bela_on_second_card {
+                BelaAnnouncementRule::OnSecondCard
+            } else {
+                BelaAnnouncementRule::OnFirstCard
+            },
+            kontra: flags.kontra,
+            extended_contracts: flags.extended_contracts,
+            auction: flags.auction,
+        }
+    }
+
+    pub fn get_flags(&self) -> RuleFlags {
+        RuleFlags {
+            three_players: self.variant == GameVariant::ThreePlayers,
+            kontra: self.kontra,
+            extended_contracts: self.extended_contracts,
+            auction: self.auction,
+            bela_on_second_card: self.bela_announcement == BelaAnnouncementRule::OnSecondCard,
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use super::{
+    deck::Card, points::get_card_points, round::Round, table::Seat, team::Team, trick::Trick,
+    trump::ContractKind,
+};
+
+const LAST_TRICK_POINTS: usize = 10;
+
+/// Playable card together with the points the solved team gets in the
+/// rest of the round when the card is played.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolvedCard {
+    pub card: Card,
+    pub points: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bounds {
+    lower: usize,
+    upper: usize,
+}
+
+/// Double dummy solver, every player sees all cards and plays perfectly.
+/// The solved team maximizes its card points in the remaining tricks while
+/// everybody else minimizes them. Declarations, bela and štiglja are not
+/// part of the search since card play doesn't change them.
+#[derive(Debug, Clone)]
+pub struct DoubleDummySolver {
+    contract: ContractKind,
+    team: Team,
+    teams: Vec<Team>,
+    hands: Vec<Vec<Card>>,
+    trick: Trick,
+    talon_points: usize,
+    /// Bounds of already searched positions at the start of a trick, keyed
+    /// by the leading seat and the cards left in every hand, each seat has
+    /// its own 32 bits of the key.
+    cache: HashMap<(usize, u128), Bounds>,
+}
+
+fn get_card_bit(card: &Card) -> u128 {
+    let suit_index = card.suit.clone() as usize;
+    let value_index = card.value.clone() as usize;
+
+    1 << (suit_index * 8 + value_index)
+}
+
+impl DoubleDummySolver {
+    /// Solves the round from its current state for the given team.
+    pub fn new(round_state: &Round, team: Team) -> Self {
+        let contract = round_state.trump.kind.clone();
+        let talon_points = round_state
+            .talon
+            .iter()
+            .map(|card| get_card_points(card, &contract))
+            .sum();
+
+        Self {
+            contract,
+            team,
+            teams: round_state.players.get_seat_teams(),
+            hands: round_state
+                .players
+                .into_iter()
+                .map(|player| player.get_hand().cards().clone())
+                .collect(),
+            trick: round_state.current_trick.clone(),
+            talon_points,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Points of cards that are still in play, including the last trick
+    /// bonus, so opponents get what the solved team doesn't.
+    pub fn get_remaining_points(&self) -> usize {
+        self.hands
+            .iter()
+            .flatten()
+            .chain(&self.trick.cards_on_table)
+            .map(|card| get_card_points(card, &self.contract))
+            .sum::<usize>()
+            + LAST_TRICK_POINTS
+            + self.talon_points
+    }
+
+    /// Points the solved team gets in the rest of the round.
+    pub fn solve(&mut self) -> usize {
+        let mut trick = self.trick.clone();
+        self.search(&mut trick, 0, usize::MAX)
+    }
+
+    /// Every card the seat on turn can play with the points the solved team
+    /// gets after it.
+    pub fn solve_cards(&mut self) -> Vec<SolvedCard> {
+        let seat = self.trick.get_seat_turn();
+        let playable_cards = self
+            .trick
+            .filter_playable_cards(&self.hands[seat.index()], &self.contract);
+
+        playable_cards
+            .into_iter()
+            .map(|card| {
+                let mut trick = self.trick.clone();
+                let points = self.play_and_search(&mut trick, seat, &card, 0, usize::MAX);
+                SolvedCard { card, points }
+            })
+            .collect()
+    }
+
+    fn get_cache_key(&self, trick: &Trick) -> (usize, u128) {
+        let cards = self
+            .hands
+            .iter()
+            .enumerate()
+            .flat_map(|(index, hand)| {
+                hand.iter()
+                    .map(move |card| get_card_bit(card) << (index * 32))
+            })
+            .fold(0, |acc, bit| acc | bit);
+
+        (trick.get_seat_turn().index(), cards)
+    }
+
+    fn search(&mut self, trick: &mut Trick, mut alpha: usize, mut beta: usize) -> usize {
+        if trick.cards_on_table.is_empty() {
+            if self.hands.iter().all(|hand| hand.is_empty()) {
+                return 0;
+            }
+            let key = self.get_cache_key(trick);
+            if let Some(bounds) = self.cache.get(&key) {
+                if bounds.lower == bounds.upper || bounds.lower >= beta || bounds.upper <= alpha {
+                    return if bounds.lower >= beta {
+                        bounds.lower
+                    } else {
+                        bounds.upper
+                    };
+                }
+                alpha = alpha.max(bounds.lower);
+                beta = beta.min(bounds.upper);
+            }
+
+            let points = self.search_moves(trick, alpha, beta);
+            let bounds = self.cache.entry(key).or_insert(Bounds {
+                lower: 0,
+                upper: usize::MAX,
+            });
+            if points <= alpha {
+                bounds.upper = bounds.upper.min(points);
+            } else if points >= beta {
+                bounds.lower = bounds.lower.max(points);
+            } else {
+                bounds.lower = points;
+                bounds.upper = points;
+            }
+
+            return points;
+        }
+
+        self.search_moves(trick, alpha, beta)
+    }
+
+    fn search_moves(&mut self, trick: &mut Trick, mut alpha: usize, mut beta: usize) -> usize {
+        let seat = trick.get_seat_turn();
+        let is_maximizing = self.teams[seat.index()] == self.team;
+        let playable_cards = trick.filter_playable_cards(&self.hands[seat.index()], &self.contract);
+
+        let mut best_points = if is_maximizing { 0 } else { usize::MAX };
+        for card in playable_cards {
+            let points = self.play_and_search(trick, seat, &card, alpha, beta);
+            if is_maximizing {
+                best_points = best_points.max(points);
+                alpha = alpha.max(points);
+            } else {
+                best_points = best_points.min(points);
+                beta = beta.min(points);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        best_points
+    }
+
+    fn play_and_search(
+        &mut self,
+        trick: &mut Trick,
+        seat: Seat,
+        card: &Card,
+        alpha: usize,
+        beta: usize,
+    ) -> usize {
+        let hand = &mut self.hands[seat.index()];
+        let card_position = hand
+            .iter()
+            .position(|hand_card| hand_card == card)
+            .expect("solved card should be in hand");
+        hand.remove(card_position);
+        let previous_trick = trick.clone();
+        trick.play_card(card.clone());
+
+        let points = if trick.is_done() {
+            let winner = trick
+                .get_trick_winner(&self.contract)
+                .expect("done trick should have a winner");
+            let mut trick_points = trick
+                .cards_on_table
+                .iter()
+                .map(|card| get_card_points(card, &self.contract))
+                .sum::<usize>();
+            if self.hands.iter().all(|hand| hand.is_empty()) {
+                trick_points += LAST_TRICK_POINTS + self.talon_points;
+            }
+            let won_points = if self.teams[winner.index()] == self.team {
+                trick_points
+            } else {
+                0
+            };
+            // the search window is shifted by the points already won
+            let mut next_trick = Trick::new(winner, self.teams.len());
+            won_points
+                + self.search(
+                    &mut next_trick,
+                    alpha.saturating_sub(won_points),
+                    beta.saturating_sub(won_points),
+                )
+        } else {
+            self.search(trick, alpha, beta)
+        };
+
+        *trick = previous_trick;
+        self.hands[seat.index()].insert(card_position, card.clone());
+
+        points
+    }
+}
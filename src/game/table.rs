@@ -0,0 +1,132 @@
+use rand::{SeedableRng, rngs::StdRng};
+
+use super::{rules::RuleSet, team::Team};
+use crate::utils::random::RandomSource;
+
+/// Position of a player at the table, seats are numbered clockwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Seat(usize);
+
+impl Seat {
+    pub fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    pub fn index(self) -> usize {
+        self.0
+    }
+
+    /// Seat that is `offset` places clockwise from this one.
+    pub fn offset(self, offset: usize, number_of_players: usize) -> Self {
+        Self((self.0 + offset) % number_of_players)
+    }
+
+    pub fn next(self, number_of_players: usize) -> Self {
+        self.offset(1, number_of_players)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SeatConfig {
+    pub name: String,
+    pub team: Team,
+}
+
+/// Everything needed to set up a table: who sits where, in which team,
+/// who deals and which rules are played.
+#[derive(Debug, Clone)]
+pub struct TableConfig {
+    pub seats: Vec<SeatConfig>,
+    pub dealer: Seat,
+    pub rules: RuleSet,
+    /// Makes the deal reproducible, a random seed is used when missing.
+    pub seed: Option<u64>,
+}
+
+impl TableConfig {
+    /// Seats the players in the given order with the default teams of the
+    /// game variant. The last player deals, so the first one plays first.
+    pub fn new(player_names: Vec<String>, rules: RuleSet) -> Self {
+        let variant = rules.variant;
+        assert_eq!(
+            player_names.len(),
+            variant.get_number_of_players(),
+            "number of players should match the game variant"
+        );
+        let seats = player_names
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| SeatConfig {
+                name,
+                team: variant.get_team(index),
+            })
+            .collect::<Vec<_>>();
+        let dealer = Seat::new(seats.len() - 1);
+
+        Self {
+            seats,
+            dealer,
+            rules,
+            seed: None,
+        }
+    }
+
+    pub fn with_dealer(mut self, dealer: Seat) -> Self {
+        assert!(dealer.index() < self.get_number_of_players());
+        self.dealer = dealer;
+        self
+    }
+
+    /// Assigns teams by seat, for example `[A, A, B, B]` makes neighbours
+    /// partners instead of the players sitting across each other. Every
+    /// seat needs a team and at least two teams have to play.
+    pub fn with_teams(mut self, teams: &[Team]) -> Self {
+        assert_eq!(
+            teams.len(),
+            self.seats.len(),
+            "every seat needs a team, got {} teams for {} seats",
+            teams.len(),
+            self.seats.len()
+        );
+        for (seat_config, team) in self.seats.iter_mut().zip(teams) {
+            seat_config.team = *team;
+        }
+        assert!(
+            self.get_teams().len() >= 2,
+            "at least two teams have to play, got {:?}",
+            teams
+        );
+        self
+    }
+
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn get_number_of_players(&self) -> usize {
+        self.seats.len()
+    }
+
+    /// Distinct teams playing at the table, in seat order.
+    pub fn get_teams(&self) -> Vec<Team> {
+        let mut teams = vec![];
+        for seat_config in &self.seats {
+            if !teams.contains(&seat_config.team) {
+                teams.push(seat_config.team);
+            }
+        }
+        teams
+    }
+
+    pub fn get_first_seat(&self) -> Seat {
+        self.dealer.next(self.get_number_of_players())
+    }
+
+    pub fn get_rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut RandomSource),
+        }
+    }
+}
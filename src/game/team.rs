@@ -2,20 +2,24 @@ use strum::EnumCount;
 use strum_macros::{EnumCount, EnumIter, IntoStaticStr};
 
 #[derive(
-    Clone, IntoStaticStr, Debug, Hash, PartialEq, PartialOrd, Eq, EnumCount, EnumIter, Copy,
+    Clone, IntoStaticStr, Debug, Hash, PartialEq, PartialOrd, Eq, EnumCount, EnumIter, Copy, Default,
 )]
 pub enum Team {
+    #[default]
     A,
     B,
+    /// Only used by the three player variant where everybody is a team.
+    C,
 }
 
 impl Team {}
 
 impl Team {
-    pub fn to_index(&self) -> usize {
+    pub fn to_index(self) -> usize {
         match self {
             Self::A => 0,
             Self::B => 1,
+            Self::C => 2,
         }
     }
 
@@ -23,24 +27,13 @@ impl Team {
         match index {
             0 => Self::A,
             1 => Self::B,
+            2 => Self::C,
             _ => panic!("Invalid index for converting to Team"),
         }
     }
-
-    pub fn get_enemy_team(&self) -> Self {
-        match self {
-            Self::A => Self::B,
-            Self::B => Self::A,
-        }
-    }
-
-    pub fn from_player_index(player_index: usize) -> Self {
-        let index = player_index % Team::COUNT;
-        Self::from_index(index)
-    }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct TeamPoints {
     points: [usize; Team::COUNT],
 }
@@ -53,17 +46,31 @@ impl TeamPoints {
 
     pub fn has_bigger_points(&self, team: Team) -> bool {
         let team_index = team.to_index();
-        let other_team_index = (team_index + 1) % Team::COUNT;
 
-        self.points[team_index] > self.points[other_team_index]
+        self.points
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != team_index)
+            .all(|(_, points)| self.points[team_index] > *points)
     }
 
-    pub fn all_points_to_team(&mut self, team: Team) {
+    /// Splits all points of the team between its opponents, the first
+    /// opponent gets what can't be split evenly.
+    pub fn give_points_to_opponents(&mut self, team: Team, opponents: &[Team]) {
         let team_index = team.to_index();
-        let other_team_index = (team_index + 1) % Team::COUNT;
-        let points_sum = self.points.iter().fold(0, |acc, curr| acc + *curr);
-        self.points[team_index] = points_sum;
-        self.points[other_team_index] = 0;
+        let team_points = self.points[team_index];
+        self.points[team_index] = 0;
+        let share = team_points / opponents.len();
+        for opponent in opponents {
+            self.points[opponent.to_index()] += share;
+        }
+        self.points[opponents[0].to_index()] += team_points % opponents.len();
+    }
+
+    pub fn multiply(&mut self, multiplier: usize) {
+        for points in self.points.iter_mut() {
+            *points *= multiplier;
+        }
     }
 
     pub fn get_points(&self, team: Team) -> usize {
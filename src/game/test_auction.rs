@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::auction::{Auction, Bid, Contract, get_all_contract_kinds};
+    use crate::game::deck::CardSuit;
+    use crate::game::kontra::Doubling;
+    use crate::game::round_observer::NullObserver;
+    use crate::game::rules::RuleSet;
+    use crate::game::table::Seat;
+    use crate::game::team::Team;
+    use crate::game::test_utils::{ScriptedRoundPlayer, get_round};
+    use crate::game::trump::ContractKind;
+
+    fn four_player_teams() -> Vec<Team> {
+        vec![Team::A, Team::B, Team::A, Team::B]
+    }
+
+    fn make_bids(auction: &mut Auction, bids: Vec<Bid>) {
+        for bid in bids {
+            auction.make_bid(bid);
+        }
+    }
+
+    #[test]
+    fn test_contract_kinds_order() {
+        // clubs < diamonds < hearts < spades < no trumps < all trumps
+        assert_eq!(
+            get_all_contract_kinds(),
+            vec![
+                ContractKind::Suit(CardSuit::Acorn),
+                ContractKind::Suit(CardSuit::Pumpkin),
+                ContractKind::Suit(CardSuit::Herz),
+                ContractKind::Suit(CardSuit::Leaf),
+                ContractKind::NoTrumps,
+                ContractKind::AllTrumps,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_valid_bids() {
+        let mut auction = Auction::new(Seat::new(1), four_player_teams());
+        assert_eq!(auction.get_valid_bids().len(), 7);
+
+        auction.make_bid(Bid::Contract(ContractKind::NoTrumps));
+        assert_eq!(
+            auction.get_valid_bids(),
+            vec![
+                Bid::Pass,
+                Bid::Contract(ContractKind::AllTrumps),
+                Bid::Double
+            ]
+        );
+
+        // partner of the bidder can't double
+        auction.make_bid(Bid::Pass);
+        assert!(!auction.get_valid_bids().contains(&Bid::Double));
+
+        auction.make_bid(Bid::Pass);
+        auction.make_bid(Bid::Double);
+        assert!(auction.get_valid_bids().contains(&Bid::Redouble));
+    }
+
+    #[test]
+    fn test_auction_closes_after_three_passes() {
+        let mut auction = Auction::new(Seat::new(0), four_player_teams());
+        make_bids(
+            &mut auction,
+            vec![
+                Bid::Contract(ContractKind::Suit(CardSuit::Herz)),
+                Bid::Contract(ContractKind::Suit(CardSuit::Leaf)),
+                Bid::Pass,
+                Bid::Pass,
+            ],
+        );
+        assert!(!auction.is_done());
+        assert_eq!(auction.get_contract(), None);
+
+        make_bids(
+            &mut auction,
+            vec![Bid::Double, Bid::Redouble, Bid::Pass, Bid::Pass],
+        );
+        assert!(!auction.is_done());
+
+        auction.make_bid(Bid::Pass);
+        assert_eq!(
+            auction.get_contract(),
+            Some(Contract {
+                kind: ContractKind::Suit(CardSuit::Leaf),
+                seat: Seat::new(1),
+                doubling: Doubling::Rekontra,
+            })
+        );
+    }
+
+    #[test]
+    fn test_higher_bid_clears_double() {
+        let mut auction = Auction::new(Seat::new(0), four_player_teams());
+        make_bids(
+            &mut auction,
+            vec![
+                Bid::Contract(ContractKind::Suit(CardSuit::Acorn)),
+                Bid::Double,
+                Bid::Contract(ContractKind::AllTrumps),
+                Bid::Pass,
+                Bid::Pass,
+                Bid::Pass,
+            ],
+        );
+
+        let contract = auction.get_contract().expect("auction should be done");
+        assert_eq!(contract.seat, Seat::new(2));
+        assert_eq!(contract.doubling, Doubling::None);
+    }
+
+    #[test]
+    fn test_all_passed() {
+        let mut auction = Auction::new(Seat::new(3), four_player_teams());
+        make_bids(&mut auction, vec![Bid::Pass; 4]);
+
+        assert!(auction.is_done());
+        assert_eq!(auction.get_contract(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_bid() {
+        let mut auction = Auction::new(Seat::new(0), four_player_teams());
+        auction.make_bid(Bid::Double);
+    }
+
+    #[test]
+    fn test_neighbour_partners_cant_double() {
+        let teams = vec![Team::A, Team::A, Team::B, Team::B];
+        let mut auction = Auction::new(Seat::new(0), teams);
+        auction.make_bid(Bid::Contract(ContractKind::NoTrumps));
+        assert!(!auction.get_valid_bids().contains(&Bid::Double));
+
+        auction.make_bid(Bid::Pass);
+        assert!(auction.get_valid_bids().contains(&Bid::Double));
+    }
+
+    #[test]
+    fn test_round_with_auction() {
+        let rules = RuleSet {
+            auction: true,
+            ..Default::default()
+        };
+        let mut round = get_round(rules);
+        round.play_round(Box::new(ScriptedRoundPlayer::default()), &NullObserver);
+
+        assert_eq!(round.trump.kind, ContractKind::Suit(CardSuit::Herz));
+        assert_eq!(round.trump.seat, Seat::new(0));
+        assert_eq!(round.auction.bids.len(), 4);
+        assert_eq!(round.final_points.get_points(Team::A), 272);
+    }
+
+    #[test]
+    fn test_dealer_calls_when_everybody_always_passes() {
+        let rules = RuleSet {
+            auction: true,
+            ..Default::default()
+        };
+        let mut round = get_round(rules);
+        let round_player = ScriptedRoundPlayer {
+            pass_auction: true,
+            ..Default::default()
+        };
+        round.play_round(Box::new(round_player), &NullObserver);
+
+        assert_eq!(round.auction.bids.len(), 4);
+        assert!(round.auction.bids.iter().all(|item| item.bid == Bid::Pass));
+        assert_eq!(round.trump.kind, ContractKind::Suit(CardSuit::Herz));
+        assert_eq!(round.trump.seat, Seat::new(3));
+        assert_eq!(round.doubling, Doubling::None);
+        assert!(!round.players.have_cards());
+    }
+}
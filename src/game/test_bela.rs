@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::bela::BelaAnnouncement;
+    use crate::game::deck::{Card, CardSuit, CardValue};
+    use crate::game::round_observer::NullObserver;
+    use crate::game::rules::{BelaAnnouncementRule, RuleSet};
+    use crate::game::table::Seat;
+    use crate::game::team::Team;
+    use crate::game::test_utils::{ScriptedRoundPlayer, get_round};
+    use crate::game::trump::{ContractKind, Trump};
+
+    #[test]
+    fn test_bela_announced_on_first_card() {
+        let mut round = get_round(RuleSet::default());
+        round.play_round(Box::new(ScriptedRoundPlayer::default()), &NullObserver);
+
+        let expected = BelaAnnouncement {
+            seat: Seat::new(0),
+            card: Card::new(CardSuit::Herz, CardValue::Queen),
+            trick_index: 5,
+        };
+        assert_eq!(round.bela_announcements, vec![expected]);
+        // 162 for all tricks, 90 for stiglja and 20 for bela
+        assert_eq!(round.final_points.get_points(Team::A), 272);
+    }
+
+    #[test]
+    fn test_bela_announced_on_second_card() {
+        let rules = RuleSet {
+            bela_announcement: BelaAnnouncementRule::OnSecondCard,
+            ..Default::default()
+        };
+        let mut round = get_round(rules);
+        round.play_round(Box::new(ScriptedRoundPlayer::default()), &NullObserver);
+
+        let expected = BelaAnnouncement {
+            seat: Seat::new(0),
+            card: Card::new(CardSuit::Herz, CardValue::King),
+            trick_index: 6,
+        };
+        assert_eq!(round.bela_announcements, vec![expected]);
+    }
+
+    #[test]
+    fn test_bela_not_announced() {
+        let mut round = get_round(RuleSet::default());
+        round.play_round(
+            Box::new(ScriptedRoundPlayer {
+                declare_bela: false,
+                ..Default::default()
+            }),
+            &NullObserver,
+        );
+
+        assert!(round.bela_announcements.is_empty());
+        assert_eq!(round.final_points.get_points(Team::A), 252);
+    }
+
+    #[test]
+    fn test_player_view_knows_other_bela_card() {
+        let mut round = get_round(RuleSet::default());
+        round.trump = Trump {
+            seat: Seat::new(0),
+            kind: ContractKind::Suit(CardSuit::Herz),
+        };
+        let queen = Card::new(CardSuit::Herz, CardValue::Queen);
+        let king = Card::new(CardSuit::Herz, CardValue::King);
+        round.players.players[0].remove_card(&queen);
+        round.current_trick.play_card(queen.clone());
+        round.bela_announcements.push(BelaAnnouncement {
+            seat: Seat::new(0),
+            card: queen,
+            trick_index: 0,
+        });
+
+        let view = round.get_player_view(Seat::new(1));
+        assert_eq!(view.get_known_cards(), vec![(Seat::new(0), king.clone())]);
+        assert!(
+            round
+                .get_player_view(Seat::new(0))
+                .get_known_cards()
+                .is_empty()
+        );
+
+        round.players.players[0].remove_card(&king);
+        round.current_trick.play_card(king);
+        assert!(
+            round
+                .get_player_view(Seat::new(1))
+                .get_known_cards()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_bela_in_every_suit_with_all_trumps() {
+        let rules = RuleSet {
+            extended_contracts: true,
+            ..Default::default()
+        };
+        let mut round = get_round(rules.clone());
+        round.play_round(
+            Box::new(ScriptedRoundPlayer {
+                contract: ContractKind::AllTrumps,
+                ..Default::default()
+            }),
+            &NullObserver,
+        );
+
+        let announcers = round
+            .bela_announcements
+            .iter()
+            .map(|announcement| announcement.seat.index())
+            .collect::<Vec<_>>();
+        assert_eq!(announcers, vec![0, 1, 2, 3]);
+
+        let mut round = get_round(rules);
+        round.play_round(
+            Box::new(ScriptedRoundPlayer {
+                contract: ContractKind::NoTrumps,
+                ..Default::default()
+            }),
+            &NullObserver,
+        );
+        assert!(round.bela_announcements.is_empty());
+    }
+}
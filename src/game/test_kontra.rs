@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::kontra::Doubling;
+    use crate::game::round_observer::NullObserver;
+    use crate::game::rules::RuleSet;
+    use crate::game::team::Team;
+    use crate::game::test_utils::{ScriptedRoundPlayer, get_round};
+
+    fn kontra_rules() -> RuleSet {
+        RuleSet {
+            kontra: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_kontra_ignored_without_rule() {
+        let mut round = get_round(RuleSet::default());
+        round.play_round(
+            Box::new(ScriptedRoundPlayer {
+                call_kontra: true,
+                ..Default::default()
+            }),
+            &NullObserver,
+        );
+
+        assert_eq!(round.doubling, Doubling::None);
+        assert_eq!(round.get_result().points.get_points(Team::A), 272);
+    }
+
+    #[test]
+    fn test_kontra_doubles_points() {
+        let mut round = get_round(kontra_rules());
+        round.play_round(
+            Box::new(ScriptedRoundPlayer {
+                call_kontra: true,
+                ..Default::default()
+            }),
+            &NullObserver,
+        );
+
+        let result = round.get_result();
+        assert_eq!(result.doubling, Doubling::Kontra);
+        assert_eq!(result.final_points.get_points(Team::A), 272);
+        assert_eq!(result.points.get_points(Team::A), 544);
+        assert_eq!(result.points.get_points(Team::B), 0);
+    }
+
+    #[test]
+    fn test_rekontra_quadruples_points() {
+        let mut round = get_round(kontra_rules());
+        round.play_round(
+            Box::new(ScriptedRoundPlayer {
+                call_kontra: true,
+                call_rekontra: true,
+                ..Default::default()
+            }),
+            &NullObserver,
+        );
+
+        assert_eq!(round.doubling, Doubling::Rekontra);
+        assert_eq!(round.get_result().points.get_points(Team::A), 1088);
+    }
+
+    #[test]
+    fn test_rekontra_needs_kontra() {
+        let mut round = get_round(kontra_rules());
+        round.play_round(
+            Box::new(ScriptedRoundPlayer {
+                call_rekontra: true,
+                ..Default::default()
+            }),
+            &NullObserver,
+        );
+
+        assert_eq!(round.doubling, Doubling::None);
+    }
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use crate::game::auction::get_all_contract_kinds;
+    use crate::game::deck::{Card, CardSuit, CardValue};
+    use crate::game::points::get_card_points;
+    use crate::game::round_observer::NullObserver;
+    use crate::game::rules::RuleSet;
+    use crate::game::team::Team;
+    use crate::game::test_utils::{ScriptedRoundPlayer, get_round};
+    use crate::game::trump::ContractKind;
+
+    const LAST_TRICK_POINTS: usize = 10;
+
+    #[test]
+    fn test_contract_point_tables() {
+        let points = |suit, value, contract: &ContractKind| {
+            get_card_points(&Card::new(suit, value), contract)
+        };
+        let herz = ContractKind::Suit(CardSuit::Herz);
+
+        assert_eq!(points(CardSuit::Herz, CardValue::Jack, &herz), 20);
+        assert_eq!(points(CardSuit::Leaf, CardValue::Jack, &herz), 2);
+        assert_eq!(
+            points(CardSuit::Leaf, CardValue::Kec, &ContractKind::NoTrumps),
+            19
+        );
+        assert_eq!(
+            points(CardSuit::Leaf, CardValue::Jack, &ContractKind::NoTrumps),
+            2
+        );
+        assert_eq!(
+            points(CardSuit::Leaf, CardValue::Jack, &ContractKind::AllTrumps),
+            14
+        );
+        assert_eq!(
+            points(CardSuit::Leaf, CardValue::IX, &ContractKind::AllTrumps),
+            9
+        );
+        assert_eq!(
+            points(CardSuit::Leaf, CardValue::Queen, &ContractKind::AllTrumps),
+            1
+        );
+    }
+
+    #[test]
+    fn test_deck_points_of_every_contract() {
+        for contract in get_all_contract_kinds() {
+            let deck_points: usize = CardSuit::iter()
+                .flat_map(|suit| CardValue::iter().map(move |value| Card::new(suit.clone(), value)))
+                .map(|card| get_card_points(&card, &contract))
+                .sum();
+
+            assert_eq!(deck_points + LAST_TRICK_POINTS, 162, "{:?}", contract);
+        }
+    }
+
+    #[test]
+    fn test_round_totals_of_every_contract() {
+        let rules = RuleSet {
+            extended_contracts: true,
+            ..Default::default()
+        };
+        let contracts = [
+            ContractKind::Suit(CardSuit::Herz),
+            ContractKind::NoTrumps,
+            ContractKind::AllTrumps,
+        ];
+        for contract in contracts {
+            let mut round = get_round(rules.clone());
+            round.play_round(
+                Box::new(ScriptedRoundPlayer {
+                    contract: contract.clone(),
+                    declare_bela: false,
+                    ..Default::default()
+                }),
+                &NullObserver,
+            );
+
+            let points = round.points.get_points(Team::A) + round.points.get_points(Team::B);
+            assert_eq!(points, 162, "{:?}", contract);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use crate::clients::heuristic_round_player::HeuristicRoundPlayer;
+    use crate::clients::replay_round_player::ReplayRoundPlayer;
+    use crate::game::game_match::Match;
+    use crate::game::record::RoundRecord;
+    use crate::game::round::Round;
+    use crate::game::round_observer::NullObserver;
+    use crate::game::rules::{GameVariant, RuleSet};
+    use crate::game::table::TableConfig;
+
+    fn get_table_config(rules: RuleSet) -> TableConfig {
+        let player_names = ["a", "b", "c", "d"]
+            .map(String::from)
+            .into_iter()
+            .take(rules.variant.get_number_of_players())
+            .collect();
+        TableConfig::new(player_names, rules).with_seed(Some(7))
+    }
+
+    fn assert_replay_matches(rules: RuleSet) {
+        let table_config = get_table_config(rules);
+        let mut round = Round::new(&table_config);
+        round.play_round(HeuristicRoundPlayer::boxed(), &NullObserver);
+
+        let text = RoundRecord::new(&table_config, &round).to_text();
+        let records = RoundRecord::parse_all(&text).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].to_text(), text);
+
+        let mut replayed_round = Round::from_deal(&records[0].table_config, &records[0].deal);
+        replayed_round.play_round(
+            Box::new(ReplayRoundPlayer::new(records[0].clone())),
+            &NullObserver,
+        );
+        assert_eq!(
+            replayed_round.get_result().points,
+            round.get_result().points
+        );
+    }
+
+    #[test]
+    fn test_record_replays_round() {
+        assert_replay_matches(RuleSet::default());
+    }
+
+    #[test]
+    fn test_record_replays_three_player_auction() {
+        assert_replay_matches(RuleSet {
+            variant: GameVariant::ThreePlayers,
+            kontra: true,
+            extended_contracts: true,
+            auction: true,
+            ..RuleSet::default()
+        });
+    }
+
+    #[test]
+    fn test_bad_record_line() {
+        let error = RoundRecord::parse_all("round\nnames a,b,c,d\nplay x y\n").unwrap_err();
+        assert_eq!(error.line, 3);
+    }
+
+    #[test]
+    fn test_match_ends_at_target() {
+        let mut game_match = Match::new(get_table_config(RuleSet::default()), 300);
+        while !game_match.is_done() {
+            game_match.play_round(HeuristicRoundPlayer::boxed(), &NullObserver);
+        }
+
+        let winner = game_match.get_winner().unwrap();
+        assert!(game_match.points.get_points(winner) >= 300);
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::deck::{Card, CardSuit, CardValue};
+    use crate::game::player::Hand;
+    use crate::game::points::get_card_points;
+    use crate::game::round::Round;
+    use crate::game::rules::RuleSet;
+    use crate::game::solver::DoubleDummySolver;
+    use crate::game::table::{Seat, TableConfig};
+    use crate::game::team::Team;
+    use crate::game::trick::Trick;
+    use crate::game::trump::{ContractKind, Trump};
+
+    fn get_seeded_round(seed: u64, kind: ContractKind) -> Round {
+        let player_names = ["a", "b", "c", "d"].map(String::from).to_vec();
+        let table_config = TableConfig::new(player_names, RuleSet::default()).with_seed(Some(seed));
+        let mut round = Round::new(&table_config);
+        round.trump = Trump {
+            seat: Seat::new(0),
+            kind,
+        };
+
+        round
+    }
+
+    // plain minimax without pruning or caching to check the solver against
+    fn minimax(hands: &mut Vec<Vec<Card>>, trick: &Trick, kind: &ContractKind) -> usize {
+        let seat = trick.get_seat_turn();
+        let is_maximizing = seat.index().is_multiple_of(2);
+        let playable_cards = trick.filter_playable_cards(&hands[seat.index()], kind);
+        let mut results = vec![];
+        for card in playable_cards {
+            let position = hands[seat.index()]
+                .iter()
+                .position(|hand_card| *hand_card == card)
+                .unwrap();
+            hands[seat.index()].remove(position);
+            let mut next_trick = trick.clone();
+            next_trick.play_card(card.clone());
+            let points = if next_trick.is_done() {
+                let winner = next_trick.get_trick_winner(kind).unwrap();
+                let mut points: usize = next_trick
+                    .cards_on_table
+                    .iter()
+                    .map(|card| get_card_points(card, kind))
+                    .sum();
+                let is_last = hands.iter().all(|hand| hand.is_empty());
+                if is_last {
+                    points += 10;
+                }
+                let won_points = if winner.index().is_multiple_of(2) {
+                    points
+                } else {
+                    0
+                };
+                let rest = if is_last {
+                    0
+                } else {
+                    minimax(hands, &Trick::new(winner, 4), kind)
+                };
+                won_points + rest
+            } else {
+                minimax(hands, &next_trick, kind)
+            };
+            hands[seat.index()].insert(position, card);
+            results.push(points);
+        }
+
+        if is_maximizing {
+            results.into_iter().max().unwrap()
+        } else {
+            results.into_iter().min().unwrap()
+        }
+    }
+
+    #[test]
+    fn test_last_trick() {
+        let mut round = get_seeded_round(0, ContractKind::Suit(CardSuit::Herz));
+        let hands = [
+            Card::new(CardSuit::Herz, CardValue::VII),
+            Card::new(CardSuit::Leaf, CardValue::Kec),
+            Card::new(CardSuit::Herz, CardValue::Jack),
+            Card::new(CardSuit::Leaf, CardValue::X),
+        ];
+        for (player, card) in round.players.players.iter_mut().zip(hands) {
+            player.hand = Hand::new(vec![card]);
+        }
+        round.current_trick = Trick::new(Seat::new(1), 4);
+
+        let mut solver = DoubleDummySolver::new(&round, Team::A);
+        // Jack of trumps takes 11 + 20 + 10 + 0 and the last trick bonus
+        assert_eq!(solver.solve(), 51);
+        assert_eq!(solver.get_remaining_points(), 51);
+
+        let mut solver = DoubleDummySolver::new(&round, Team::B);
+        assert_eq!(solver.solve(), 0);
+    }
+
+    #[test]
+    fn test_solver_matches_minimax() {
+        let contracts = [
+            ContractKind::Suit(CardSuit::Acorn),
+            ContractKind::NoTrumps,
+            ContractKind::AllTrumps,
+        ];
+        for seed in 0..6 {
+            let kind = contracts[seed as usize % contracts.len()].clone();
+            let mut round = get_seeded_round(seed, kind.clone());
+            // three cards each keep the plain minimax fast
+            for player in round.players.players.iter_mut() {
+                player.hand.hand.truncate(3);
+            }
+            round.current_trick = Trick::new(Seat::new(seed as usize % 4), 4);
+
+            let mut hands = round
+                .players
+                .players
+                .iter()
+                .map(|player| player.hand.cards().clone())
+                .collect::<Vec<_>>();
+            let expected = minimax(&mut hands, &round.current_trick, &kind);
+            let mut solver = DoubleDummySolver::new(&round, Team::A);
+            assert_eq!(solver.solve(), expected, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_best_card_gets_solved_points() {
+        let mut round = get_seeded_round(7, ContractKind::Suit(CardSuit::Leaf));
+        for player in round.players.players.iter_mut() {
+            player.hand.hand.truncate(5);
+        }
+        let mut solver = DoubleDummySolver::new(&round, Team::A);
+        let points = solver.solve();
+
+        let solved_cards = solver.solve_cards();
+        assert_eq!(solved_cards.len(), 5);
+        let best_points = solved_cards.iter().map(|solved| solved.points).max();
+        assert_eq!(best_points, Some(points));
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::round::Round;
+    use crate::game::rules::RuleSet;
+    use crate::game::table::{Seat, TableConfig};
+    use crate::game::team::Team;
+
+    fn get_table_config() -> TableConfig {
+        let player_names = ["a", "b", "c", "d"].map(String::from).to_vec();
+        TableConfig::new(player_names, RuleSet::default())
+    }
+
+    #[test]
+    fn test_default_table() {
+        let table_config = get_table_config();
+        let round = Round::new(&table_config);
+
+        assert_eq!(round.seat_turn, Seat::new(0));
+        let teams = round
+            .players
+            .players
+            .iter()
+            .map(|player| player.get_team())
+            .collect::<Vec<_>>();
+        assert_eq!(teams, vec![Team::A, Team::B, Team::A, Team::B]);
+        assert_eq!(round.get_player(Seat::new(2)).name, "c");
+    }
+
+    #[test]
+    fn test_player_after_dealer_plays_first() {
+        let table_config = get_table_config().with_dealer(Seat::new(1));
+        assert_eq!(table_config.get_first_seat(), Seat::new(2));
+
+        let table_config = table_config.with_dealer(Seat::new(3));
+        assert_eq!(Round::new(&table_config).seat_turn, Seat::new(0));
+    }
+
+    #[test]
+    fn test_custom_teams() {
+        let table_config = get_table_config().with_teams(&[Team::A, Team::A, Team::B, Team::B]);
+        let round = Round::new(&table_config);
+
+        assert_eq!(round.players.get_team(Seat::new(1)), Team::A);
+        assert_eq!(round.players.get_team(Seat::new(2)), Team::B);
+        assert_eq!(round.players.get_teams(), vec![Team::A, Team::B]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least two teams")]
+    fn test_one_team_cant_play() {
+        get_table_config().with_teams(&[Team::A, Team::A, Team::A, Team::A]);
+    }
+
+    #[test]
+    #[should_panic(expected = "every seat needs a team")]
+    fn test_every_seat_needs_a_team() {
+        get_table_config().with_teams(&[Team::A, Team::B]);
+    }
+
+    #[test]
+    fn test_seat_wraps_around() {
+        let seat = Seat::new(3);
+
+        assert_eq!(seat.next(4), Seat::new(0));
+        assert_eq!(seat.offset(2, 4), Seat::new(1));
+    }
+}
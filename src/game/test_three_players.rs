@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::auction::{Auction, Bid};
+    use crate::game::deck::CardSuit;
+    use crate::game::round::Round;
+    use crate::game::round_observer::NullObserver;
+    use crate::game::rules::{GameVariant, RuleSet};
+    use crate::game::table::{Seat, TableConfig};
+    use crate::game::team::{Team, TeamPoints};
+    use crate::game::test_utils::ScriptedRoundPlayer;
+    use crate::game::trump::ContractKind;
+
+    fn three_player_rules() -> RuleSet {
+        RuleSet {
+            variant: GameVariant::ThreePlayers,
+            ..Default::default()
+        }
+    }
+
+    fn three_player_table() -> TableConfig {
+        let player_names = ["a", "b", "c"].map(String::from).to_vec();
+        TableConfig::new(player_names, three_player_rules())
+    }
+
+    #[test]
+    fn test_three_player_deal() {
+        let round = Round::new(&three_player_table());
+
+        assert_eq!(round.get_number_of_players(), 3);
+        assert_eq!(round.talon.len(), 2);
+        for (index, player) in round.players.players.iter().enumerate() {
+            assert_eq!(player.hand.cards().len(), 10);
+            assert_eq!(player.get_team(), Team::from_index(index));
+        }
+    }
+
+    #[test]
+    fn test_three_player_round() {
+        let mut round = Round::new(&three_player_table());
+        round.play_round(
+            Box::new(ScriptedRoundPlayer {
+                declare_bela: false,
+                ..Default::default()
+            }),
+            &NullObserver,
+        );
+
+        assert_eq!(round.trick_history.len(), 10);
+        assert!(
+            round
+                .trick_history
+                .iter()
+                .all(|trick_item| trick_item.trick.cards_on_table.len() == 3)
+        );
+        // talon points go to the last trick winner, so every card is counted
+        let points_sum: usize = [Team::A, Team::B, Team::C]
+            .into_iter()
+            .map(|team| round.points.get_points(team))
+            .sum();
+        assert_eq!(points_sum, 162);
+    }
+
+    #[test]
+    fn test_failed_caller_points_are_split() {
+        let mut points = TeamPoints::default();
+        points.add_points(Team::A, 41);
+        points.add_points(Team::B, 60);
+        points.add_points(Team::C, 71);
+
+        assert!(!points.has_bigger_points(Team::A));
+        assert!(points.has_bigger_points(Team::C));
+
+        points.give_points_to_opponents(Team::A, &[Team::B, Team::C]);
+        assert_eq!(points.get_points(Team::A), 0);
+        assert_eq!(points.get_points(Team::B), 81);
+        assert_eq!(points.get_points(Team::C), 91);
+    }
+
+    #[test]
+    fn test_three_player_auction() {
+        let mut auction = Auction::new(Seat::new(2), vec![Team::A, Team::B, Team::C]);
+        auction.make_bid(Bid::Contract(ContractKind::Suit(CardSuit::Acorn)));
+        // everybody else is an opponent and can double
+        assert!(auction.get_valid_bids().contains(&Bid::Double));
+        auction.make_bid(Bid::Pass);
+        auction.make_bid(Bid::Pass);
+
+        let contract = auction.get_contract().expect("auction should be done");
+        assert_eq!(contract.seat, Seat::new(2));
+    }
+}
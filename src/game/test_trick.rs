@@ -1,26 +1,29 @@
 #[cfg(test)]
-mod test_trick {
+mod tests {
     use crate::game::player::{Hand, Player, Players};
+    use crate::game::table::Seat;
+    use crate::game::trump::ContractKind;
     use crate::game::{deck::Card, trick::Trick};
     use crate::game::{deck::CardSuit, deck::CardValue};
     fn get_playeble_card_test_fn(
         cards_on_table: Vec<Card>,
         cards_in_hand: Vec<Card>,
         expacted_result: Vec<Card>,
-        trump_color: CardSuit,
-        player_index: usize,
+        contract: ContractKind,
+        seat: usize,
     ) {
-        let mut trick = Trick::new(player_index);
+        let mut trick = Trick::new(Seat::new(seat), 4);
         trick.cards_on_table = cards_on_table;
         let mut players = Players::default();
-        players.players[player_index] = Player {
+        players.players[seat] = Player {
             name: "test".to_string(),
-            index: player_index,
+            seat: Seat::new(seat),
             hand: Hand {
                 hand: cards_in_hand,
             },
+            ..Default::default()
         };
-        let result = trick.get_playeble_cards(&players, &trump_color);
+        let result = trick.get_playeble_cards(&players, &contract);
 
         assert_eq!(result, expacted_result);
     }
@@ -385,7 +388,7 @@ mod test_trick {
                 test.cards_on_table,
                 test.cards_in_hand,
                 test.expacted,
-                test.trump_color,
+                ContractKind::Suit(test.trump_color),
                 cards_on_table_len,
             );
         }
@@ -515,12 +518,91 @@ mod test_trick {
         ];
 
         for test_case in test_cases {
-            let mut trick = Trick::new(test_case.start_index);
+            let mut trick = Trick::new(Seat::new(test_case.start_index), 4);
             for card in test_case.table {
                 trick.play_card(card);
             }
-            let result = trick.get_trick_winner(&test_case.trump);
-            assert_eq!(result, test_case.expected)
+            let result = trick.get_trick_winner(&ContractKind::Suit(test_case.trump));
+            assert_eq!(result, test_case.expected.map(Seat::new))
+        }
+    }
+
+    #[test]
+    fn test_get_playeble_cards_extended_contracts() {
+        let cards_on_table = vec![
+            Card::new(CardSuit::Acorn, CardValue::X),
+            Card::new(CardSuit::Acorn, CardValue::Jack),
+        ];
+        let cards_in_hand = vec![
+            Card::new(CardSuit::Herz, CardValue::Jack),
+            Card::new(CardSuit::Acorn, CardValue::VII),
+            Card::new(CardSuit::Acorn, CardValue::IX),
+            Card::new(CardSuit::Acorn, CardValue::Kec),
+        ];
+        // no trumps only requires following suit
+        get_playeble_card_test_fn(
+            cards_on_table.clone(),
+            cards_in_hand.clone(),
+            vec![
+                Card::new(CardSuit::Acorn, CardValue::VII),
+                Card::new(CardSuit::Acorn, CardValue::IX),
+                Card::new(CardSuit::Acorn, CardValue::Kec),
+            ],
+            ContractKind::NoTrumps,
+            2,
+        );
+        // in all trumps the jack is the best card, so nothing overtakes it
+        get_playeble_card_test_fn(
+            cards_on_table.clone(),
+            cards_in_hand.clone(),
+            vec![
+                Card::new(CardSuit::Acorn, CardValue::VII),
+                Card::new(CardSuit::Acorn, CardValue::IX),
+                Card::new(CardSuit::Acorn, CardValue::Kec),
+            ],
+            ContractKind::AllTrumps,
+            2,
+        );
+        get_playeble_card_test_fn(
+            vec![Card::new(CardSuit::Acorn, CardValue::X)],
+            cards_in_hand.clone(),
+            vec![
+                Card::new(CardSuit::Acorn, CardValue::IX),
+                Card::new(CardSuit::Acorn, CardValue::Kec),
+            ],
+            ContractKind::AllTrumps,
+            1,
+        );
+        // without the led suit any card can be played, even in all trumps
+        get_playeble_card_test_fn(
+            vec![Card::new(CardSuit::Leaf, CardValue::X)],
+            cards_in_hand.clone(),
+            cards_in_hand,
+            ContractKind::AllTrumps,
+            1,
+        );
+    }
+
+    #[test]
+    fn test_get_trick_winner_extended_contracts() {
+        let table = vec![
+            Card::new(CardSuit::Pumpkin, CardValue::X),
+            Card::new(CardSuit::Pumpkin, CardValue::IX),
+            Card::new(CardSuit::Herz, CardValue::Jack),
+            Card::new(CardSuit::Pumpkin, CardValue::Kec),
+        ];
+        let test_cases = vec![
+            (ContractKind::NoTrumps, Some(0)),
+            (ContractKind::AllTrumps, Some(2)),
+            (ContractKind::Suit(CardSuit::Herz), Some(3)),
+        ];
+
+        for (contract, expected) in test_cases {
+            let mut trick = Trick::new(Seat::new(1), 4);
+            for card in table.clone() {
+                trick.play_card(card);
+            }
+            assert_eq!(trick.get_trick_winner(&contract), expected.map(Seat::new));
         }
     }
 }
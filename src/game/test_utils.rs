@@ -0,0 +1,106 @@
+use strum::IntoEnumIterator;
+
+use crate::game::auction::{Bid, get_bid_for_contract_call};
+use crate::game::deck::{Card, CardSuit, CardValue};
+use crate::game::declaration::Declaration;
+use crate::game::player::Hand;
+use crate::game::round::Round;
+use crate::game::round_player::RoundPlayer;
+use crate::game::rules::RuleSet;
+use crate::game::table::Seat;
+use crate::game::table::TableConfig;
+use crate::game::trump::ContractKind;
+
+pub struct ScriptedRoundPlayer {
+    pub declare_bela: bool,
+    pub call_kontra: bool,
+    pub call_rekontra: bool,
+    pub contract: ContractKind,
+    /// Passes every bid of an auction.
+    pub pass_auction: bool,
+}
+
+impl Default for ScriptedRoundPlayer {
+    fn default() -> Self {
+        Self {
+            declare_bela: true,
+            call_kontra: false,
+            call_rekontra: false,
+            contract: ContractKind::Suit(CardSuit::Herz),
+            pass_auction: false,
+        }
+    }
+}
+
+impl RoundPlayer for ScriptedRoundPlayer {
+    fn try_call_trump(&self, _round_state: &Round, _seat: Seat) -> Option<CardSuit> {
+        Some(CardSuit::Herz)
+    }
+
+    fn must_call_trump(&self, _round_state: &Round, _seat: Seat) -> CardSuit {
+        CardSuit::Herz
+    }
+
+    fn try_call_contract(&self, _round_state: &Round, _seat: Seat) -> Option<ContractKind> {
+        Some(self.contract.clone())
+    }
+
+    fn make_bid(&self, round_state: &Round, seat: Seat, valid_bids: Vec<Bid>) -> Bid {
+        if self.pass_auction {
+            return Bid::Pass;
+        }
+        let contract = self.try_call_contract(round_state, seat);
+        get_bid_for_contract_call(contract, &valid_bids)
+    }
+
+    fn play_card(&self, _round_state: &Round, _seat: Seat, available_cards: Vec<Card>) -> Card {
+        available_cards[0].clone()
+    }
+
+    fn call_declaration(
+        &self,
+        _round_state: &Round,
+        _seat: Seat,
+        _declaration: &Declaration,
+    ) -> bool {
+        false
+    }
+
+    fn will_declare_bella(&self, _round_state: &Round, _seat: Seat) -> bool {
+        self.declare_bela
+    }
+
+    fn will_call_kontra(&self, _round_state: &Round, _seat: Seat) -> bool {
+        self.call_kontra
+    }
+
+    fn will_call_rekontra(&self, _round_state: &Round, _seat: Seat) -> bool {
+        self.call_rekontra
+    }
+}
+
+pub fn suit_hand(suit: CardSuit) -> Hand {
+    Hand::new(
+        CardValue::iter()
+            .map(|value| Card::new(suit.clone(), value))
+            .collect(),
+    )
+}
+
+// player 0 holds every trump, so they lead and win every trick playing
+// their hand from the lowest card up
+pub fn get_round(rules: RuleSet) -> Round {
+    let player_names = ["a", "b", "c", "d"].map(String::from).to_vec();
+    let mut round = Round::new(&TableConfig::new(player_names, rules));
+    let suits = [
+        CardSuit::Herz,
+        CardSuit::Leaf,
+        CardSuit::Pumpkin,
+        CardSuit::Acorn,
+    ];
+    for (player, suit) in round.players.players.iter_mut().zip(suits) {
+        player.hand = suit_hand(suit);
+    }
+
+    round
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::round::Round;
+    use crate::game::rules::RuleSet;
+    use crate::game::table::{Seat, TableConfig};
+    use crate::game::visibility::Visibility;
+
+    fn get_round() -> Round {
+        let player_names = ["a", "b", "c", "d"].map(String::from).to_vec();
+        Round::new(&TableConfig::new(player_names, RuleSet::default()).with_seed(Some(3)))
+    }
+
+    #[test]
+    fn test_names_are_parsed() {
+        for visibility in [
+            Visibility::Public,
+            Visibility::Seat(Seat::new(2)),
+            Visibility::Open,
+        ] {
+            assert_eq!(
+                Visibility::from_name(&visibility.get_name()),
+                Some(visibility)
+            );
+        }
+        assert_eq!(Visibility::from_name("seat-x"), None);
+        assert_eq!(Visibility::from_name("all"), None);
+    }
+
+    #[test]
+    fn test_hands_are_shown_by_visibility() {
+        let round = get_round();
+        assert!(Visibility::Public.get_hands(&round).is_empty());
+
+        let hands = Visibility::Seat(Seat::new(1)).get_hands(&round);
+        assert_eq!(hands.len(), 1);
+        assert_eq!(hands[0].0, Seat::new(1));
+        assert_eq!(&hands[0].1, round.get_player(Seat::new(1)).hand.cards());
+
+        let hands = Visibility::Open.get_hands(&round);
+        let seats = hands
+            .iter()
+            .map(|(seat, _)| seat.index())
+            .collect::<Vec<_>>();
+        assert_eq!(seats, [0, 1, 2, 3]);
+    }
+}
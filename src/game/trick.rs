@@ -1,92 +1,177 @@
-use crate::game::trump::Trump;
 use crate::game::points::{better_than_normal, better_than_trump};
+use crate::game::trump::{ContractKind, Trump};
 
+use super::table::Seat;
 use super::team::Team;
 use super::{
     deck::{Card, CardSuit},
-    player::{NUMBER_OF_PLAYERS, Players},
-    points::{get_best_normal, get_best_trump, get_normal_points, get_trump_points},
+    player::Players,
+    points::{get_best_normal, get_best_trump, get_card_points},
     round::Round,
 };
 
 #[derive(Debug, Clone)]
 pub struct Trick {
-    player_index_turn: usize,
+    seat_turn: Seat,
+    first_seat: Seat,
+    number_of_players: usize,
     pub cards_on_table: Vec<Card>,
 }
 
 impl Trick {
-    pub fn new(player_index_turn: usize) -> Self {
+    pub fn new(seat_turn: Seat, number_of_players: usize) -> Self {
         Trick {
             cards_on_table: vec![],
-            player_index_turn,
+            seat_turn,
+            first_seat: seat_turn,
+            number_of_players,
         }
     }
 
     pub fn is_done(&self) -> bool {
-        self.cards_on_table.len() >= NUMBER_OF_PLAYERS
+        self.cards_on_table.len() >= self.number_of_players
     }
 
     fn trick_winner_by_color(
         &self,
         color: &CardSuit,
         better: fn(a: &Card, b: &Card) -> bool,
-    ) -> Option<usize> {
+    ) -> Option<Seat> {
         let best_card_index_on_table = self
             .cards_on_table
             .iter()
             .enumerate()
             .filter(|(_, card)| card.suit == *color)
-            .reduce(|acc, curr| if better(&acc.1, &curr.1) { acc } else { curr })?
+            .reduce(|acc, curr| if better(acc.1, curr.1) { acc } else { curr })?
             .0;
-        let player_index = (best_card_index_on_table + self.player_index_turn) % NUMBER_OF_PLAYERS;
+        let seat = self
+            .first_seat
+            .offset(best_card_index_on_table, self.number_of_players);
 
-        return Some(player_index);
+        Some(seat)
     }
 
-    pub fn get_trick_winner(&self, trump: &CardSuit) -> Option<usize> {
+    pub fn get_trick_winner(&self, contract: &ContractKind) -> Option<Seat> {
         if !self.is_done() {
             return None;
         }
 
-        let has_trump = self.cards_on_table.iter().any(|card| card.suit == *trump);
-        if has_trump {
-            return self.trick_winner_by_color(trump, better_than_trump);
-        }
-        let first_card_color = &self.cards_on_table.get(0)?.suit;
+        self.get_current_winner(contract)
+    }
+
+    /// Seat holding the trick with the cards played so far.
+    pub fn get_current_winner(&self, contract: &ContractKind) -> Option<Seat> {
+        let first_card_color = &self.cards_on_table.first()?.suit;
+        match contract {
+            ContractKind::Suit(trump) => {
+                let has_trump = self.cards_on_table.iter().any(|card| card.suit == *trump);
+                if has_trump {
+                    return self.trick_winner_by_color(trump, better_than_trump);
+                }
 
-        return self.trick_winner_by_color(first_card_color, better_than_normal);
+                self.trick_winner_by_color(first_card_color, better_than_normal)
+            }
+            ContractKind::NoTrumps => {
+                self.trick_winner_by_color(first_card_color, better_than_normal)
+            }
+            ContractKind::AllTrumps => {
+                self.trick_winner_by_color(first_card_color, better_than_trump)
+            }
+        }
     }
 
     pub fn play_card(&mut self, card: Card) {
         self.cards_on_table.push(card);
-        self.player_index_turn += 1;
-        self.player_index_turn %= NUMBER_OF_PLAYERS;
+        self.seat_turn = self.seat_turn.next(self.number_of_players);
     }
 
-    pub fn get_player_index_turn(&self) -> usize {
-        self.player_index_turn
+    pub fn get_seat_turn(&self) -> Seat {
+        self.seat_turn
     }
 
-    pub fn get_playeble_cards(&self, players: &Players, trump_color: &CardSuit) -> Vec<Card> {
-        let number_of_cards_on_table = self.cards_on_table.len();
+    pub fn get_first_seat(&self) -> Seat {
+        self.first_seat
+    }
+
+    pub fn has_player_played_card(&self, seat: Seat, card: &Card) -> bool {
+        self.cards_on_table
+            .iter()
+            .enumerate()
+            .any(|(index, table_card)| {
+                table_card == card && self.first_seat.offset(index, self.number_of_players) == seat
+            })
+    }
+
+    pub fn get_playeble_cards(&self, players: &Players, contract: &ContractKind) -> Vec<Card> {
         let player_cards = players
-            .get(self.get_player_index_turn())
+            .get(self.get_seat_turn())
             .expect("player index always to be inside player boundaries")
             .get_hand()
             .cards();
-        let cloned_cards = player_cards.clone();
+
+        self.filter_playable_cards(player_cards, contract)
+    }
+
+    /// Cards from the given hand that can be played on this trick.
+    pub fn filter_playable_cards(
+        &self,
+        player_cards: &[Card],
+        contract: &ContractKind,
+    ) -> Vec<Card> {
+        let number_of_cards_on_table = self.cards_on_table.len();
+        let cloned_cards = player_cards.to_vec();
         if number_of_cards_on_table == 0 {
             return cloned_cards;
         }
 
-        self.filter_by_played_first_card(cloned_cards, trump_color)
+        match contract {
+            ContractKind::Suit(trump_color) => {
+                self.filter_by_played_first_card(cloned_cards, trump_color)
+            }
+            ContractKind::NoTrumps => self.filter_no_trumps_options(cloned_cards),
+            ContractKind::AllTrumps => self.filter_all_trumps_options(cloned_cards),
+        }
+    }
+
+    fn get_first_card_suit(&self) -> &CardSuit {
+        &self
+            .cards_on_table
+            .first()
+            .expect("should have at least one card on table")
+            .suit
+    }
+
+    // in no trumps you only have to follow suit, overtaking is not required
+    fn filter_no_trumps_options(&self, cards: Vec<Card>) -> Vec<Card> {
+        let first_suit = self.get_first_card_suit();
+        let has_matching_suit = cards.iter().any(|card| card.suit == *first_suit);
+        if !has_matching_suit {
+            return cards;
+        }
+
+        cards
+            .into_iter()
+            .filter(|card| card.suit == *first_suit)
+            .collect()
+    }
+
+    // in all trumps every suit behaves like trump, so you follow suit and
+    // overtake whenever you can
+    fn filter_all_trumps_options(&self, cards: Vec<Card>) -> Vec<Card> {
+        let first_suit = self.get_first_card_suit();
+        let has_matching_suit = cards.iter().any(|card| card.suit == *first_suit);
+        if !has_matching_suit {
+            return cards;
+        }
+        let best_card = get_best_trump(&self.cards_on_table, first_suit.clone());
+
+        self.filter_trump_options(cards, best_card, first_suit)
     }
 
     fn filter_by_played_first_card(&self, cards: Vec<Card>, trump_color: &CardSuit) -> Vec<Card> {
         let first_card = self
             .cards_on_table
-            .get(0)
+            .first()
             .expect("should have at least one card on table");
         let has_matching_suit = cards.iter().any(|card| card.suit == first_card.suit);
         let best_trump = get_best_trump(&self.cards_on_table, trump_color.clone());
@@ -121,20 +206,20 @@ impl Trick {
             .filter(|card| card.suit == first_card.suit)
             .collect();
 
-        if let Some(_) = best_trump {
+        if best_trump.is_some() {
             return filtered;
         }
 
         let has_uber = filtered
             .iter()
-            .any(|card| better_than_normal(&card, &best_normal));
+            .any(|card| better_than_normal(card, &best_normal));
         if !has_uber {
             return filtered;
         }
 
         filtered
             .into_iter()
-            .filter(|card| better_than_normal(&card, &best_normal))
+            .filter(|card| better_than_normal(card, &best_normal))
             .collect()
     }
 
@@ -148,7 +233,7 @@ impl Trick {
             .into_iter()
             .filter(|card| card.suit == *trump_color)
             .collect();
-        if let None = best_trump {
+        if best_trump.is_none() {
             return filtered;
         }
         let best_trump = best_trump.expect("must be Some because we checked None");
@@ -166,48 +251,41 @@ impl Trick {
     }
 
     pub fn get_points(&self, trump: &Trump) -> usize {
-        self.cards_on_table.iter().fold(0, |acc, curr| {
-            if curr.suit == trump.trump_suit {
-                acc + get_trump_points(&curr.value)
-            } else {
-                acc + get_normal_points(&curr.value)
-            }
-        })
+        self.cards_on_table
+            .iter()
+            .fold(0, |acc, curr| acc + get_card_points(curr, &trump.kind))
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct TrickHistoryItem {
-    #[allow(dead_code)]
     pub trick: Trick,
-    #[allow(dead_code)]
     pub trump: Trump,
-    pub player_index_winner: usize,
+    pub winner_seat: Seat,
     pub team_winner: Team,
     pub points: usize,
 }
 
 impl TrickHistoryItem {
     pub fn new(round_state: &Round, trick: Trick) -> Self {
-        let player_index_winner = trick
-            .get_trick_winner(&round_state.trump.trump_suit)
+        let winner_seat = trick
+            .get_trick_winner(&round_state.trump.kind)
             .expect("To trick is done we always have a trick winner");
-        let player_winner = &round_state.players.players[player_index_winner];
-        let team_winner = player_winner.get_team();
+        let team_winner = round_state.players.get_team(winner_seat);
         let trump = round_state.trump.clone();
         let points = trick.get_points(&round_state.trump);
 
         Self {
             trick,
             trump,
-            player_index_winner,
+            winner_seat,
             team_winner,
             points,
         }
     }
 
-    pub fn get_winner_index(&self) -> usize {
-        self.player_index_winner
+    pub fn get_winner_seat(&self) -> Seat {
+        self.winner_seat
     }
 
     pub fn get_winner_team(&self) -> &Team {
@@ -1,17 +1,65 @@
-use strum::EnumCount;
+use super::{auction::get_all_contract_kinds, deck::CardSuit, table::Seat};
 
-use super::{deck::CardSuit, team::Team};
+/// Contract of the round, see [`ContractKind::get_bid_rank`] for which
+/// one is higher in the auction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContractKind {
+    Suit(CardSuit),
+    /// No suit is trump, cards rank as in a non-trump suit and score by
+    /// [`get_no_trumps_points`](super::points::get_no_trumps_points).
+    NoTrumps,
+    /// Every suit is trump, cards rank as in a trump suit and score by
+    /// [`get_all_trumps_points`](super::points::get_all_trumps_points).
+    AllTrumps,
+}
 
-#[derive(Debug, Default, Clone)]
-pub struct Trump {
-    pub player_index: usize,
-    pub trump_suit: CardSuit,
+impl Default for ContractKind {
+    fn default() -> Self {
+        Self::Suit(CardSuit::default())
+    }
 }
 
-impl Trump {
-    pub fn get_caller_team(&self) -> Team {
-        let team_index = self.player_index % Team::COUNT;
+impl ContractKind {
+    pub fn is_trump_suit(&self, suit: &CardSuit) -> bool {
+        match self {
+            Self::Suit(trump_suit) => trump_suit == suit,
+            Self::NoTrumps => false,
+            Self::AllTrumps => true,
+        }
+    }
+
+    /// Rank of the contract in the auction, clubs are the lowest suit,
+    /// then diamonds, hearts and spades, then no trumps and all trumps.
+    pub fn get_bid_rank(&self) -> usize {
+        match self {
+            Self::Suit(CardSuit::Acorn) => 0,
+            Self::Suit(CardSuit::Pumpkin) => 1,
+            Self::Suit(CardSuit::Herz) => 2,
+            Self::Suit(CardSuit::Leaf) => 3,
+            Self::NoTrumps => 4,
+            Self::AllTrumps => 5,
+        }
+    }
 
-        Team::from_index(team_index)
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Suit(suit) => suit.clone().into(),
+            Self::NoTrumps => "NoTrumps",
+            Self::AllTrumps => "AllTrumps",
+        }
     }
+
+    /// Contract with the name given by [`ContractKind::get_name`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        get_all_contract_kinds()
+            .into_iter()
+            .find(|kind| kind.get_name() == name)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Trump {
+    pub seat: Seat,
+    pub kind: ContractKind,
 }
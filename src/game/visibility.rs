@@ -0,0 +1,54 @@
+use super::{deck::Card, round::Round, table::Seat};
+
+/// Hands a spectator of a table may see, everything else that happens at
+/// the table is public.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Visibility {
+    /// No hand, only what the players show on the table.
+    #[default]
+    Public,
+    /// The hand of one seat, the round as its player sees it.
+    Seat(Seat),
+    /// Every hand.
+    Open,
+}
+
+impl Visibility {
+    /// Parses `public`, `open` or `seat-N` with the seat counted from 0.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "public" => Some(Self::Public),
+            "open" => Some(Self::Open),
+            _ => {
+                let seat = name.strip_prefix("seat-")?.parse().ok()?;
+                Some(Self::Seat(Seat::new(seat)))
+            }
+        }
+    }
+
+    pub fn get_name(self) -> String {
+        match self {
+            Self::Public => "public".to_string(),
+            Self::Seat(seat) => format!("seat-{}", seat.index()),
+            Self::Open => "open".to_string(),
+        }
+    }
+
+    pub fn shows_hand(self, seat: Seat) -> bool {
+        match self {
+            Self::Public => false,
+            Self::Seat(visible_seat) => visible_seat == seat,
+            Self::Open => true,
+        }
+    }
+
+    /// Hands the spectator sees in seat order, with the seat holding them.
+    pub fn get_hands(self, round_state: &Round) -> Vec<(Seat, Vec<Card>)> {
+        (0..round_state.get_number_of_players())
+            .map(Seat::new)
+            .filter(|seat| self.shows_hand(*seat))
+            .map(|seat| (seat, round_state.get_player(seat).hand.cards().clone()))
+            .collect()
+    }
+}
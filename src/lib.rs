@@ -0,0 +1,99 @@
+//! Belot card game engine: cards and rules, the round and match state
+//! machines, bots and everything needed to let them play against each other.
+//!
+//! A round asks a [`RoundPlayer`] for every decision and tells a
+//! [`RoundObserver`] about everything that happens. Implementing the first
+//! one is all it takes to bring your own agent to the table:
+//!
+//! ```
+//! use belot_game_optmal::{
+//!     Card, CardSuit, NullObserver, Round, RoundPlayer, RuleSet, Seat, TableConfig,
+//!     game::declaration::Declaration,
+//! };
+//!
+//! /// Never calls trump on its own and always plays the first card it can.
+//! struct FirstCardPlayer;
+//!
+//! impl RoundPlayer for FirstCardPlayer {
+//!     fn try_call_trump(&self, _round_state: &Round, _seat: Seat) -> Option<CardSuit> {
+//!         None
+//!     }
+//!
+//!     fn must_call_trump(&self, _round_state: &Round, _seat: Seat) -> CardSuit {
+//!         CardSuit::Herz
+//!     }
+//!
+//!     fn play_card(&self, _round_state: &Round, _seat: Seat, available_cards: Vec<Card>) -> Card {
+//!         available_cards[0].clone()
+//!     }
+//!
+//!     fn call_declaration(&self, _round_state: &Round, _seat: Seat, _declaration: &Declaration) -> bool {
+//!         true
+//!     }
+//!
+//!     fn will_declare_bella(&self, _round_state: &Round, _seat: Seat) -> bool {
+//!         true
+//!     }
+//! }
+//!
+//! let names = ["Ana", "Ivo", "Maja", "Luka"].map(String::from).to_vec();
+//! let table_config = TableConfig::new(names, RuleSet::default()).with_seed(Some(1));
+//! let mut round = Round::new(&table_config);
+//! round.play_round(Box::new(FirstCardPlayer), &NullObserver);
+//!
+//! assert_eq!(round.trick_history.len(), 8);
+//! ```
+//!
+//! Different agents can share a table with [`SeatedRoundPlayer`], and a
+//! [`Match`] keeps playing rounds until a team reaches the target points:
+//!
+//! ```
+//! use belot_game_optmal::{
+//!     HeuristicRoundPlayer, Match, NullObserver, RuleSet, SeatedRoundPlayer, TableConfig,
+//!     clients::random_round_player::RandomRoundPlayer,
+//! };
+//!
+//! let names = ["Ana", "Ivo", "Maja", "Luka"].map(String::from).to_vec();
+//! let table_config = TableConfig::new(names, RuleSet::default()).with_seed(Some(7));
+//! let mut game_match = Match::new(table_config, 301);
+//! while !game_match.is_done() {
+//!     let round_player = SeatedRoundPlayer::new(vec![
+//!         HeuristicRoundPlayer::boxed(),
+//!         Box::new(RandomRoundPlayer),
+//!         HeuristicRoundPlayer::boxed(),
+//!         Box::new(RandomRoundPlayer),
+//!     ]);
+//!     game_match.play_round(Box::new(round_player), &NullObserver);
+//! }
+//!
+//! assert!(game_match.get_winner().is_some());
+//! ```
+
+#[cfg(any(feature = "python", feature = "wasm"))]
+mod bindings;
+pub mod clients;
+pub mod game;
+#[cfg(feature = "net")]
+pub mod net;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod render;
+pub mod simulation;
+mod utils;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use clients::{
+    heuristic_round_player::HeuristicRoundPlayer, seated_round_player::SeatedRoundPlayer,
+};
+pub use game::{
+    deck::{Card, CardSuit, CardValue},
+    game_match::Match,
+    round::{Round, RoundUpdateEvent},
+    round_observer::{NullObserver, RoundObserver},
+    round_player::RoundPlayer,
+    rules::{BelaAnnouncementRule, GameVariant, RuleSet},
+    table::{Seat, TableConfig},
+    team::{Team, TeamPoints},
+};
+pub use utils::random::{RandomSource, set_random_seed, set_random_source};
@@ -1,17 +1,10 @@
-mod clients;
-mod game;
-mod utils;
-use clients::cli_round_player::CliRoundPlayer;
-use game::{round::Round};
-use utils::console::clear_console;
+mod commands;
+#[cfg(feature = "tui")]
+mod tui;
 
 fn main() {
-    clear_console();
-    let round_player = CliRoundPlayer::boxed();
-    let test_player_names = ["Beki", "Zvona", "Murko", "Zorka"];
-    let mut round = Round::new(0, test_player_names);
-    round.play_round(round_player);
-
-    println!("points {:#?}", round.points);
-    println!("final_points {:#?}", round.final_points);
+    if let Err(error) = commands::run() {
+        eprintln!("error: {}", error);
+        std::process::exit(1);
+    }
 }
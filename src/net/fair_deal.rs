@@ -0,0 +1,131 @@
+//! Deals the players can check. Before every round the server commits to a
+//! secret with its SHA-256 hash, then every client adds entropy of its own.
+//! The deal comes from the hash of the secret and the entropy, so the
+//! server can't choose it. Once the round is over the server reveals the
+//! secret and every client can check the commitment, its cards and that
+//! its own entropy went into the deal.
+//!
+//! The clients don't commit to their entropy, so this only keeps a server
+//! from choosing the deal on its own. A server that tells a client the
+//! secret, or a client the entropy of the others before it gives its own,
+//! lets that client choose the deal.
+//!
+//! The seed of a round is the SHA-256 hash of the secret and the entropy of
+//! every seat in seat order, each on a line of its own and empty for seats
+//! that gave none. The deck in the order of [`Deck::new`] is shuffled from
+//! the last card down: card `i` swaps with card `n % (i + 1)`, where `n` is
+//! the big endian number in the first 8 bytes of the hash of the seed and
+//! the byte `i`. Seat `k` gets the `k`th hand of the shuffled deck, the
+//! cards after the hands are the talon. A redeal after an auction nobody
+//! bid in comes from the rng of the table seeded with [`get_table_seed`].
+
+use sha2::{Digest, Sha256};
+
+use crate::game::{
+    deck::{Card, Deal, Deck},
+    rules::GameVariant,
+};
+
+/// Longest entropy a client may give, in hexadecimal digits.
+pub const MAX_ENTROPY_LENGTH: usize = 64;
+
+pub type Seed = [u8; 32];
+
+fn get_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// New random secret of the server, 32 bytes in hexadecimal.
+pub fn get_secret() -> String {
+    get_hex(&rand::random::<Seed>())
+}
+
+/// SHA-256 hash of the secret in hexadecimal, sent before the deal.
+pub fn get_commitment(secret: &str) -> String {
+    get_hex(&Sha256::digest(secret.as_bytes()))
+}
+
+/// Entropy is hexadecimal, so it can't change how the seed is put together.
+pub fn is_entropy_valid(entropy: &str) -> bool {
+    !entropy.is_empty()
+        && entropy.len() <= MAX_ENTROPY_LENGTH
+        && entropy.chars().all(|digit| digit.is_ascii_hexdigit())
+}
+
+pub fn get_seed(secret: &str, entropies: &[Option<String>]) -> Seed {
+    let mut hasher = Sha256::new();
+    hasher.update(secret.as_bytes());
+    for entropy in entropies {
+        hasher.update(b"\n");
+        hasher.update(entropy.as_deref().unwrap_or_default().as_bytes());
+    }
+
+    hasher.finalize().into()
+}
+
+/// Seed of the rng a redeal of the round comes from.
+pub fn get_table_seed(seed: &Seed) -> u64 {
+    u64::from_be_bytes(seed[..8].try_into().expect("8 bytes"))
+}
+
+pub fn get_deal(seed: &Seed, variant: GameVariant) -> Deal {
+    let mut cards = Deck::new().deck;
+    for index in (1..cards.len()).rev() {
+        let mut hasher = Sha256::new();
+        hasher.update(seed);
+        hasher.update([index as u8]);
+        let hash = hasher.finalize();
+        let number = u64::from_be_bytes(hash[..8].try_into().expect("8 bytes"));
+        cards.swap(index, (number % (index as u64 + 1)) as usize);
+    }
+
+    let hand_size = variant.get_hand_size();
+    let number_of_players = variant.get_number_of_players();
+    let talon = cards.split_off(hand_size * number_of_players);
+    let hands = cards
+        .chunks(hand_size)
+        .map(|hand| {
+            let mut hand = hand.to_vec();
+            hand.sort_by_key(|card| (card.suit.clone(), card.value.clone()));
+            hand
+        })
+        .collect();
+
+    Deal { hands, talon }
+}
+
+/// Checks the revealed secret against the commitment, the entropy that was
+/// sent against the revealed one and the cards that were seen against the
+/// deal of the seed. `sent_entropies` and `hands` pair seats with the
+/// entropy the client gave and the cards they were dealt.
+pub fn verify_deal(
+    commitment: &str,
+    secret: &str,
+    entropies: &[Option<String>],
+    variant: GameVariant,
+    sent_entropies: &[(usize, String)],
+    hands: &[(usize, Vec<Card>)],
+) -> Result<(), String> {
+    if get_commitment(secret) != commitment {
+        return Err("the secret doesn't match the commitment".to_string());
+    }
+    for (seat, entropy) in sent_entropies {
+        if entropies.get(*seat).and_then(Option::as_ref) != Some(entropy) {
+            return Err(format!("the entropy of seat {} was left out", seat));
+        }
+    }
+    let deal = get_deal(&get_seed(secret, entropies), variant);
+    for (seat, cards) in hands {
+        let dealt = deal
+            .hands
+            .get(*seat)
+            .ok_or_else(|| format!("the deal has no seat {}", seat))?;
+        let mut cards = cards.clone();
+        cards.sort_by_key(|card| (card.suit.clone(), card.value.clone()));
+        if cards != *dealt {
+            return Err(format!("seat {} wasn't dealt the committed cards", seat));
+        }
+    }
+
+    Ok(())
+}
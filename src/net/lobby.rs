@@ -0,0 +1,794 @@
+//! Rooms where clients meet before they play. A client in the lobby can
+//! open a room or join one, choose its seat and with it its team, put bots
+//! in the seats nobody took, change the rules and start the match once
+//! every seat is taken. Everybody in a room may change it. After a match
+//! the room stays together and can start a rematch.
+//!
+//! Clients that join without choosing a room play a quick match in a room
+//! with the rules and bots of the server, it starts as soon as its seats
+//! are taken.
+//!
+//! A client that loses its connection during a match keeps its seat, the
+//! bot plays for it until it comes back with the token of its welcome.
+//!
+//! Clients can watch a room instead of playing in it. Spectators may change
+//! the room like the clients in its seats, a room of bots and spectators
+//! plays matches nobody takes part in.
+//!
+//! The lobby only keeps the state and tells clients about it through their
+//! message queues, the server plays the matches it starts.
+
+use std::{
+    collections::BTreeMap,
+    sync::mpsc::{self, Receiver, Sender},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use crate::{
+    clients::{
+        heuristic_round_player::HeuristicRoundPlayer, random_round_player::RandomRoundPlayer,
+        search_round_player::SearchRoundPlayer,
+    },
+    game::{rules::RuleSet, visibility::Visibility},
+    simulation::{AgentFactory, MATCH_SEED_STRIDE},
+};
+
+use super::{
+    protocol::{
+        ClientMessage, RoomMessage, RulesMessage, SeatMessage, ServerMessage, SpectatorMessage,
+        VisibilityMessage, get_team_name,
+    },
+    server::{MoveTimeout, ServerConfig},
+};
+
+/// Bot a seat of a room can be given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumIter)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BotKind {
+    Random,
+    Heuristic,
+    /// Simulates deals to choose its cards, see [`SearchRoundPlayer`].
+    Search,
+}
+
+impl BotKind {
+    pub fn get_factory(self) -> AgentFactory {
+        match self {
+            Self::Random => || Box::new(RandomRoundPlayer),
+            Self::Heuristic => HeuristicRoundPlayer::boxed,
+            Self::Search => SearchRoundPlayer::boxed,
+        }
+    }
+}
+
+/// Name a bot plays under.
+pub fn get_bot_name(seat: usize) -> String {
+    format!("Bot {}", seat + 1)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Occupant {
+    Client(usize),
+    Bot(BotKind),
+}
+
+struct Room {
+    name: String,
+    rules: RuleSet,
+    target_points: usize,
+    seats: Vec<Option<Occupant>>,
+    playing: bool,
+    matches_played: usize,
+    /// Room of a quick match, it starts once every seat is taken.
+    quick: bool,
+    spectators: BTreeMap<usize, Visibility>,
+    /// Where clients that come back and spectators go while the room plays
+    /// a match.
+    updates: Option<Sender<MatchUpdate>>,
+}
+
+impl Room {
+    fn get_seat_of(&self, client: usize) -> Option<usize> {
+        self.seats
+            .iter()
+            .position(|occupant| *occupant == Some(Occupant::Client(client)))
+    }
+
+    fn get_clients(&self) -> impl Iterator<Item = usize> + '_ {
+        self.seats.iter().filter_map(|occupant| match occupant {
+            Some(Occupant::Client(client)) => Some(*client),
+            _ => None,
+        })
+    }
+
+    /// Clients in the seats and spectators.
+    fn get_members(&self) -> impl Iterator<Item = usize> + '_ {
+        self.get_clients().chain(self.spectators.keys().copied())
+    }
+
+    fn is_full(&self) -> bool {
+        self.seats.iter().all(Option::is_some)
+    }
+
+    fn get_team_name(&self, seat: usize) -> String {
+        get_team_name(self.rules.variant.get_team(seat))
+    }
+
+    /// Seat a client can take: the chosen one unless another client has
+    /// it, a free seat of the chosen team or else any free seat.
+    fn find_seat(&self, seat: Option<usize>, team: Option<&str>) -> Result<usize, String> {
+        if let Some(seat) = seat {
+            return match self.seats.get(seat) {
+                None => Err(format!("the room has no seat {}", seat)),
+                Some(Some(Occupant::Client(_))) => Err(format!("seat {} is taken", seat)),
+                Some(_) => Ok(seat),
+            };
+        }
+
+        (0..self.seats.len())
+            .find(|seat| {
+                self.seats[*seat].is_none()
+                    && team.is_none_or(|team| self.get_team_name(*seat) == team)
+            })
+            .ok_or_else(|| match team {
+                Some(team) => format!("team {} has no free seat", team),
+                None => "the room is full".to_string(),
+            })
+    }
+}
+
+struct LobbyClient {
+    name: String,
+    token: String,
+    outbox: Sender<ServerMessage>,
+    /// A client without a connection is only kept until its match is over.
+    connected: bool,
+    room: Option<usize>,
+    /// Where the answers of the client go while it plays a match.
+    answers: Option<Sender<String>>,
+}
+
+/// Player of a seat in a match the lobby started.
+pub enum MatchSeat {
+    Bot(BotKind),
+    Client {
+        outbox: Sender<ServerMessage>,
+        answers: Receiver<String>,
+    },
+}
+
+/// Change of the people at a running match.
+#[non_exhaustive]
+pub enum MatchUpdate {
+    /// Client that takes its seat back.
+    Rejoin {
+        seat: usize,
+        outbox: Sender<ServerMessage>,
+        answers: Receiver<String>,
+    },
+    /// Client that starts watching, or watches with another visibility.
+    Watch {
+        client: usize,
+        outbox: Sender<ServerMessage>,
+        visibility: Visibility,
+    },
+    StopWatching {
+        client: usize,
+    },
+}
+
+/// Everything the server needs to play the match of a room.
+pub struct MatchStart {
+    pub room: usize,
+    pub rules: RuleSet,
+    pub target_points: usize,
+    pub seed: Option<u64>,
+    pub move_timeout: Option<MoveTimeout>,
+    pub fair_dealing: bool,
+    pub spectator_delay: Duration,
+    pub names: Vec<String>,
+    pub seats: Vec<MatchSeat>,
+    /// Spectators of the room are the first updates.
+    pub updates: Receiver<MatchUpdate>,
+}
+
+/// Secret a client comes back to its seat with.
+fn get_token() -> String {
+    format!(
+        "{:016x}{:016x}",
+        rand::random::<u64>(),
+        rand::random::<u64>()
+    )
+}
+
+pub struct Lobby {
+    config: ServerConfig,
+    clients: BTreeMap<usize, LobbyClient>,
+    rooms: BTreeMap<usize, Room>,
+    next_client: usize,
+    next_room: usize,
+    /// Matches started so far, every match gets a seed of its own.
+    matches_started: usize,
+}
+
+impl Lobby {
+    /// The rules, seed and bots of `config` are the ones of quick matches,
+    /// new rooms get its rules and seed.
+    pub fn new(config: ServerConfig) -> Self {
+        Self {
+            config,
+            clients: BTreeMap::new(),
+            rooms: BTreeMap::new(),
+            next_client: 0,
+            next_room: 0,
+            matches_started: 0,
+        }
+    }
+
+    /// Lets a client that said hello in, every message for it goes to
+    /// `outbox`.
+    pub fn connect(&mut self, name: String, outbox: Sender<ServerMessage>) -> usize {
+        let client = self.next_client;
+        self.next_client += 1;
+        self.clients.insert(
+            client,
+            LobbyClient {
+                name,
+                token: get_token(),
+                outbox,
+                connected: true,
+                room: None,
+                answers: None,
+            },
+        );
+        self.welcome(client);
+        self.send(client, self.get_rooms_message());
+
+        client
+    }
+
+    /// Lets a client that lost its connection during a match back in with
+    /// the token of its welcome, it gets its seat back.
+    pub fn reconnect(
+        &mut self,
+        token: &str,
+        outbox: Sender<ServerMessage>,
+    ) -> Result<usize, String> {
+        let (client, lobby_client) = self
+            .clients
+            .iter_mut()
+            .find(|(_, lobby_client)| lobby_client.token == token)
+            .ok_or("the token belongs to no client")?;
+        if lobby_client.connected {
+            return Err(format!("client {} is still connected", client));
+        }
+        let client = *client;
+        lobby_client.outbox = outbox;
+        lobby_client.connected = true;
+        let room_id = lobby_client
+            .room
+            .expect("room of a client without a connection");
+
+        self.welcome(client);
+        self.rejoin(client, room_id);
+        self.update_room(room_id);
+
+        Ok(client)
+    }
+
+    /// A client that left during a match keeps its seat until the match is
+    /// over, the bot of the server plays for it.
+    pub fn disconnect(&mut self, client: usize) {
+        let Some(lobby_client) = self.clients.get(&client) else {
+            return;
+        };
+        let Some(room_id) = lobby_client.room else {
+            self.clients.remove(&client);
+            return;
+        };
+        if self.rooms[&room_id].spectators.contains_key(&client) {
+            self.stop_watching(client, room_id);
+            self.clients.remove(&client);
+            self.update_room(room_id);
+            return;
+        }
+        let room = self.rooms.get_mut(&room_id).expect("room of a client");
+        if room.playing {
+            let lobby_client = self.clients.get_mut(&client).expect("connected client");
+            lobby_client.connected = false;
+            // the match stops waiting for its answers
+            lobby_client.answers = None;
+        } else {
+            let seat = room.get_seat_of(client).expect("seat of a client");
+            room.seats[seat] = None;
+            self.clients.remove(&client);
+        }
+        self.update_room(room_id);
+    }
+
+    /// Acts on a message of the client, the client gets an error when the
+    /// message isn't allowed now. Returns the match to play when the
+    /// message started one.
+    pub fn handle(&mut self, client: usize, message: ClientMessage) -> Option<MatchStart> {
+        match self.handle_message(client, message) {
+            Ok(start) => start,
+            Err(message) => {
+                self.send(client, ServerMessage::Error { message });
+                None
+            }
+        }
+    }
+
+    /// Opens the room for changes and a rematch.
+    pub fn finish_match(&mut self, room_id: usize) {
+        let Some(room) = self.rooms.get_mut(&room_id) else {
+            return;
+        };
+        room.playing = false;
+        room.matches_played += 1;
+        room.updates = None;
+        for seat in 0..room.seats.len() {
+            if let Some(Occupant::Client(client)) = room.seats[seat] {
+                let lobby_client = self.clients.get_mut(&client).expect("seated client");
+                lobby_client.answers = None;
+                if !lobby_client.connected {
+                    room.seats[seat] = None;
+                    self.clients.remove(&client);
+                }
+            }
+        }
+        self.update_room(room_id);
+    }
+
+    fn handle_message(
+        &mut self,
+        client: usize,
+        message: ClientMessage,
+    ) -> Result<Option<MatchStart>, String> {
+        match message {
+            ClientMessage::Hello { .. } => return Err("hello was already said".to_string()),
+            ClientMessage::ListRooms => self.send(client, self.get_rooms_message()),
+            ClientMessage::CreateRoom { name, rules } => {
+                self.check_not_in_room(client)?;
+                let room_id = self.next_room;
+                let (rules, target_points) = match rules {
+                    Some(rules) => (rules.get_rules(), rules.target_points),
+                    None => (self.config.rules.clone(), self.config.target_points),
+                };
+                let mut seats = vec![None; rules.variant.get_number_of_players()];
+                seats[0] = Some(Occupant::Client(client));
+                self.add_room(Room {
+                    name: name.unwrap_or_else(|| format!("Room {}", room_id + 1)),
+                    rules,
+                    target_points,
+                    seats,
+                    playing: false,
+                    matches_played: 0,
+                    quick: false,
+                    spectators: BTreeMap::new(),
+                    updates: None,
+                });
+                self.get_client(client).room = Some(room_id);
+                self.update_room(room_id);
+            }
+            ClientMessage::JoinRoom { room, seat, team } => {
+                self.check_not_in_room(client)?;
+                let room_id = match room {
+                    Some(room_id) => room_id,
+                    None => self.get_quick_room(),
+                };
+                let room = self
+                    .rooms
+                    .get_mut(&room_id)
+                    .ok_or_else(|| format!("there is no room {}", room_id))?;
+                if room.playing {
+                    return Err("the room is playing a match".to_string());
+                }
+                let seat = room.find_seat(seat, team.as_deref())?;
+                room.seats[seat] = Some(Occupant::Client(client));
+                self.get_client(client).room = Some(room_id);
+                self.update_room(room_id);
+                let room = &self.rooms[&room_id];
+                if room.quick && room.is_full() {
+                    return Ok(Some(self.start_match(room_id)));
+                }
+            }
+            ClientMessage::ChooseSeat { seat, team } => {
+                let room_id = self.get_open_room(client)?;
+                let room = self.rooms.get_mut(&room_id).expect("room of a client");
+                let current_seat = room.get_seat_of(client);
+                if let Some(current_seat) = current_seat {
+                    room.seats[current_seat] = None;
+                }
+                match room.find_seat(seat, team.as_deref()) {
+                    Ok(seat) => room.seats[seat] = Some(Occupant::Client(client)),
+                    Err(message) => {
+                        if let Some(current_seat) = current_seat {
+                            room.seats[current_seat] = Some(Occupant::Client(client));
+                        }
+                        return Err(message);
+                    }
+                }
+                room.spectators.remove(&client);
+                self.update_room(room_id);
+                let room = &self.rooms[&room_id];
+                if room.quick && room.is_full() {
+                    return Ok(Some(self.start_match(room_id)));
+                }
+            }
+            ClientMessage::SetBot { seat, bot } => {
+                let room_id = self.get_open_room(client)?;
+                let room = self.rooms.get_mut(&room_id).expect("room of a client");
+                match room.seats.get(seat) {
+                    None => return Err(format!("the room has no seat {}", seat)),
+                    Some(Some(Occupant::Client(_))) => {
+                        return Err(format!("seat {} is taken", seat));
+                    }
+                    Some(_) => room.seats[seat] = bot.map(Occupant::Bot),
+                }
+                self.update_room(room_id);
+            }
+            ClientMessage::SetRules { rules } => {
+                let room_id = self.get_open_room(client)?;
+                let room = self.rooms.get_mut(&room_id).expect("room of a client");
+                let new_rules = rules.get_rules();
+                let number_of_players = new_rules.variant.get_number_of_players();
+                let removed_seats = room.seats.get(number_of_players..).unwrap_or_default();
+                if removed_seats
+                    .iter()
+                    .any(|occupant| matches!(occupant, Some(Occupant::Client(_))))
+                {
+                    return Err("a client sits in a seat the rules don't have".to_string());
+                }
+                if room.spectators.values().any(|visibility| {
+                    matches!(visibility, Visibility::Seat(seat) if seat.index() >= number_of_players)
+                }) {
+                    return Err("a spectator watches a seat the rules don't have".to_string());
+                }
+                room.seats.resize(number_of_players, None);
+                room.rules = new_rules;
+                room.target_points = rules.target_points;
+                self.update_room(room_id);
+            }
+            ClientMessage::Start => {
+                let room_id = self.get_open_room(client)?;
+                if !self.rooms[&room_id].is_full() {
+                    return Err("every seat needs a player or a bot".to_string());
+                }
+                return Ok(Some(self.start_match(room_id)));
+            }
+            ClientMessage::Spectate { room, visibility } => {
+                self.spectate(client, room, visibility)?;
+            }
+            ClientMessage::LeaveRoom => {
+                let room_id = self.clients[&client]
+                    .room
+                    .ok_or("join a room first".to_string())?;
+                if self.rooms[&room_id].spectators.contains_key(&client) {
+                    self.stop_watching(client, room_id);
+                } else {
+                    let room_id = self.get_open_room(client)?;
+                    let room = self.rooms.get_mut(&room_id).expect("room of a client");
+                    let seat = room.get_seat_of(client).expect("seat of a client");
+                    room.seats[seat] = None;
+                }
+                self.get_client(client).room = None;
+                self.update_room(room_id);
+            }
+            ClientMessage::Resume => {
+                let room_id = self.clients[&client]
+                    .room
+                    .ok_or("join a room first".to_string())?;
+                let room = &self.rooms[&room_id];
+                if room.get_seat_of(client).is_none() {
+                    return Err("spectators have no seat to take back".to_string());
+                }
+                if !room.playing {
+                    return Err("the room isn't playing a match".to_string());
+                }
+                self.rejoin(client, room_id);
+            }
+            // entropy goes the same way, the match tells it apart
+            ClientMessage::Answer { answer } | ClientMessage::Entropy { entropy: answer } => {
+                let answers = self
+                    .get_client(client)
+                    .answers
+                    .as_ref()
+                    .ok_or("nothing was asked")?;
+                if answers.send(answer).is_err() {
+                    return Err("nothing was asked".to_string());
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn get_client(&mut self, client: usize) -> &mut LobbyClient {
+        self.clients.get_mut(&client).expect("connected client")
+    }
+
+    fn welcome(&self, client: usize) {
+        let message = ServerMessage::Welcome {
+            client,
+            token: self.clients[&client].token.clone(),
+            bots: BotKind::iter().collect(),
+        };
+        self.send(client, message);
+    }
+
+    /// Gives the seat of the client in the match of its room back to it.
+    fn rejoin(&mut self, client: usize, room_id: usize) {
+        let room = &self.rooms[&room_id];
+        let lobby_client = self.clients.get_mut(&client).expect("connected client");
+        let (answer_sender, answers) = mpsc::channel();
+        lobby_client.answers = Some(answer_sender);
+        let rejoin = MatchUpdate::Rejoin {
+            seat: room.get_seat_of(client).expect("seat of a client"),
+            outbox: lobby_client.outbox.clone(),
+            answers,
+        };
+        if let Some(updates) = &room.updates {
+            // a match that is over doesn't take clients back
+            _ = updates.send(rejoin);
+        }
+    }
+
+    /// Lets the client watch the room, a client of the room leaves its
+    /// seat for it.
+    fn spectate(
+        &mut self,
+        client: usize,
+        room_id: usize,
+        visibility: VisibilityMessage,
+    ) -> Result<(), String> {
+        if let Some(current_room) = self.clients[&client].room
+            && current_room != room_id
+        {
+            return Err(format!("leave room {} first", current_room));
+        }
+        let room = self
+            .rooms
+            .get_mut(&room_id)
+            .ok_or_else(|| format!("there is no room {}", room_id))?;
+        let visibility = visibility.get_visibility();
+        if let Visibility::Seat(seat) = visibility
+            && seat.index() >= room.seats.len()
+        {
+            return Err(format!("the room has no seat {}", seat.index()));
+        }
+        if let Some(seat) = room.get_seat_of(client) {
+            if room.playing {
+                return Err("the room is playing a match".to_string());
+            }
+            room.seats[seat] = None;
+        }
+        room.spectators.insert(client, visibility);
+        let lobby_client = self.clients.get_mut(&client).expect("connected client");
+        lobby_client.room = Some(room_id);
+        if let Some(updates) = &room.updates {
+            // a match that is over doesn't take spectators
+            _ = updates.send(MatchUpdate::Watch {
+                client,
+                outbox: lobby_client.outbox.clone(),
+                visibility,
+            });
+        }
+        self.update_room(room_id);
+
+        Ok(())
+    }
+
+    /// Takes the spectator out of the room and its match.
+    fn stop_watching(&mut self, client: usize, room_id: usize) {
+        let room = self.rooms.get_mut(&room_id).expect("room of a client");
+        room.spectators.remove(&client);
+        if let Some(updates) = &room.updates {
+            _ = updates.send(MatchUpdate::StopWatching { client });
+        }
+    }
+
+    fn send(&self, client: usize, message: ServerMessage) {
+        if let Some(lobby_client) = self.clients.get(&client) {
+            // a client that is gone is removed by its own thread
+            _ = lobby_client.outbox.send(message);
+        }
+    }
+
+    fn check_not_in_room(&self, client: usize) -> Result<(), String> {
+        match self.clients[&client].room {
+            Some(room_id) => Err(format!("leave room {} first", room_id)),
+            None => Ok(()),
+        }
+    }
+
+    /// Room of the client when it doesn't play a match in it.
+    fn get_open_room(&self, client: usize) -> Result<usize, String> {
+        let room_id = self.clients[&client]
+            .room
+            .ok_or("join a room first".to_string())?;
+        if self.rooms[&room_id].playing {
+            return Err("the room is playing a match".to_string());
+        }
+
+        Ok(room_id)
+    }
+
+    fn add_room(&mut self, room: Room) -> usize {
+        let room_id = self.next_room;
+        self.next_room += 1;
+        self.rooms.insert(room_id, room);
+
+        room_id
+    }
+
+    /// Quick match that waits for players, a new one when none does.
+    fn get_quick_room(&mut self) -> usize {
+        let waiting = self.rooms.iter().find(|(_, room)| {
+            room.quick && !room.playing && room.seats.iter().any(Option::is_none)
+        });
+        if let Some((room_id, _)) = waiting {
+            return *room_id;
+        }
+
+        let room = Room {
+            name: format!("Quick match {}", self.next_room + 1),
+            rules: self.config.rules.clone(),
+            target_points: self.config.target_points,
+            seats: self
+                .config
+                .bots
+                .iter()
+                .map(|bot| bot.map(Occupant::Bot))
+                .collect(),
+            playing: false,
+            matches_played: 0,
+            quick: true,
+            spectators: BTreeMap::new(),
+            updates: None,
+        };
+        self.add_room(room)
+    }
+
+    fn start_match(&mut self, room_id: usize) -> MatchStart {
+        let seed = self
+            .config
+            .seed
+            .map(|seed| seed.wrapping_add(self.matches_started as u64 * MATCH_SEED_STRIDE));
+        self.matches_started += 1;
+        let room = self.rooms.get_mut(&room_id).expect("started room");
+        room.playing = true;
+        let (update_sender, updates) = mpsc::channel();
+        for (client, visibility) in &room.spectators {
+            _ = update_sender.send(MatchUpdate::Watch {
+                client: *client,
+                outbox: self.clients[client].outbox.clone(),
+                visibility: *visibility,
+            });
+        }
+        room.updates = Some(update_sender);
+
+        let mut names = vec![];
+        let mut seats = vec![];
+        for (seat, occupant) in room.seats.iter().copied().enumerate() {
+            match occupant.expect("full room") {
+                Occupant::Bot(bot) => {
+                    names.push(get_bot_name(seat));
+                    seats.push(MatchSeat::Bot(bot));
+                }
+                Occupant::Client(client) => {
+                    let lobby_client = self.clients.get_mut(&client).expect("seated client");
+                    let (answer_sender, answers) = mpsc::channel();
+                    lobby_client.answers = Some(answer_sender);
+                    names.push(lobby_client.name.clone());
+                    seats.push(MatchSeat::Client {
+                        outbox: lobby_client.outbox.clone(),
+                        answers,
+                    });
+                }
+            }
+        }
+        let start = MatchStart {
+            room: room_id,
+            rules: room.rules.clone(),
+            target_points: room.target_points,
+            seed,
+            move_timeout: self.config.move_timeout,
+            fair_dealing: self.config.fair_dealing,
+            spectator_delay: self.config.spectator_delay,
+            names,
+            seats,
+            updates,
+        };
+        self.update_room(room_id);
+
+        start
+    }
+
+    fn get_room_message(&self, room_id: usize, room: &Room) -> RoomMessage {
+        let seats = room
+            .seats
+            .iter()
+            .enumerate()
+            .map(|(seat, occupant)| {
+                let (name, client, bot) = match occupant {
+                    Some(Occupant::Client(client)) => {
+                        let name = self
+                            .clients
+                            .get(client)
+                            .map(|lobby_client| lobby_client.name.clone());
+                        (name, Some(*client), None)
+                    }
+                    Some(Occupant::Bot(bot)) => (Some(get_bot_name(seat)), None, Some(*bot)),
+                    None => (None, None, None),
+                };
+                SeatMessage {
+                    seat,
+                    team: room.get_team_name(seat),
+                    name,
+                    client,
+                    bot,
+                }
+            })
+            .collect();
+        let spectators = room
+            .spectators
+            .iter()
+            .filter_map(|(client, visibility)| {
+                Some(SpectatorMessage {
+                    client: *client,
+                    name: self.clients.get(client)?.name.clone(),
+                    visibility: VisibilityMessage::new(*visibility),
+                })
+            })
+            .collect();
+
+        RoomMessage {
+            room: room_id,
+            name: room.name.clone(),
+            rules: RulesMessage::new(&room.rules, room.target_points),
+            seats,
+            spectators,
+            playing: room.playing,
+            matches_played: room.matches_played,
+        }
+    }
+
+    fn get_rooms_message(&self) -> ServerMessage {
+        ServerMessage::Rooms {
+            rooms: self
+                .rooms
+                .iter()
+                .map(|(room_id, room)| self.get_room_message(*room_id, room))
+                .collect(),
+        }
+    }
+
+    /// Tells the clients and spectators in the room and the clients in no
+    /// room about a change, a room nobody is left in is closed.
+    fn update_room(&mut self, room_id: usize) {
+        let room = &self.rooms[&room_id];
+        if !room.playing && room.get_members().next().is_none() {
+            self.rooms.remove(&room_id);
+        } else {
+            let message = ServerMessage::Room {
+                room: self.get_room_message(room_id, room),
+            };
+            for client in room.get_members() {
+                self.send(client, message.clone());
+            }
+        }
+
+        let rooms = self.get_rooms_message();
+        for (client, lobby_client) in &self.clients {
+            if lobby_client.room.is_none() {
+                self.send(*client, rooms.clone());
+            }
+        }
+    }
+}
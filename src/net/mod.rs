@@ -0,0 +1,169 @@
+//! Multiplayer over TCP and WebSockets. Clients and the server exchange
+//! JSON messages, see [`protocol`] for the messages. Over TCP every message
+//! is one line, over a WebSocket every message is one text frame; browsers
+//! get the client page from the address the WebSockets connect to, see
+//! [`web`]. A client says hello with its name and meets the other clients
+//! in the [`lobby`], where it joins a room and takes a seat. Once the match
+//! of the room starts, the client gets asked for its decisions like any
+//! other player. Spectators watch a room instead, they hear about the
+//! table with the hands their visibility shows, as late as the server
+//! delays them. A server that deals fairly lets every client check its
+//! deals, see [`fair_deal`].
+//!
+//! ```text
+//! -> {"type":"hello","name":"Ana"}
+//! <- {"type":"welcome","client":0,"bots":["random","heuristic","search"]}
+//! <- {"type":"rooms","rooms":[]}
+//! -> {"type":"join_room"}
+//! <- {"type":"room","room":{"room":0,"name":"Quick match 1",...,"playing":false,...}}
+//! <- {"type":"match_started","seat":0,"names":["Ana","Ivo","Maja","Luka"],...}
+//! <- {"type":"view","view":{"seat":0,"hand":["Herz-Jack",...],...}}
+//! <- {"type":"ask","request":"trump","options":["Leaf","Pumpkin","Herz","Acorn","pass"],"declaration":null}
+//! -> {"type":"answer","answer":"pass"}
+//! <- {"type":"event","event":{"kind":"pass","seat":0}}
+//! ```
+
+pub mod fair_deal;
+pub mod lobby;
+pub mod protocol;
+pub mod server;
+pub mod web;
+
+use std::{
+    io::{self, BufRead, BufReader, ErrorKind, Read, Write},
+    mem,
+    net::TcpStream,
+    time::Duration,
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use tungstenite::{Message, WebSocket};
+
+/// Longest line a peer may send, a longer one ends the connection.
+const MAX_LINE_LENGTH: usize = 8192;
+
+enum Transport {
+    Lines {
+        reader: BufReader<TcpStream>,
+        writer: TcpStream,
+        /// Start of a line a timeout interrupted.
+        pending: Vec<u8>,
+    },
+    WebSocket(Box<WebSocket<TcpStream>>),
+}
+
+/// Messages as JSON over a TCP stream, one per line, or over a WebSocket,
+/// one per text frame.
+pub struct Connection {
+    transport: Transport,
+}
+
+fn get_io_error(error: tungstenite::Error) -> io::Error {
+    match error {
+        tungstenite::Error::Io(error) => error,
+        tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed => {
+            ErrorKind::ConnectionAborted.into()
+        }
+        error => io::Error::other(error),
+    }
+}
+
+impl Connection {
+    pub fn new(stream: TcpStream) -> io::Result<Self> {
+        Ok(Self {
+            transport: Transport::Lines {
+                reader: BufReader::new(stream.try_clone()?),
+                writer: stream,
+                pending: vec![],
+            },
+        })
+    }
+
+    /// Connection over a WebSocket that finished its handshake.
+    pub fn from_web_socket(socket: WebSocket<TcpStream>) -> Self {
+        Self {
+            transport: Transport::WebSocket(Box::new(socket)),
+        }
+    }
+
+    /// How long [`Connection::receive`] waits before it gives up with an
+    /// error [`is_timeout`] recognizes, `None` waits for as long as it takes.
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        match &self.transport {
+            Transport::Lines { writer, .. } => writer.set_read_timeout(timeout),
+            Transport::WebSocket(socket) => socket.get_ref().set_read_timeout(timeout),
+        }
+    }
+
+    pub fn send<T: Serialize>(&mut self, message: &T) -> io::Result<()> {
+        match &mut self.transport {
+            Transport::Lines { writer, .. } => {
+                let mut line = serde_json::to_vec(message)?;
+                line.push(b'\n');
+                writer.write_all(&line)?;
+                writer.flush()
+            }
+            Transport::WebSocket(socket) => {
+                let text = serde_json::to_string(message)?;
+                socket.send(Message::text(text)).map_err(get_io_error)
+            }
+        }
+    }
+
+    /// Next message, `None` once the other side closed the connection. A
+    /// line or frame that isn't a valid message is an
+    /// [`ErrorKind::InvalidData`] error, the connection can still be used
+    /// after it. A line longer than 8192 bytes is any other error.
+    pub fn receive<T: DeserializeOwned>(&mut self) -> io::Result<Option<T>> {
+        let text = match &mut self.transport {
+            Transport::Lines {
+                reader, pending, ..
+            } => {
+                let limit = MAX_LINE_LENGTH + 1 - pending.len();
+                if reader
+                    .by_ref()
+                    .take(limit as u64)
+                    .read_until(b'\n', pending)?
+                    == 0
+                {
+                    return Ok(None);
+                }
+                if pending.len() > MAX_LINE_LENGTH && pending.last() != Some(&b'\n') {
+                    return Err(io::Error::other(format!(
+                        "line is longer than {} bytes",
+                        MAX_LINE_LENGTH
+                    )));
+                }
+                String::from_utf8(mem::take(pending))
+                    .map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?
+            }
+            Transport::WebSocket(socket) => loop {
+                match socket.read() {
+                    Ok(Message::Text(text)) => break text.to_string(),
+                    Ok(Message::Close(_))
+                    | Err(tungstenite::Error::ConnectionClosed)
+                    | Err(tungstenite::Error::AlreadyClosed) => return Ok(None),
+                    // pings are answered by the socket itself
+                    Ok(_) => continue,
+                    Err(error) => return Err(get_io_error(error)),
+                }
+            },
+        };
+        serde_json::from_str(&text)
+            .map(Some)
+            .map_err(|error| io::Error::new(ErrorKind::InvalidData, error))
+    }
+}
+
+/// Whether the error only means that nothing arrived within the read
+/// timeout.
+pub fn is_timeout(error: &io::Error) -> bool {
+    matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut)
+}
+
+#[cfg(test)]
+mod test_fair_deal;
+#[cfg(test)]
+mod test_lobby;
+#[cfg(test)]
+mod test_server;
@@ -0,0 +1,531 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::{
+    deck::Card,
+    game_match::DEFAULT_TARGET_POINTS,
+    player_view::PlayerView,
+    round::{Round, RoundUpdateEvent},
+    rules::{RuleFlags, RuleSet},
+    table::Seat,
+    team::Team,
+    visibility::Visibility,
+};
+
+use crate::clients::engine_protocol::Request;
+
+use super::lobby::BotKind;
+
+fn get_card_names(cards: &[Card]) -> Vec<String> {
+    cards.iter().map(Card::get_name).collect()
+}
+
+pub fn get_team_name(team: Team) -> String {
+    let name: &str = team.into();
+    name.to_string()
+}
+
+/// Message a client sends to the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ClientMessage {
+    /// First message of every client, it enters the lobby. A client that
+    /// lost its connection during a match comes back to its seat with the
+    /// token of its welcome, under the name it had.
+    Hello {
+        name: String,
+        #[serde(default)]
+        token: Option<String>,
+    },
+    ListRooms,
+    /// Opens a room with the rules of the server unless others are given,
+    /// the client takes the first seat.
+    CreateRoom {
+        #[serde(default)]
+        name: Option<String>,
+        #[serde(default)]
+        rules: Option<RulesMessage>,
+    },
+    /// Takes a seat in a room, the chosen one or a free seat of the chosen
+    /// team. Without a room the client plays the next quick match, it
+    /// starts as soon as its seats are taken.
+    JoinRoom {
+        #[serde(default)]
+        room: Option<usize>,
+        #[serde(default)]
+        seat: Option<usize>,
+        #[serde(default)]
+        team: Option<String>,
+    },
+    /// Moves to another seat of the room, the chosen one or a free seat of
+    /// the chosen team.
+    ChooseSeat {
+        #[serde(default)]
+        seat: Option<usize>,
+        #[serde(default)]
+        team: Option<String>,
+    },
+    /// Puts a bot in a seat no client has, `None` takes it away.
+    SetBot {
+        seat: usize,
+        #[serde(default)]
+        bot: Option<BotKind>,
+    },
+    SetRules {
+        rules: RulesMessage,
+    },
+    /// Starts the match of the room once every seat is taken, after a
+    /// match it starts the rematch.
+    Start,
+    /// Watches a room instead of playing in it, a client of the room gives
+    /// its seat up unless the room plays a match. Watching again changes
+    /// what the client sees, choosing a seat ends watching.
+    Spectate {
+        room: usize,
+        #[serde(default)]
+        visibility: VisibilityMessage,
+    },
+    LeaveRoom,
+    /// Takes the seat back from the bot that plays for the client and
+    /// sends the state of the round again.
+    Resume,
+    /// Answer to the last `ask`, one of its options.
+    Answer {
+        answer: String,
+    },
+    /// Answer to `deal_committed`, up to 64 hexadecimal digits the next
+    /// deal comes from as well.
+    Entropy {
+        entropy: String,
+    },
+}
+
+/// Message the server sends to a client.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ServerMessage {
+    /// Answer to the hello, `client` tells the client apart in rooms and
+    /// `token` brings it back after a lost connection.
+    Welcome {
+        client: usize,
+        token: String,
+        bots: Vec<BotKind>,
+    },
+    /// Rooms of the server, sent to clients that aren't in a room whenever
+    /// a room changes.
+    Rooms {
+        rooms: Vec<RoomMessage>,
+    },
+    /// Room of the client, sent to everybody in it whenever it changes.
+    Room {
+        room: RoomMessage,
+    },
+    MatchStarted {
+        seat: usize,
+        names: Vec<String>,
+        /// Team of every seat.
+        teams: Vec<String>,
+        target_points: usize,
+    },
+    /// Match a spectator watches, sent when it starts or when the
+    /// spectator starts watching it.
+    Watching {
+        visibility: VisibilityMessage,
+        names: Vec<String>,
+        teams: Vec<String>,
+        target_points: usize,
+    },
+    /// Hands the spectator may see, sent whenever they change.
+    Hands {
+        hands: Vec<HandMessage>,
+    },
+    /// What the client may know about the round, sent before every `ask`
+    /// and when the client comes back to its seat.
+    View {
+        view: ViewMessage,
+    },
+    Event {
+        event: EventMessage,
+    },
+    /// The client has to decide, `declaration` is the one it may declare.
+    Ask {
+        request: String,
+        options: Vec<String>,
+        declaration: Option<DeclarationMessage>,
+    },
+    Error {
+        message: String,
+    },
+    /// Hash of the secret the next deal comes from, players answer with
+    /// their entropy. Only sent when the server deals fairly, see
+    /// [`fair_deal`](super::fair_deal).
+    DealCommitted {
+        round: usize,
+        commitment: String,
+    },
+    /// Secret of the round that was just played and the entropy of every
+    /// seat, the deal can be checked with them.
+    DealRevealed {
+        round: usize,
+        secret: String,
+        entropies: Vec<Option<String>>,
+    },
+    RoundOver {
+        round: usize,
+        scores: Vec<ScoreMessage>,
+    },
+    MatchOver {
+        winner: String,
+    },
+}
+
+impl ServerMessage {
+    pub fn ask(seat: usize, request: &Request) -> Self {
+        let declaration = match request {
+            Request::Declaration(declaration) => Some(DeclarationMessage {
+                seat,
+                cards: get_card_names(&declaration.cards),
+                points: declaration.points,
+            }),
+            _ => None,
+        };
+
+        Self::Ask {
+            request: request.get_name().to_string(),
+            options: request.get_options(),
+            declaration,
+        }
+    }
+}
+
+/// Rules of a room, fields that are left out keep their defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RulesMessage {
+    pub three_players: bool,
+    pub kontra: bool,
+    pub extended_contracts: bool,
+    pub auction: bool,
+    pub bela_on_second_card: bool,
+    pub target_points: usize,
+}
+
+impl Default for RulesMessage {
+    fn default() -> Self {
+        Self::new(&RuleSet::default(), DEFAULT_TARGET_POINTS)
+    }
+}
+
+impl RulesMessage {
+    pub fn new(rules: &RuleSet, target_points: usize) -> Self {
+        let flags = rules.get_flags();
+        Self {
+            three_players: flags.three_players,
+            kontra: flags.kontra,
+            extended_contracts: flags.extended_contracts,
+            auction: flags.auction,
+            bela_on_second_card: flags.bela_on_second_card,
+            target_points,
+        }
+    }
+
+    pub fn get_rules(&self) -> RuleSet {
+        RuleSet::from_flags(RuleFlags {
+            three_players: self.three_players,
+            kontra: self.kontra,
+            extended_contracts: self.extended_contracts,
+            auction: self.auction,
+            bela_on_second_card: self.bela_on_second_card,
+        })
+    }
+}
+
+/// Seat of a room, taken by a client, a bot or nobody yet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeatMessage {
+    pub seat: usize,
+    pub team: String,
+    /// Name of the client or the bot in the seat.
+    pub name: Option<String>,
+    pub client: Option<usize>,
+    pub bot: Option<BotKind>,
+}
+
+/// What a spectator sees, see [`Visibility`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum VisibilityMessage {
+    #[default]
+    Public,
+    Seat {
+        seat: usize,
+    },
+    Open,
+}
+
+impl VisibilityMessage {
+    pub fn new(visibility: Visibility) -> Self {
+        match visibility {
+            Visibility::Public => Self::Public,
+            Visibility::Seat(seat) => Self::Seat { seat: seat.index() },
+            Visibility::Open => Self::Open,
+        }
+    }
+
+    pub fn get_visibility(self) -> Visibility {
+        match self {
+            Self::Public => Visibility::Public,
+            Self::Seat { seat } => Visibility::Seat(Seat::new(seat)),
+            Self::Open => Visibility::Open,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpectatorMessage {
+    pub client: usize,
+    pub name: String,
+    pub visibility: VisibilityMessage,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomMessage {
+    pub room: usize,
+    pub name: String,
+    pub rules: RulesMessage,
+    pub seats: Vec<SeatMessage>,
+    pub spectators: Vec<SpectatorMessage>,
+    pub playing: bool,
+    /// Matches finished in the room, the ones after the first are rematches.
+    pub matches_played: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeclarationMessage {
+    pub seat: usize,
+    pub cards: Vec<String>,
+    pub points: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HandMessage {
+    pub seat: usize,
+    pub cards: Vec<String>,
+}
+
+impl HandMessage {
+    /// Hands of the round `visibility` shows.
+    pub fn get_hands(visibility: Visibility, round_state: &Round) -> Vec<Self> {
+        visibility
+            .get_hands(round_state)
+            .into_iter()
+            .map(|(seat, cards)| Self {
+                seat: seat.index(),
+                cards: get_card_names(&cards),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreMessage {
+    pub team: String,
+    /// Points of the round that was just played.
+    pub points: usize,
+    /// Points of the match so far.
+    pub total: usize,
+}
+
+/// Something that happened at the table, every client gets every event.
+/// Seats count from 0, cards, contracts and bids use the names of round
+/// records.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum EventMessage {
+    Card {
+        seat: usize,
+        card: String,
+    },
+    /// The seat didn't call trump.
+    Pass {
+        seat: usize,
+    },
+    Trump {
+        seat: usize,
+        contract: String,
+    },
+    Bid {
+        seat: usize,
+        bid: String,
+    },
+    Redealt,
+    Declaration {
+        seat: usize,
+        cards: Vec<String>,
+        points: usize,
+    },
+    Kontra {
+        seat: usize,
+    },
+    Rekontra {
+        seat: usize,
+    },
+    Bela {
+        seat: usize,
+    },
+    Trick {
+        seat: usize,
+        points: usize,
+    },
+    /// The seat didn't answer in time and the server decided for it.
+    TimedOut {
+        seat: usize,
+    },
+    /// The bot plays for the seat until its client comes back.
+    BotTookOver {
+        seat: usize,
+    },
+    /// The client of the seat plays again.
+    PlayerReturned {
+        seat: usize,
+    },
+}
+
+impl EventMessage {
+    pub fn from_round_event(round_event: &RoundUpdateEvent) -> Vec<Self> {
+        let event = match round_event {
+            RoundUpdateEvent::CardPlayed { seat, card } => Self::Card {
+                seat: seat.index(),
+                card: card.get_name(),
+            },
+            RoundUpdateEvent::TrumpCallEvent { seat, trump } => match trump {
+                Some(trump) => Self::Trump {
+                    seat: seat.index(),
+                    contract: trump.kind.get_name().to_string(),
+                },
+                None => Self::Pass { seat: seat.index() },
+            },
+            RoundUpdateEvent::BidMade { seat, bid } => Self::Bid {
+                seat: seat.index(),
+                bid: bid.get_name().to_string(),
+            },
+            RoundUpdateEvent::Redealt => Self::Redealt,
+            RoundUpdateEvent::DeclarationsCalled(declarations) => {
+                return declarations
+                    .iter()
+                    .map(|info| Self::Declaration {
+                        seat: info.seat.index(),
+                        cards: get_card_names(&info.declaration.cards),
+                        points: info.declaration.points,
+                    })
+                    .collect();
+            }
+            RoundUpdateEvent::KontraCalled { seat } => Self::Kontra { seat: seat.index() },
+            RoundUpdateEvent::RekontraCalled { seat } => Self::Rekontra { seat: seat.index() },
+            RoundUpdateEvent::BelaDeclared(announcement) => Self::Bela {
+                seat: announcement.seat.index(),
+            },
+            RoundUpdateEvent::TrickDone(trick_item) => Self::Trick {
+                seat: trick_item.get_winner_seat().index(),
+                points: trick_item.get_points(),
+            },
+        };
+
+        vec![event]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BidMessage {
+    pub seat: usize,
+    pub bid: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrumpMessage {
+    pub seat: usize,
+    pub contract: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrickMessage {
+    pub first_seat: usize,
+    pub cards: Vec<String>,
+    /// Seat that took the trick, `None` while the trick is played.
+    pub winner: Option<usize>,
+}
+
+/// [`PlayerView`] of one seat, the only state of the round a client gets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewMessage {
+    pub seat: usize,
+    pub hand: Vec<String>,
+    /// `None` until somebody called trump.
+    pub trump: Option<TrumpMessage>,
+    pub bids: Vec<BidMessage>,
+    pub tricks: Vec<TrickMessage>,
+    pub current_trick: TrickMessage,
+    pub declarations: Vec<DeclarationMessage>,
+    pub bela: Vec<usize>,
+}
+
+impl ViewMessage {
+    pub fn new(view: &PlayerView, is_trump_called: bool) -> Self {
+        let get_trick = |first_seat: Seat, cards: &[Card], winner: Option<Seat>| TrickMessage {
+            first_seat: first_seat.index(),
+            cards: get_card_names(cards),
+            winner: winner.map(Seat::index),
+        };
+
+        Self {
+            seat: view.seat.index(),
+            hand: get_card_names(view.hand.cards()),
+            trump: is_trump_called.then(|| TrumpMessage {
+                seat: view.trump.seat.index(),
+                contract: view.trump.kind.get_name().to_string(),
+            }),
+            bids: view
+                .auction_bids
+                .iter()
+                .map(|item| BidMessage {
+                    seat: item.seat.index(),
+                    bid: item.bid.get_name().to_string(),
+                })
+                .collect(),
+            tricks: view
+                .trick_history
+                .iter()
+                .map(|item| {
+                    get_trick(
+                        item.trick.get_first_seat(),
+                        &item.trick.cards_on_table,
+                        Some(item.winner_seat),
+                    )
+                })
+                .collect(),
+            current_trick: get_trick(
+                view.current_trick.get_first_seat(),
+                &view.current_trick.cards_on_table,
+                None,
+            ),
+            declarations: view
+                .team_declarations
+                .declarations
+                .iter()
+                .flatten()
+                .map(|info| DeclarationMessage {
+                    seat: info.seat.index(),
+                    cards: get_card_names(&info.declaration.cards),
+                    points: info.declaration.points,
+                })
+                .collect(),
+            bela: view
+                .bela_announcements
+                .iter()
+                .map(|announcement| announcement.seat.index())
+                .collect(),
+        }
+    }
+}
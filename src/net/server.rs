@@ -0,0 +1,705 @@
+use std::{
+    cell::{Cell, RefCell},
+    io::{self, ErrorKind},
+    net::{TcpListener, TcpStream},
+    rc::Rc,
+    sync::{
+        Arc, Mutex, MutexGuard,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    clients::{
+        engine_protocol::{Answer, Request},
+        protocol_round_player::{ProtocolRoundPlayer, RequestAnswerer},
+        seated_round_player::SeatedRoundPlayer,
+    },
+    game::{
+        deck::Card,
+        game_match::Match,
+        points::get_card_points,
+        round::{Round, RoundUpdateEvent},
+        round_observer::RoundObserver,
+        rules::RuleSet,
+        table::{Seat, TableConfig},
+        trump::ContractKind,
+        visibility::Visibility,
+    },
+};
+
+use super::{
+    Connection, fair_deal, is_timeout,
+    lobby::{BotKind, Lobby, MatchSeat, MatchStart, MatchUpdate},
+    protocol::{
+        ClientMessage, EventMessage, HandMessage, ScoreMessage, ServerMessage, ViewMessage,
+        VisibilityMessage, get_team_name,
+    },
+    web,
+};
+
+/// How long a client thread waits for a message before it passes on the
+/// messages queued for its client.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Time the clients have to give their entropy for a deal.
+const ENTROPY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Pause after a failed accept, so running out of file descriptors
+/// doesn't spin the accept loop.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// What the server does for a client that doesn't answer in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeoutFallback {
+    /// Plays the lowest card the client may play, the bot makes every
+    /// other decision. The client keeps its seat.
+    LowestCard,
+    /// Hands the seat to the bot until the client resumes.
+    Bot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveTimeout {
+    /// Time a client has for every decision.
+    pub limit: Duration,
+    pub fallback: TimeoutFallback,
+}
+
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// Rules of quick matches and new rooms.
+    pub rules: RuleSet,
+    pub target_points: usize,
+    /// Makes the deals reproducible, every match gets a seed of its own.
+    pub seed: Option<u64>,
+    /// Bot of every seat of a quick match, seats without one are taken by
+    /// clients in the order they join.
+    pub bots: Vec<Option<BotKind>>,
+    /// Clients may think as long as they like without one.
+    pub move_timeout: Option<MoveTimeout>,
+    /// Spectators learn what happens at a table this much later, so they
+    /// can't tell the players in time.
+    pub spectator_delay: Duration,
+    /// Deals every round so the clients can check it, see [`fair_deal`].
+    pub fair_dealing: bool,
+}
+
+/// Card with the fewest points, trumps are kept as long as possible.
+fn get_lowest_card(contract: &ContractKind, cards: &[Card]) -> Card {
+    cards
+        .iter()
+        .min_by_key(|card| {
+            (
+                get_card_points(card, contract),
+                contract.is_trump_suit(&card.suit),
+                card.value.clone(),
+            )
+        })
+        .cloned()
+        .expect("a card should be available")
+}
+
+/// Where the server reaches the client of a seat, a seat loses it when the
+/// client goes away and the fallback bot plays for it from then on.
+struct RemoteClient {
+    outbox: Sender<ServerMessage>,
+    answers: Receiver<String>,
+    /// The bot plays for a client that ran out of time until it resumes,
+    /// the client still hears about the table.
+    away: bool,
+}
+
+/// How the client of a seat answered a request.
+enum Exchange {
+    Answer(Answer),
+    TimedOut,
+    Gone,
+}
+
+/// Passes messages on to a spectator once they are `delay` old.
+struct DelayedOutbox {
+    messages: Sender<(Instant, ServerMessage)>,
+    /// Messages on their way are dropped once the spectator stops watching.
+    is_stopped: Arc<AtomicBool>,
+}
+
+impl DelayedOutbox {
+    fn new(outbox: Sender<ServerMessage>, delay: Duration) -> Self {
+        let (messages, delayed_messages) = mpsc::channel::<(Instant, ServerMessage)>();
+        let is_stopped = Arc::new(AtomicBool::new(false));
+        let is_thread_stopped = is_stopped.clone();
+        thread::spawn(move || {
+            for (sent, message) in delayed_messages {
+                thread::sleep((sent + delay).saturating_duration_since(Instant::now()));
+                if is_thread_stopped.load(Ordering::Relaxed) || outbox.send(message).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            messages,
+            is_stopped,
+        }
+    }
+
+    fn send(&self, message: ServerMessage) -> bool {
+        !self.is_stopped.load(Ordering::Relaxed)
+            && self.messages.send((Instant::now(), message)).is_ok()
+    }
+
+    fn stop(&self) {
+        self.is_stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+struct Spectator {
+    client: usize,
+    visibility: Visibility,
+    outbox: DelayedOutbox,
+    /// Hands the spectator was sent last.
+    hands: Vec<HandMessage>,
+}
+
+/// Clients of the seats at a table and the spectators watching it.
+struct TableClients {
+    clients: Vec<RefCell<Option<RemoteClient>>>,
+    spectators: RefCell<Vec<Spectator>>,
+    updates: Receiver<MatchUpdate>,
+    names: Vec<String>,
+    teams: Vec<String>,
+    target_points: usize,
+    move_timeout: Option<MoveTimeout>,
+    spectator_delay: Duration,
+    /// Whether the views of the round show the trump.
+    is_trump_called: Cell<bool>,
+}
+
+impl TableClients {
+    fn send(&self, seat: usize, message: &ServerMessage) {
+        let is_sent = match self.clients[seat].borrow().as_ref() {
+            Some(remote_client) => remote_client.outbox.send(message.clone()).is_ok(),
+            None => return,
+        };
+        if !is_sent {
+            self.take_over(seat);
+        }
+    }
+
+    /// Sends the message to every seat and every spectator.
+    fn broadcast(&self, message: &ServerMessage) {
+        for seat in 0..self.clients.len() {
+            self.send(seat, message);
+        }
+        self.spectators
+            .borrow_mut()
+            .retain(|spectator| spectator.outbox.send(message.clone()));
+    }
+
+    fn broadcast_event(&self, event: EventMessage) {
+        self.broadcast(&ServerMessage::Event { event });
+    }
+
+    fn get_match_started(&self, seat: usize) -> ServerMessage {
+        ServerMessage::MatchStarted {
+            seat,
+            names: self.names.clone(),
+            teams: self.teams.clone(),
+            target_points: self.target_points,
+        }
+    }
+
+    /// The bot plays for a client that is gone.
+    fn take_over(&self, seat: usize) {
+        self.clients[seat].replace(None);
+        self.broadcast_event(EventMessage::BotTookOver { seat });
+    }
+
+    /// Seats the clients that came back and sends them the match and the
+    /// round as far as they may know it, spectators start or stop
+    /// watching. There is no round before the first one is dealt.
+    fn take_updates(&self, round_state: Option<&Round>) {
+        for update in self.updates.try_iter() {
+            match update {
+                MatchUpdate::Rejoin {
+                    seat,
+                    outbox,
+                    answers,
+                } => {
+                    self.clients[seat].replace(Some(RemoteClient {
+                        outbox,
+                        answers,
+                        away: false,
+                    }));
+                    self.send(seat, &self.get_match_started(seat));
+                    if let Some(round_state) = round_state {
+                        let view = ViewMessage::new(
+                            &round_state.get_player_view(Seat::new(seat)),
+                            self.is_trump_called.get(),
+                        );
+                        self.send(seat, &ServerMessage::View { view });
+                    }
+                    self.broadcast_event(EventMessage::PlayerReturned { seat });
+                }
+                MatchUpdate::Watch {
+                    client,
+                    outbox,
+                    visibility,
+                } => {
+                    self.stop_watching(client);
+                    let spectator = Spectator {
+                        client,
+                        visibility,
+                        outbox: DelayedOutbox::new(outbox, self.spectator_delay),
+                        hands: vec![],
+                    };
+                    let is_sent = spectator.outbox.send(ServerMessage::Watching {
+                        visibility: VisibilityMessage::new(visibility),
+                        names: self.names.clone(),
+                        teams: self.teams.clone(),
+                        target_points: self.target_points,
+                    });
+                    if is_sent {
+                        self.spectators.borrow_mut().push(spectator);
+                    }
+                }
+                MatchUpdate::StopWatching { client } => self.stop_watching(client),
+            }
+        }
+        if let Some(round_state) = round_state {
+            self.show_hands(round_state);
+        }
+    }
+
+    fn stop_watching(&self, client: usize) {
+        self.spectators.borrow_mut().retain(|spectator| {
+            let is_watching = spectator.client == client;
+            if is_watching {
+                spectator.outbox.stop();
+            }
+            !is_watching
+        });
+    }
+
+    /// Sends the spectators the hands they see when these changed.
+    fn show_hands(&self, round_state: &Round) {
+        self.spectators.borrow_mut().retain_mut(|spectator| {
+            let hands = HandMessage::get_hands(spectator.visibility, round_state);
+            if hands == spectator.hands {
+                return true;
+            }
+            spectator.hands = hands.clone();
+            spectator.outbox.send(ServerMessage::Hands { hands })
+        });
+    }
+
+    /// Commits to the secret of the next deal and collects the entropy the
+    /// clients give in time, seats of bots and clients that are away give
+    /// none.
+    fn commit_deal(&self, round: usize, commitment: String) -> Vec<Option<String>> {
+        for client in &self.clients {
+            if let Some(client) = client.borrow().as_ref() {
+                // answers nobody asked for aren't entropy
+                while client.answers.try_recv().is_ok() {}
+            }
+        }
+        self.broadcast(&ServerMessage::DealCommitted { round, commitment });
+
+        let deadline = Instant::now() + ENTROPY_TIMEOUT;
+        self.clients
+            .iter()
+            .map(|client| {
+                let client = client.borrow();
+                let client = client.as_ref().filter(|client| !client.away)?;
+                loop {
+                    let left = deadline.saturating_duration_since(Instant::now());
+                    let entropy = client.answers.recv_timeout(left).ok()?;
+                    if fair_deal::is_entropy_valid(&entropy) {
+                        return Some(entropy);
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Asks until the client gives one of the options or runs out of time.
+    fn exchange(&self, client: &RemoteClient, view: ViewMessage, request: &Request) -> Exchange {
+        // answers nobody asked for aren't answers to this request
+        while client.answers.try_recv().is_ok() {}
+        let ask = ServerMessage::ask(view.seat, request);
+        if client.outbox.send(ServerMessage::View { view }).is_err()
+            || client.outbox.send(ask).is_err()
+        {
+            return Exchange::Gone;
+        }
+        let deadline = self
+            .move_timeout
+            .map(|move_timeout| Instant::now() + move_timeout.limit);
+        loop {
+            let answer = match deadline {
+                Some(deadline) => {
+                    let left = deadline.saturating_duration_since(Instant::now());
+                    match client.answers.recv_timeout(left) {
+                        Ok(answer) => answer,
+                        Err(RecvTimeoutError::Timeout) => return Exchange::TimedOut,
+                        Err(RecvTimeoutError::Disconnected) => return Exchange::Gone,
+                    }
+                }
+                None => match client.answers.recv() {
+                    Ok(answer) => answer,
+                    Err(_) => return Exchange::Gone,
+                },
+            };
+            if let Some(answer) = request.parse_answer(&answer) {
+                return Exchange::Answer(answer);
+            }
+            let message = format!("`{}` isn't one of the options", answer);
+            if client
+                .outbox
+                .send(ServerMessage::Error { message })
+                .is_err()
+            {
+                return Exchange::Gone;
+            }
+        }
+    }
+}
+
+/// Seat played by a client over the network.
+#[derive(Clone)]
+struct RemoteSeat {
+    clients: Rc<TableClients>,
+}
+
+impl RequestAnswerer for RemoteSeat {
+    fn answer(&self, round_state: &Round, seat: Seat, request: Request) -> Option<Answer> {
+        self.clients.take_updates(Some(round_state));
+        let index = seat.index();
+        let exchange = {
+            let client = self.clients.clients[index].borrow();
+            let remote_client = client.as_ref().filter(|client| !client.away)?;
+            let is_trump_called = !matches!(
+                request,
+                Request::Trump
+                    | Request::ForcedTrump
+                    | Request::Contract
+                    | Request::ForcedContract
+                    | Request::Bid(_)
+            );
+            let view = ViewMessage::new(&round_state.get_player_view(seat), is_trump_called);
+            self.clients.exchange(remote_client, view, &request)
+        };
+
+        match exchange {
+            Exchange::Answer(answer) => Some(answer),
+            Exchange::Gone => {
+                self.clients.take_over(index);
+                None
+            }
+            Exchange::TimedOut => {
+                let fallback = self.clients.move_timeout.map(|timeout| timeout.fallback);
+                self.clients
+                    .broadcast_event(EventMessage::TimedOut { seat: index });
+                match (fallback, request) {
+                    (Some(TimeoutFallback::LowestCard), Request::Card(cards)) => Some(
+                        Answer::Card(get_lowest_card(&round_state.trump.kind, &cards)),
+                    ),
+                    (Some(TimeoutFallback::LowestCard), _) => None,
+                    _ => {
+                        if let Some(client) = self.clients.clients[index].borrow_mut().as_mut() {
+                            client.away = true;
+                        }
+                        self.clients
+                            .broadcast_event(EventMessage::BotTookOver { seat: index });
+                        None
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Tells every client and spectator about everything that happens at the
+/// table.
+struct TableObserver {
+    clients: Rc<TableClients>,
+}
+
+impl RoundObserver for TableObserver {
+    fn on_update(&self, round_state: &Round, round_event: RoundUpdateEvent) {
+        match round_event {
+            RoundUpdateEvent::TrumpCallEvent { trump: Some(_), .. } => {
+                self.clients.is_trump_called.set(true)
+            }
+            RoundUpdateEvent::Redealt => self.clients.is_trump_called.set(false),
+            _ => {}
+        }
+        for event in EventMessage::from_round_event(&round_event) {
+            self.clients.broadcast_event(event);
+        }
+        self.clients.take_updates(Some(round_state));
+    }
+}
+
+/// Plays the match a room started with the clients and bots in its seats.
+fn play_match(start: MatchStart) {
+    let mut bots = vec![];
+    let mut clients = vec![];
+    for seat in start.seats {
+        match seat {
+            MatchSeat::Bot(bot) => {
+                bots.push(Some(bot.get_factory()));
+                clients.push(RefCell::new(None));
+            }
+            MatchSeat::Client { outbox, answers } => {
+                bots.push(None);
+                clients.push(RefCell::new(Some(RemoteClient {
+                    outbox,
+                    answers,
+                    away: false,
+                })));
+            }
+        }
+    }
+
+    let variant = start.rules.variant;
+    let table_config = TableConfig::new(start.names.clone(), start.rules).with_seed(start.seed);
+    let teams = table_config
+        .seats
+        .iter()
+        .map(|seat_config| get_team_name(seat_config.team))
+        .collect();
+    let clients = Rc::new(TableClients {
+        clients,
+        spectators: RefCell::new(vec![]),
+        updates: start.updates,
+        names: start.names,
+        teams,
+        target_points: start.target_points,
+        move_timeout: start.move_timeout,
+        spectator_delay: start.spectator_delay,
+        is_trump_called: Cell::new(false),
+    });
+    let mut game_match = Match::new(table_config, start.target_points);
+    for seat in 0..bots.len() {
+        clients.send(seat, &clients.get_match_started(seat));
+    }
+    clients.take_updates(None);
+
+    let observer = TableObserver {
+        clients: clients.clone(),
+    };
+    while !game_match.is_done() {
+        let players = bots
+            .iter()
+            .map(|bot| match bot {
+                Some(factory) => factory(),
+                None => Box::new(ProtocolRoundPlayer::new(RemoteSeat {
+                    clients: clients.clone(),
+                })) as _,
+            })
+            .collect();
+        clients.is_trump_called.set(false);
+        let round_player = Box::new(SeatedRoundPlayer::new(players));
+        let round = if start.fair_dealing {
+            let round_number = game_match.round_results.len() + 1;
+            let secret = fair_deal::get_secret();
+            let commitment = fair_deal::get_commitment(&secret);
+            let entropies = clients.commit_deal(round_number, commitment);
+            let seed = fair_deal::get_seed(&secret, &entropies);
+            let round = game_match.play_round_with_deal(
+                &fair_deal::get_deal(&seed, variant),
+                fair_deal::get_table_seed(&seed),
+                round_player,
+                &observer,
+            );
+            clients.broadcast(&ServerMessage::DealRevealed {
+                round: round_number,
+                secret,
+                entropies,
+            });
+            round
+        } else {
+            game_match.play_round(round_player, &observer)
+        };
+
+        let scores = game_match
+            .get_teams()
+            .into_iter()
+            .map(|team| ScoreMessage {
+                team: get_team_name(team),
+                points: round.get_result().points.get_points(team),
+                total: game_match.points.get_points(team),
+            })
+            .collect();
+        clients.broadcast(&ServerMessage::RoundOver {
+            round: game_match.round_results.len(),
+            scores,
+        });
+    }
+
+    let winner = game_match
+        .get_winner()
+        .expect("finished match should have a winner");
+    clients.broadcast(&ServerMessage::MatchOver {
+        winner: get_team_name(winner),
+    });
+}
+
+/// Keeps the lobby, clients of every listener meet in it.
+#[derive(Clone)]
+pub struct Server {
+    lobby: Arc<Mutex<Lobby>>,
+}
+
+impl Server {
+    pub fn new(config: ServerConfig) -> Self {
+        assert_eq!(
+            config.bots.len(),
+            config.rules.variant.get_number_of_players(),
+            "every seat should be in the bots"
+        );
+        assert!(
+            config.bots.iter().any(Option::is_none),
+            "a quick match needs a seat for clients"
+        );
+
+        Self {
+            lobby: Arc::new(Mutex::new(Lobby::new(config))),
+        }
+    }
+
+    fn lock_lobby(&self) -> MutexGuard<'_, Lobby> {
+        self.lobby.lock().expect("lobby lock")
+    }
+
+    /// Plays the match on a thread of its own and opens the room again
+    /// once it is over.
+    fn start_match(&self, start: MatchStart) {
+        let server = self.clone();
+        thread::spawn(move || {
+            let room = start.room;
+            play_match(start);
+            server.lock_lobby().finish_match(room);
+        });
+    }
+
+    /// Passes messages between the client and the lobby until the client
+    /// goes away.
+    fn relay(
+        &self,
+        connection: &mut Connection,
+        client: usize,
+        messages: &Receiver<ServerMessage>,
+    ) -> io::Result<()> {
+        connection.set_read_timeout(Some(POLL_INTERVAL))?;
+        loop {
+            for message in messages.try_iter() {
+                connection.send(&message)?;
+            }
+            let message = match connection.receive::<ClientMessage>() {
+                Ok(Some(message)) => message,
+                Ok(None) => return Ok(()),
+                Err(error) if is_timeout(&error) => continue,
+                Err(error) if error.kind() == ErrorKind::InvalidData => {
+                    let message = format!("invalid message: {}", error);
+                    connection.send(&ServerMessage::Error { message })?;
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
+            let start = self.lock_lobby().handle(client, message);
+            if let Some(start) = start {
+                self.start_match(start);
+            }
+        }
+    }
+
+    /// Waits for the hello of a client and lets it in the lobby, or back
+    /// to its seat when it brings a token.
+    fn serve_client(&self, mut connection: Connection) -> io::Result<()> {
+        let (client, messages) = loop {
+            let message = match connection.receive::<ClientMessage>() {
+                Ok(Some(ClientMessage::Hello { name, token })) => {
+                    let (outbox, messages) = mpsc::channel();
+                    let mut lobby = self.lock_lobby();
+                    let client = match token {
+                        Some(token) => lobby.reconnect(&token, outbox),
+                        None => Ok(lobby.connect(name, outbox)),
+                    };
+                    match client {
+                        Ok(client) => break (client, messages),
+                        Err(message) => message,
+                    }
+                }
+                Ok(Some(_)) => "say hello first".to_string(),
+                Ok(None) => return Ok(()),
+                Err(error) if error.kind() == ErrorKind::InvalidData => {
+                    format!("invalid message: {}", error)
+                }
+                Err(error) => return Err(error),
+            };
+            connection.send(&ServerMessage::Error { message })?;
+        };
+
+        let result = self.relay(&mut connection, client, &messages);
+        self.lock_lobby().disconnect(client);
+
+        result
+    }
+
+    /// Takes clients that send JSON lines, a client that fails to connect
+    /// is reported and skipped.
+    pub fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            let Some(stream) = accept(stream) else {
+                continue;
+            };
+            let server = self.clone();
+            thread::spawn(move || server.serve_client(Connection::new(stream)?));
+        }
+
+        Ok(())
+    }
+
+    /// Serves the browser client and takes the browsers that connect with a
+    /// WebSocket, a browser that fails to connect is reported and skipped.
+    pub fn serve_web(&self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            let Some(stream) = accept(stream) else {
+                continue;
+            };
+            let server = self.clone();
+            thread::spawn(move || match web::accept(stream)? {
+                Some(connection) => server.serve_client(connection),
+                None => Ok(()),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Stream of an accepted connection, a failed accept such as an aborted
+/// connection or too many open files only ends that connection.
+fn accept(stream: io::Result<TcpStream>) -> Option<TcpStream> {
+    match stream {
+        Ok(stream) => Some(stream),
+        Err(error) => {
+            eprintln!("Failed to accept a connection: {}", error);
+            thread::sleep(ACCEPT_RETRY_DELAY);
+            None
+        }
+    }
+}
+
+/// Takes clients that send JSON lines, see [`Server`] to take clients from
+/// more listeners.
+pub fn serve(listener: TcpListener, config: ServerConfig) -> io::Result<()> {
+    Server::new(config).serve(listener)
+}
@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::deck::Deck;
+    use crate::game::rules::GameVariant;
+    use crate::net::fair_deal::{
+        get_commitment, get_deal, get_secret, get_seed, is_entropy_valid, verify_deal,
+    };
+
+    fn get_entropies() -> Vec<Option<String>> {
+        vec![
+            Some("c0ffee".to_string()),
+            None,
+            Some("42".to_string()),
+            None,
+        ]
+    }
+
+    #[test]
+    fn test_deal_uses_every_card_once() {
+        let seed = get_seed(&get_secret(), &get_entropies());
+        for (variant, hand_size, talon_size) in [
+            (GameVariant::FourPlayers, 8, 0),
+            (GameVariant::ThreePlayers, 10, 2),
+        ] {
+            let deal = get_deal(&seed, variant);
+            assert!(deal.hands.iter().all(|hand| hand.len() == hand_size));
+            assert_eq!(deal.talon.len(), talon_size);
+
+            let mut cards = deal.hands.concat();
+            cards.extend(deal.talon);
+            let mut deck = Deck::new().deck;
+            for cards in [&mut cards, &mut deck] {
+                cards.sort_by_key(|card| (card.suit.clone(), card.value.clone()));
+            }
+            assert_eq!(cards, deck);
+        }
+    }
+
+    #[test]
+    fn test_every_entropy_changes_the_deal() {
+        let secret = get_secret();
+        let seed = get_seed(&secret, &get_entropies());
+        assert_eq!(seed, get_seed(&secret, &get_entropies()));
+        let deal = get_deal(&seed, GameVariant::FourPlayers);
+        assert_eq!(deal.hands, get_deal(&seed, GameVariant::FourPlayers).hands);
+
+        let mut entropies = get_entropies();
+        entropies[3] = Some("1".to_string());
+        assert_ne!(seed, get_seed(&secret, &entropies));
+        // entropy can't move to another seat unnoticed
+        entropies = vec![
+            None,
+            Some("c0ffee".to_string()),
+            Some("42".to_string()),
+            None,
+        ];
+        assert_ne!(seed, get_seed(&secret, &entropies));
+    }
+
+    #[test]
+    fn test_dealt_hands_are_verified_against_the_commitment() {
+        let secret = get_secret();
+        let commitment = get_commitment(&secret);
+        let deal = get_deal(
+            &get_seed(&secret, &get_entropies()),
+            GameVariant::FourPlayers,
+        );
+        let mut hand = deal.hands[2].clone();
+        hand.reverse();
+        let hands = [(2, hand)];
+        let variant = GameVariant::FourPlayers;
+        assert_eq!(
+            verify_deal(&commitment, &secret, &get_entropies(), variant, &[], &hands),
+            Ok(())
+        );
+
+        let other_secret = get_secret();
+        assert_eq!(
+            verify_deal(
+                &commitment,
+                &other_secret,
+                &get_entropies(),
+                variant,
+                &[],
+                &hands
+            ),
+            Err("the secret doesn't match the commitment".to_string())
+        );
+        assert_eq!(
+            verify_deal(&commitment, &secret, &vec![None; 4], variant, &[], &hands),
+            Err("seat 2 wasn't dealt the committed cards".to_string())
+        );
+    }
+
+    #[test]
+    fn test_entropy_the_server_left_out_fails_the_deal() {
+        let secret = get_secret();
+        let commitment = get_commitment(&secret);
+        let variant = GameVariant::FourPlayers;
+        let sent = [(2, "42".to_string())];
+        assert_eq!(
+            verify_deal(&commitment, &secret, &get_entropies(), variant, &sent, &[]),
+            Ok(())
+        );
+
+        // left out, the server could deal as if the seat gave none
+        let mut entropies = get_entropies();
+        entropies[2] = None;
+        assert_eq!(
+            verify_deal(&commitment, &secret, &entropies, variant, &sent, &[]),
+            Err("the entropy of seat 2 was left out".to_string())
+        );
+        // swapped for entropy of the server's choosing
+        entropies[2] = Some("43".to_string());
+        assert_eq!(
+            verify_deal(&commitment, &secret, &entropies, variant, &sent, &[]),
+            Err("the entropy of seat 2 was left out".to_string())
+        );
+        assert_eq!(
+            verify_deal(&commitment, &secret, &[], variant, &sent, &[]),
+            Err("the entropy of seat 2 was left out".to_string())
+        );
+    }
+
+    #[test]
+    fn test_entropy_is_hexadecimal() {
+        assert!(is_entropy_valid("c0FFee"));
+        assert!(!is_entropy_valid(""));
+        assert!(!is_entropy_valid("pass"));
+        assert!(!is_entropy_valid("a\nb"));
+        assert!(!is_entropy_valid(&"a".repeat(65)));
+    }
+}
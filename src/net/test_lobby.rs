@@ -0,0 +1,581 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::mpsc::{self, Receiver},
+        time::Duration,
+    };
+
+    use crate::game::rules::RuleSet;
+    use crate::game::visibility::Visibility;
+    use crate::net::{
+        lobby::{BotKind, Lobby, MatchSeat, MatchStart, MatchUpdate},
+        protocol::{ClientMessage, RoomMessage, RulesMessage, ServerMessage, VisibilityMessage},
+        server::ServerConfig,
+    };
+
+    // client that lives in the test instead of behind a connection
+    struct LocalClient {
+        id: usize,
+        messages: Receiver<ServerMessage>,
+    }
+
+    impl LocalClient {
+        fn connect(lobby: &mut Lobby, name: &str) -> Self {
+            let (outbox, messages) = mpsc::channel();
+            let id = lobby.connect(name.to_string(), outbox);
+
+            Self { id, messages }
+        }
+
+        fn take_messages(&self) -> Vec<ServerMessage> {
+            self.messages.try_iter().collect()
+        }
+
+        fn get_last_room(&self) -> Option<RoomMessage> {
+            self.take_messages()
+                .into_iter()
+                .filter_map(|message| match message {
+                    ServerMessage::Room { room } => Some(room),
+                    _ => None,
+                })
+                .next_back()
+        }
+
+        fn get_last_error(&self) -> Option<String> {
+            self.take_messages()
+                .into_iter()
+                .filter_map(|message| match message {
+                    ServerMessage::Error { message } => Some(message),
+                    _ => None,
+                })
+                .next_back()
+        }
+    }
+
+    fn get_lobby() -> Lobby {
+        Lobby::new(ServerConfig {
+            rules: RuleSet::default(),
+            target_points: 1001,
+            seed: Some(3),
+            bots: vec![None, Some(BotKind::Heuristic), None, Some(BotKind::Random)],
+            move_timeout: None,
+            spectator_delay: Duration::ZERO,
+            fair_dealing: false,
+        })
+    }
+
+    fn create_room(lobby: &mut Lobby, client: &LocalClient) -> usize {
+        let message = ClientMessage::CreateRoom {
+            name: Some("Office".to_string()),
+            rules: None,
+        };
+        assert!(lobby.handle(client.id, message).is_none());
+
+        client
+            .get_last_room()
+            .expect("creator should be in the room")
+            .room
+    }
+
+    fn join(lobby: &mut Lobby, client: &LocalClient, room: Option<usize>) -> Option<MatchStart> {
+        let message = ClientMessage::JoinRoom {
+            room,
+            seat: None,
+            team: None,
+        };
+        lobby.handle(client.id, message)
+    }
+
+    fn set_bot(lobby: &mut Lobby, client: &LocalClient, seat: usize, bot: Option<BotKind>) {
+        assert!(
+            lobby
+                .handle(client.id, ClientMessage::SetBot { seat, bot })
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_clients_are_welcomed_with_the_rooms() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        create_room(&mut lobby, &ana);
+
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let messages = ivo.take_messages();
+        assert!(matches!(
+            &messages[0],
+            ServerMessage::Welcome { client, bots, .. } if *client == ivo.id && bots.len() == 3
+        ));
+        let ServerMessage::Rooms { rooms } = &messages[1] else {
+            panic!("rooms should follow the welcome");
+        };
+        assert_eq!(rooms.len(), 1);
+        assert_eq!(rooms[0].name, "Office");
+        assert_eq!(rooms[0].seats[0].name.as_deref(), Some("Ana"));
+        assert_eq!(rooms[0].seats[0].client, Some(ana.id));
+    }
+
+    #[test]
+    fn test_clients_choose_seats_and_teams() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let room = create_room(&mut lobby, &ana);
+
+        let message = ClientMessage::JoinRoom {
+            room: Some(room),
+            seat: None,
+            team: Some("B".to_string()),
+        };
+        lobby.handle(ivo.id, message);
+        let seats = ivo.get_last_room().unwrap().seats;
+        assert_eq!(seats[1].client, Some(ivo.id));
+        assert_eq!(seats[1].team, "B");
+
+        // a taken seat can't be chosen, a free one can
+        lobby.handle(
+            ivo.id,
+            ClientMessage::ChooseSeat {
+                seat: Some(0),
+                team: None,
+            },
+        );
+        assert_eq!(ivo.get_last_error().unwrap(), "seat 0 is taken");
+        lobby.handle(
+            ivo.id,
+            ClientMessage::ChooseSeat {
+                seat: Some(2),
+                team: None,
+            },
+        );
+        let seats = ana.get_last_room().unwrap().seats;
+        assert_eq!(seats[1].client, None);
+        assert_eq!(seats[2].client, Some(ivo.id));
+        assert_eq!(seats[2].team, "A");
+
+        lobby.handle(
+            ivo.id,
+            ClientMessage::ChooseSeat {
+                seat: None,
+                team: Some("C".to_string()),
+            },
+        );
+        assert_eq!(ivo.get_last_error().unwrap(), "team C has no free seat");
+    }
+
+    #[test]
+    fn test_match_starts_once_every_seat_is_taken() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let room = create_room(&mut lobby, &ana);
+        join(&mut lobby, &ivo, Some(room));
+
+        assert!(lobby.handle(ana.id, ClientMessage::Start).is_none());
+        assert_eq!(
+            ana.get_last_error().unwrap(),
+            "every seat needs a player or a bot"
+        );
+        set_bot(&mut lobby, &ana, 2, Some(BotKind::Search));
+        set_bot(&mut lobby, &ivo, 3, Some(BotKind::Random));
+        lobby.handle(
+            ana.id,
+            ClientMessage::SetBot {
+                seat: 1,
+                bot: Some(BotKind::Random),
+            },
+        );
+        assert_eq!(ana.get_last_error().unwrap(), "seat 1 is taken");
+
+        let start = lobby.handle(ivo.id, ClientMessage::Start).unwrap();
+        assert_eq!(start.room, room);
+        assert_eq!(start.names, ["Ana", "Ivo", "Bot 3", "Bot 4"]);
+        assert_eq!(start.seed, Some(3));
+        assert!(matches!(start.seats[0], MatchSeat::Client { .. }));
+        assert!(matches!(start.seats[2], MatchSeat::Bot(BotKind::Search)));
+        assert!(ana.get_last_room().unwrap().playing);
+
+        // answers go to the match, the room can't change while it is played
+        lobby.handle(
+            ana.id,
+            ClientMessage::Answer {
+                answer: "pass".to_string(),
+            },
+        );
+        let MatchSeat::Client { answers, .. } = &start.seats[0] else {
+            unreachable!();
+        };
+        assert_eq!(answers.try_recv().unwrap(), "pass");
+        lobby.handle(ana.id, ClientMessage::LeaveRoom);
+        assert_eq!(ana.get_last_error().unwrap(), "the room is playing a match");
+    }
+
+    #[test]
+    fn test_room_plays_rematches_with_new_deals() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        create_room(&mut lobby, &ana);
+        for seat in 1..4 {
+            set_bot(&mut lobby, &ana, seat, Some(BotKind::Heuristic));
+        }
+
+        let start = lobby.handle(ana.id, ClientMessage::Start).unwrap();
+        lobby.finish_match(start.room);
+        let room = ana.get_last_room().unwrap();
+        assert!(!room.playing);
+        assert_eq!(room.matches_played, 1);
+
+        let rematch = lobby.handle(ana.id, ClientMessage::Start).unwrap();
+        assert_eq!(rematch.room, start.room);
+        assert_ne!(rematch.seed, start.seed);
+    }
+
+    #[test]
+    fn test_quick_match_starts_when_its_seats_are_taken() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let maja = LocalClient::connect(&mut lobby, "Maja");
+
+        assert!(join(&mut lobby, &ana, None).is_none());
+        let room = ana.get_last_room().unwrap();
+        assert_eq!(room.seats[1].bot, Some(BotKind::Heuristic));
+        assert_eq!(room.seats[3].bot, Some(BotKind::Random));
+
+        let start = join(&mut lobby, &ivo, None).unwrap();
+        assert_eq!(start.room, room.room);
+        assert_eq!(start.names, ["Ana", "Bot 2", "Ivo", "Bot 4"]);
+
+        // the next client waits for a quick match of its own
+        assert!(join(&mut lobby, &maja, None).is_none());
+        assert_ne!(maja.get_last_room().unwrap().room, room.room);
+    }
+
+    #[test]
+    fn test_rules_change_the_seats() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let room = create_room(&mut lobby, &ana);
+        set_bot(&mut lobby, &ana, 1, Some(BotKind::Heuristic));
+        let three_players = RulesMessage {
+            three_players: true,
+            kontra: true,
+            target_points: 501,
+            ..RulesMessage::default()
+        };
+
+        let message = ClientMessage::JoinRoom {
+            room: Some(room),
+            seat: Some(3),
+            team: None,
+        };
+        lobby.handle(ivo.id, message);
+        let message = ClientMessage::SetRules {
+            rules: three_players.clone(),
+        };
+        lobby.handle(ana.id, message.clone());
+        assert_eq!(
+            ana.get_last_error().unwrap(),
+            "a client sits in a seat the rules don't have"
+        );
+
+        lobby.handle(
+            ivo.id,
+            ClientMessage::ChooseSeat {
+                seat: Some(2),
+                team: None,
+            },
+        );
+        lobby.handle(ana.id, message);
+        let room = ana.get_last_room().unwrap();
+        assert_eq!(room.rules, three_players);
+        assert_eq!(room.seats.len(), 3);
+        assert_eq!(room.seats[1].bot, Some(BotKind::Heuristic));
+        assert_eq!(room.seats[2].team, "C");
+    }
+
+    #[test]
+    fn test_rules_add_seats() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        create_room(&mut lobby, &ana);
+        let three_players = RulesMessage {
+            three_players: true,
+            ..RulesMessage::default()
+        };
+        lobby.handle(
+            ana.id,
+            ClientMessage::SetRules {
+                rules: three_players,
+            },
+        );
+        set_bot(&mut lobby, &ana, 2, Some(BotKind::Random));
+
+        lobby.handle(
+            ana.id,
+            ClientMessage::SetRules {
+                rules: RulesMessage::default(),
+            },
+        );
+        let room = ana.get_last_room().expect("rules with more seats are set");
+        assert_eq!(room.seats.len(), 4);
+        assert_eq!(room.seats[0].client, Some(ana.id));
+        assert_eq!(room.seats[2].bot, Some(BotKind::Random));
+        assert_eq!(room.seats[3].name, None);
+        assert_eq!(room.seats[3].team, "B");
+    }
+
+    #[test]
+    fn test_empty_rooms_are_closed() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let room = create_room(&mut lobby, &ana);
+        join(&mut lobby, &ivo, Some(room));
+
+        lobby.disconnect(ivo.id);
+        let seats = ana.get_last_room().unwrap().seats;
+        assert_eq!(seats[1].client, None);
+
+        lobby.handle(ana.id, ClientMessage::LeaveRoom);
+        let rooms = ana
+            .take_messages()
+            .into_iter()
+            .filter_map(|message| match message {
+                ServerMessage::Rooms { rooms } => Some(rooms),
+                _ => None,
+            })
+            .next_back()
+            .unwrap();
+        assert!(rooms.is_empty());
+        assert!(join(&mut lobby, &ana, Some(room)).is_none());
+        assert_eq!(
+            ana.get_last_error().unwrap(),
+            format!("there is no room {}", room)
+        );
+    }
+
+    fn get_token(messages: &[ServerMessage]) -> String {
+        messages
+            .iter()
+            .find_map(|message| match message {
+                ServerMessage::Welcome { token, .. } => Some(token.clone()),
+                _ => None,
+            })
+            .expect("client should be welcomed")
+    }
+
+    // Ana and Ivo play against two bots
+    fn start_match(lobby: &mut Lobby, ana: &LocalClient, ivo: &LocalClient) -> MatchStart {
+        let room = create_room(lobby, ana);
+        join(lobby, ivo, Some(room));
+        set_bot(lobby, ana, 2, Some(BotKind::Random));
+        set_bot(lobby, ana, 3, Some(BotKind::Random));
+
+        lobby.handle(ana.id, ClientMessage::Start).unwrap()
+    }
+
+    #[test]
+    fn test_clients_come_back_to_their_seats() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let ana_token = get_token(&ana.take_messages());
+        let ivo_token = get_token(&ivo.take_messages());
+        let start = start_match(&mut lobby, &ana, &ivo);
+
+        lobby.disconnect(ivo.id);
+        let seats = ana.get_last_room().unwrap().seats;
+        assert_eq!(seats[1].client, Some(ivo.id));
+
+        let (outbox, _) = mpsc::channel();
+        let error = lobby.reconnect("unknown", outbox.clone()).unwrap_err();
+        assert_eq!(error, "the token belongs to no client");
+        let error = lobby.reconnect(&ana_token, outbox).unwrap_err();
+        assert_eq!(error, format!("client {} is still connected", ana.id));
+
+        let (outbox, messages) = mpsc::channel();
+        let ivo = LocalClient {
+            id: lobby.reconnect(&ivo_token, outbox).unwrap(),
+            messages,
+        };
+        assert_eq!(get_token(&ivo.take_messages()), ivo_token);
+
+        // the match gets the client back and its answers from then on
+        let Ok(MatchUpdate::Rejoin { seat, answers, .. }) = start.updates.try_recv() else {
+            panic!("the client should rejoin");
+        };
+        assert_eq!(seat, 1);
+        lobby.handle(
+            ivo.id,
+            ClientMessage::Answer {
+                answer: "pass".to_string(),
+            },
+        );
+        assert_eq!(answers.try_recv().unwrap(), "pass");
+    }
+
+    #[test]
+    fn test_clients_resume_only_during_a_match() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let start = start_match(&mut lobby, &ana, &ivo);
+
+        lobby.handle(ana.id, ClientMessage::Resume);
+        let update = start.updates.try_recv();
+        assert!(matches!(update, Ok(MatchUpdate::Rejoin { seat: 0, .. })));
+
+        lobby.finish_match(start.room);
+        lobby.handle(ana.id, ClientMessage::Resume);
+        assert_eq!(
+            ana.get_last_error().unwrap(),
+            "the room isn't playing a match"
+        );
+    }
+
+    #[test]
+    fn test_clients_without_a_connection_leave_after_the_match() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let ivo_token = get_token(&ivo.take_messages());
+        let start = start_match(&mut lobby, &ana, &ivo);
+
+        lobby.disconnect(ivo.id);
+        lobby.finish_match(start.room);
+        let seats = ana.get_last_room().unwrap().seats;
+        assert_eq!(seats[1].client, None);
+
+        let (outbox, _) = mpsc::channel();
+        assert!(lobby.reconnect(&ivo_token, outbox).is_err());
+    }
+
+    fn spectate(
+        lobby: &mut Lobby,
+        client: &LocalClient,
+        room: usize,
+        visibility: VisibilityMessage,
+    ) {
+        assert!(
+            lobby
+                .handle(client.id, ClientMessage::Spectate { room, visibility })
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_spectators_watch_rooms_until_they_take_a_seat() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let room = create_room(&mut lobby, &ana);
+
+        let visibility = VisibilityMessage::Seat { seat: 7 };
+        lobby.handle(ivo.id, ClientMessage::Spectate { room, visibility });
+        assert_eq!(ivo.get_last_error().unwrap(), "the room has no seat 7");
+
+        spectate(&mut lobby, &ivo, room, VisibilityMessage::Seat { seat: 0 });
+        let spectators = ana.get_last_room().unwrap().spectators;
+        assert_eq!(spectators.len(), 1);
+        assert_eq!(spectators[0].name, "Ivo");
+        assert_eq!(
+            spectators[0].visibility,
+            VisibilityMessage::Seat { seat: 0 }
+        );
+        assert!(join(&mut lobby, &ivo, None).is_none());
+        assert_eq!(
+            ivo.get_last_error().unwrap(),
+            format!("leave room {} first", room)
+        );
+
+        let message = ClientMessage::ChooseSeat {
+            seat: Some(2),
+            team: None,
+        };
+        assert!(lobby.handle(ivo.id, message).is_none());
+        let room_message = ana.get_last_room().unwrap();
+        assert!(room_message.spectators.is_empty());
+        assert_eq!(room_message.seats[2].client, Some(ivo.id));
+
+        // a client of the room gives its seat up to watch
+        spectate(&mut lobby, &ana, room, VisibilityMessage::Open);
+        let room_message = ivo.get_last_room().unwrap();
+        assert_eq!(room_message.seats[0].client, None);
+        assert_eq!(room_message.spectators[0].client, ana.id);
+    }
+
+    #[test]
+    fn test_bots_play_for_a_room_of_spectators() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let room = create_room(&mut lobby, &ana);
+        spectate(&mut lobby, &ana, room, VisibilityMessage::Open);
+        for seat in 0..4 {
+            set_bot(&mut lobby, &ana, seat, Some(BotKind::Random));
+        }
+
+        let start = lobby.handle(ana.id, ClientMessage::Start).unwrap();
+        assert!(
+            start
+                .seats
+                .iter()
+                .all(|seat| matches!(seat, MatchSeat::Bot(BotKind::Random)))
+        );
+        let update = start.updates.try_recv();
+        assert!(matches!(
+            update,
+            Ok(MatchUpdate::Watch { client, visibility: Visibility::Open, .. }) if client == ana.id
+        ));
+
+        // the room isn't closed while its spectator is gone
+        lobby.handle(ana.id, ClientMessage::LeaveRoom);
+        assert!(matches!(
+            start.updates.try_recv(),
+            Ok(MatchUpdate::StopWatching { client }) if client == ana.id
+        ));
+        lobby.finish_match(room);
+        let ServerMessage::Rooms { rooms } = ana.take_messages().pop().unwrap() else {
+            panic!("a client in no room should get the rooms");
+        };
+        assert!(rooms.is_empty());
+    }
+
+    #[test]
+    fn test_spectators_join_running_matches() {
+        let mut lobby = get_lobby();
+        let ana = LocalClient::connect(&mut lobby, "Ana");
+        let ivo = LocalClient::connect(&mut lobby, "Ivo");
+        let maja = LocalClient::connect(&mut lobby, "Maja");
+        let start = start_match(&mut lobby, &ana, &ivo);
+
+        spectate(&mut lobby, &maja, start.room, VisibilityMessage::default());
+        assert!(matches!(
+            start.updates.try_recv(),
+            Ok(MatchUpdate::Watch { client, visibility: Visibility::Public, .. }) if client == maja.id
+        ));
+        lobby.handle(maja.id, ClientMessage::Resume);
+        assert_eq!(
+            maja.get_last_error().unwrap(),
+            "spectators have no seat to take back"
+        );
+
+        // clients of the match can't leave their seats to watch it
+        lobby.handle(
+            ana.id,
+            ClientMessage::Spectate {
+                room: start.room,
+                visibility: VisibilityMessage::Open,
+            },
+        );
+        assert_eq!(ana.get_last_error().unwrap(), "the room is playing a match");
+
+        lobby.disconnect(maja.id);
+        assert!(matches!(
+            start.updates.try_recv(),
+            Ok(MatchUpdate::StopWatching { client }) if client == maja.id
+        ));
+        assert!(ana.get_last_room().unwrap().spectators.is_empty());
+    }
+}
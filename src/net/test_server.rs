@@ -0,0 +1,664 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::mpsc::{self, Sender},
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
+    };
+
+    use crate::game::deck::Card;
+    use crate::game::rules::{GameVariant, RuleSet};
+    use crate::net::{
+        Connection,
+        fair_deal::verify_deal,
+        lobby::BotKind,
+        protocol::{
+            ClientMessage, EventMessage, HandMessage, RulesMessage, ServerMessage,
+            VisibilityMessage,
+        },
+        server::{MoveTimeout, Server, ServerConfig, TimeoutFallback, serve},
+    };
+
+    fn get_quick_match_config() -> ServerConfig {
+        ServerConfig {
+            rules: RuleSet::default(),
+            target_points: 1,
+            seed: Some(1),
+            bots: vec![
+                None,
+                Some(BotKind::Heuristic),
+                None,
+                Some(BotKind::Heuristic),
+            ],
+            move_timeout: None,
+            spectator_delay: Duration::ZERO,
+            fair_dealing: false,
+        }
+    }
+
+    fn start_server(config: ServerConfig) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        thread::spawn(move || serve(listener, config));
+
+        address
+    }
+
+    fn connect(address: &str, name: &str) -> Connection {
+        let mut connection = Connection::new(TcpStream::connect(address).unwrap()).unwrap();
+        connection
+            .send(&ClientMessage::Hello {
+                name: name.to_string(),
+                token: None,
+            })
+            .unwrap();
+
+        connection
+    }
+
+    // plays the first option of every request until the match is over,
+    // the first answer of every client is one it wasn't offered
+    fn play_match(connection: &mut Connection) -> Vec<ServerMessage> {
+        let mut messages = vec![];
+        let mut options = vec![];
+        while let Some(message) = connection.receive::<ServerMessage>().unwrap() {
+            messages.push(message.clone());
+            let answer = match message {
+                ServerMessage::Ask {
+                    options: asked_options,
+                    ..
+                } if options.is_empty() => {
+                    options = asked_options;
+                    "Leaf-Kec-Herz".to_string()
+                }
+                ServerMessage::Ask {
+                    options: asked_options,
+                    ..
+                } => asked_options[0].clone(),
+                ServerMessage::Error { .. } => options[0].clone(),
+                ServerMessage::DealCommitted { .. } => {
+                    let entropy = "c0ffee".to_string();
+                    connection
+                        .send(&ClientMessage::Entropy { entropy })
+                        .unwrap();
+                    continue;
+                }
+                ServerMessage::MatchOver { .. } => break,
+                _ => continue,
+            };
+            connection.send(&ClientMessage::Answer { answer }).unwrap();
+        }
+
+        messages
+    }
+
+    // joins a quick match and plays it
+    fn play_quick_match(mut connection: Connection) -> Vec<ServerMessage> {
+        connection
+            .send(&ClientMessage::JoinRoom {
+                room: None,
+                seat: None,
+                team: None,
+            })
+            .unwrap();
+
+        play_match(&mut connection)
+    }
+
+    // opens a room, puts bots in the other seats and starts its match
+    fn start_room_match(connection: &mut Connection) {
+        let rules = RulesMessage {
+            target_points: 1,
+            ..RulesMessage::default()
+        };
+        let mut messages = vec![ClientMessage::CreateRoom {
+            name: Some("Office".to_string()),
+            rules: Some(rules),
+        }];
+        messages.extend((1..4).map(|seat| ClientMessage::SetBot {
+            seat,
+            bot: Some(BotKind::Random),
+        }));
+        messages.push(ClientMessage::Start);
+        for message in messages {
+            connection.send(&message).unwrap();
+        }
+    }
+
+    fn receive_until(
+        connection: &mut Connection,
+        is_last: impl Fn(&ServerMessage) -> bool,
+    ) -> Vec<ServerMessage> {
+        let mut messages = vec![];
+        while let Some(message) = connection.receive::<ServerMessage>().unwrap() {
+            let is_done = is_last(&message);
+            messages.push(message);
+            if is_done {
+                break;
+            }
+        }
+
+        messages
+    }
+
+    fn count_events(messages: &[ServerMessage], event: EventMessage) -> usize {
+        let event = ServerMessage::Event { event };
+        messages.iter().filter(|message| **message == event).count()
+    }
+
+    fn get_match_seat(messages: &[ServerMessage]) -> (usize, Vec<String>) {
+        messages
+            .iter()
+            .find_map(|message| match message {
+                ServerMessage::MatchStarted { seat, names, .. } => Some((*seat, names.clone())),
+                _ => None,
+            })
+            .expect("match should start once the seats are taken")
+    }
+
+    #[test]
+    fn test_clients_play_a_quick_match_with_bots() {
+        let address = start_server(get_quick_match_config());
+        let clients = ["Ana", "Ivo"].map(|name| {
+            let address = address.clone();
+            thread::spawn(move || play_quick_match(connect(&address, name)))
+        });
+        let messages = clients.map(|client| client.join().unwrap());
+
+        let mut seats = vec![];
+        for (messages, name) in messages.iter().zip(["Ana", "Ivo"]) {
+            assert!(matches!(messages[0], ServerMessage::Welcome { .. }));
+            let (seat, names) = get_match_seat(messages);
+            seats.push(seat);
+            assert_eq!(names[seat], name);
+            assert_eq!(names[1], "Bot 2");
+            assert_eq!(names[3], "Bot 4");
+            assert!(matches!(
+                messages.last(),
+                Some(ServerMessage::MatchOver { .. })
+            ));
+
+            let views = messages.iter().filter_map(|message| match message {
+                ServerMessage::View { view } => Some(view),
+                _ => None,
+            });
+            for view in views {
+                assert_eq!(view.seat, seat);
+                assert!(view.hand.len() <= 8);
+            }
+            let errors = messages
+                .iter()
+                .filter(|message| matches!(message, ServerMessage::Error { .. }))
+                .count();
+            assert_eq!(errors, 1);
+            let cards_played = messages
+                .iter()
+                .filter(|message| {
+                    matches!(
+                        message,
+                        ServerMessage::Event {
+                            event: EventMessage::Card { .. }
+                        }
+                    )
+                })
+                .count();
+            assert_eq!(cards_played % 32, 0);
+        }
+        seats.sort();
+        assert_eq!(seats, [0, 2]);
+    }
+
+    #[test]
+    fn test_room_plays_a_rematch() {
+        let address = start_server(get_quick_match_config());
+        let mut connection = connect(&address, "Ana");
+        start_room_match(&mut connection);
+
+        let first_match = play_match(&mut connection);
+        assert!(matches!(
+            first_match.last(),
+            Some(ServerMessage::MatchOver { .. })
+        ));
+        let (seat, names) = get_match_seat(&first_match);
+        assert_eq!(seat, 0);
+        assert_eq!(names, ["Ana", "Bot 2", "Bot 3", "Bot 4"]);
+
+        // the room opens again after the match, then the rematch starts
+        loop {
+            match connection.receive::<ServerMessage>().unwrap() {
+                Some(ServerMessage::Room { room }) if !room.playing => {
+                    assert_eq!(room.name, "Office");
+                    assert_eq!(room.matches_played, 1);
+                    break;
+                }
+                Some(_) => continue,
+                None => panic!("server should keep the connection"),
+            }
+        }
+        connection.send(&ClientMessage::Start).unwrap();
+        let rematch = play_match(&mut connection);
+        assert!(matches!(
+            rematch.last(),
+            Some(ServerMessage::MatchOver { .. })
+        ));
+    }
+
+    #[test]
+    fn test_clients_check_a_fair_deal() {
+        let address = start_server(ServerConfig {
+            fair_dealing: true,
+            ..get_quick_match_config()
+        });
+        let mut connection = connect(&address, "Ana");
+        start_room_match(&mut connection);
+        let messages = play_match(&mut connection);
+
+        let mut commitment = None;
+        let mut hand = None;
+        let mut rounds_checked = 0;
+        for message in messages {
+            match message {
+                ServerMessage::DealCommitted {
+                    commitment: sent, ..
+                } => {
+                    commitment = Some(sent);
+                    hand = None;
+                }
+                ServerMessage::View { view } if hand.is_none() => {
+                    hand = Some(
+                        view.hand
+                            .iter()
+                            .filter_map(|name| Card::from_name(name))
+                            .collect(),
+                    );
+                }
+                ServerMessage::DealRevealed {
+                    secret, entropies, ..
+                } => {
+                    // only the client gave entropy, the bots don't
+                    assert_eq!(entropies, [Some("c0ffee".to_string()), None, None, None]);
+                    let hands = [(0, hand.take().unwrap())];
+                    let commitment = commitment.take().unwrap();
+                    let variant = GameVariant::FourPlayers;
+                    let sent = [(0, "c0ffee".to_string())];
+                    assert_eq!(
+                        verify_deal(&commitment, &secret, &entropies, variant, &sent, &hands),
+                        Ok(())
+                    );
+                    // a server that leaves out the entropy of the client is caught
+                    assert_eq!(
+                        verify_deal(&commitment, &secret, &[], variant, &sent, &hands),
+                        Err("the entropy of seat 0 was left out".to_string())
+                    );
+                    rounds_checked += 1;
+                }
+                _ => {}
+            }
+        }
+        assert!(rounds_checked > 0);
+    }
+
+    fn get_hands(messages: &[ServerMessage]) -> Vec<Vec<HandMessage>> {
+        messages
+            .iter()
+            .filter_map(|message| match message {
+                ServerMessage::Hands { hands } => Some(hands.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_spectators_watch_a_match_of_bots_late() {
+        let delay = Duration::from_millis(300);
+        let address = start_server(ServerConfig {
+            spectator_delay: delay,
+            ..get_quick_match_config()
+        });
+        let mut ana = connect(&address, "Ana");
+        let mut ivo = connect(&address, "Ivo");
+        let rules = RulesMessage {
+            target_points: 1,
+            ..RulesMessage::default()
+        };
+        ana.send(&ClientMessage::CreateRoom {
+            name: None,
+            rules: Some(rules),
+        })
+        .unwrap();
+        receive_until(&mut ana, |message| {
+            matches!(message, ServerMessage::Room { .. })
+        });
+        let spectate = |visibility| ClientMessage::Spectate {
+            room: 0,
+            visibility,
+        };
+        ivo.send(&spectate(VisibilityMessage::Seat { seat: 1 }))
+            .unwrap();
+        receive_until(&mut ivo, |message| {
+            matches!(message, ServerMessage::Room { .. })
+        });
+
+        // the room is left to bots and its spectators
+        ana.send(&spectate(VisibilityMessage::Open)).unwrap();
+        for seat in 0..4 {
+            ana.send(&ClientMessage::SetBot {
+                seat,
+                bot: Some(BotKind::Random),
+            })
+            .unwrap();
+        }
+        let started = Instant::now();
+        ana.send(&ClientMessage::Start).unwrap();
+        receive_until(&mut ana, |message| {
+            matches!(message, ServerMessage::Watching { .. })
+        });
+        assert!(started.elapsed() >= delay);
+        let is_over = |message: &ServerMessage| matches!(message, ServerMessage::MatchOver { .. });
+        let ana_messages = receive_until(&mut ana, is_over);
+        let ivo_messages = receive_until(&mut ivo, is_over);
+
+        // spectators hear everything but are asked nothing
+        for messages in [&ana_messages, &ivo_messages] {
+            assert!(messages.iter().all(|message| !matches!(
+                message,
+                ServerMessage::Ask { .. } | ServerMessage::View { .. }
+            )));
+            assert!(
+                messages
+                    .iter()
+                    .any(|message| matches!(message, ServerMessage::RoundOver { .. }))
+            );
+        }
+        assert!(
+            ivo_messages
+                .iter()
+                .any(|message| matches!(message, ServerMessage::Watching { .. }))
+        );
+        let open_hands = get_hands(&ana_messages);
+        assert!(!open_hands.is_empty());
+        assert!(open_hands.iter().all(|hands| hands.len() == 4));
+        let seat_hands = get_hands(&ivo_messages);
+        assert!(!seat_hands.is_empty());
+        assert!(
+            seat_hands
+                .iter()
+                .all(|hands| hands.len() == 1 && hands[0].seat == 1)
+        );
+    }
+
+    fn get_timeout_config(limit: u64, fallback: TimeoutFallback) -> ServerConfig {
+        ServerConfig {
+            move_timeout: Some(MoveTimeout {
+                limit: Duration::from_millis(limit),
+                fallback,
+            }),
+            spectator_delay: Duration::ZERO,
+            fair_dealing: false,
+            ..get_quick_match_config()
+        }
+    }
+
+    #[test]
+    fn test_lowest_card_is_played_for_a_client_out_of_time() {
+        let address = start_server(get_timeout_config(20, TimeoutFallback::LowestCard));
+        let mut connection = connect(&address, "Ana");
+        start_room_match(&mut connection);
+
+        // the client never answers
+        let messages = receive_until(&mut connection, |message| {
+            matches!(message, ServerMessage::MatchOver { .. })
+        });
+        let asks = messages
+            .iter()
+            .filter(|message| matches!(message, ServerMessage::Ask { .. }))
+            .count();
+        assert!(asks > 0);
+        assert_eq!(
+            count_events(&messages, EventMessage::TimedOut { seat: 0 }),
+            asks
+        );
+        assert_eq!(
+            count_events(&messages, EventMessage::BotTookOver { seat: 0 }),
+            0
+        );
+        let cards_played = messages.iter().filter(|message| {
+            matches!(
+                message,
+                ServerMessage::Event {
+                    event: EventMessage::Card { seat: 0, .. }
+                }
+            )
+        });
+        assert!(cards_played.count() >= 8);
+    }
+
+    // quick match where Ana takes the first seat and Ivo the other one, he
+    // holds his first answer until Ana is ready so the bots can't finish
+    // the match before
+    struct HeldMatch {
+        ana: Connection,
+        welcome: ServerMessage,
+        ready: Sender<()>,
+        ivo: JoinHandle<()>,
+    }
+
+    fn start_held_quick_match(address: &str) -> HeldMatch {
+        let mut ana = connect(address, "Ana");
+        ana.send(&ClientMessage::JoinRoom {
+            room: None,
+            seat: None,
+            team: None,
+        })
+        .unwrap();
+        let messages = receive_until(&mut ana, |message| {
+            matches!(message, ServerMessage::Room { .. })
+        });
+
+        let (ready_sender, ready) = mpsc::channel();
+        let mut ivo = connect(address, "Ivo");
+        ivo.send(&ClientMessage::JoinRoom {
+            room: None,
+            seat: None,
+            team: None,
+        })
+        .unwrap();
+        let ivo = thread::spawn(move || {
+            ready.recv().unwrap();
+            play_match(&mut ivo);
+        });
+
+        HeldMatch {
+            ana,
+            welcome: messages[0].clone(),
+            ready: ready_sender,
+            ivo,
+        }
+    }
+
+    #[test]
+    fn test_bot_plays_for_a_client_out_of_time_until_it_resumes() {
+        let address = start_server(get_timeout_config(500, TimeoutFallback::Bot));
+        let HeldMatch {
+            ana: mut connection,
+            ready,
+            ivo,
+            ..
+        } = start_held_quick_match(&address);
+
+        let bot_took_over = ServerMessage::Event {
+            event: EventMessage::BotTookOver { seat: 0 },
+        };
+        let messages = receive_until(&mut connection, |message| *message == bot_took_over);
+        assert_eq!(
+            count_events(&messages, EventMessage::TimedOut { seat: 0 }),
+            1
+        );
+
+        connection.send(&ClientMessage::Resume).unwrap();
+        ready.send(()).unwrap();
+        let messages = play_match(&mut connection);
+        ivo.join().unwrap();
+        let (seat, _) = get_match_seat(&messages);
+        assert_eq!(seat, 0);
+        assert_eq!(
+            count_events(&messages, EventMessage::PlayerReturned { seat: 0 }),
+            1
+        );
+        assert!(matches!(
+            messages.last(),
+            Some(ServerMessage::MatchOver { .. })
+        ));
+    }
+
+    #[test]
+    fn test_client_comes_back_after_a_lost_connection() {
+        let address = start_server(get_quick_match_config());
+        let HeldMatch {
+            ana: mut connection,
+            welcome,
+            ready,
+            ivo,
+        } = start_held_quick_match(&address);
+        receive_until(&mut connection, |message| {
+            matches!(message, ServerMessage::Ask { .. })
+        });
+        let ServerMessage::Welcome { client, token, .. } = welcome else {
+            panic!("client should be welcomed");
+        };
+        drop(connection);
+
+        // the server may not have noticed that the connection was lost yet
+        let mut connection = loop {
+            let mut connection = Connection::new(TcpStream::connect(&address).unwrap()).unwrap();
+            let hello = ClientMessage::Hello {
+                name: "Ana".to_string(),
+                token: Some(token.clone()),
+            };
+            connection.send(&hello).unwrap();
+            match connection.receive::<ServerMessage>().unwrap() {
+                Some(ServerMessage::Welcome { client: id, .. }) => {
+                    assert_eq!(id, client);
+                    break connection;
+                }
+                Some(ServerMessage::Error { .. }) => thread::sleep(Duration::from_millis(10)),
+                message => panic!("unexpected answer to the hello: {:?}", message),
+            }
+        };
+
+        ready.send(()).unwrap();
+        let messages = play_match(&mut connection);
+        ivo.join().unwrap();
+        let (seat, _) = get_match_seat(&messages);
+        assert_eq!(seat, 0);
+        let resynced = messages
+            .iter()
+            .position(|message| matches!(message, ServerMessage::View { .. }));
+        let first_ask = messages
+            .iter()
+            .position(|message| matches!(message, ServerMessage::Ask { .. }));
+        assert!(resynced < first_ask);
+        assert_eq!(
+            count_events(&messages, EventMessage::PlayerReturned { seat: 0 }),
+            1
+        );
+        assert!(matches!(
+            messages.last(),
+            Some(ServerMessage::MatchOver { .. })
+        ));
+    }
+
+    fn get(address: &str, path: &str) -> String {
+        let mut stream = TcpStream::connect(address).unwrap();
+        let request = format!("GET {} HTTP/1.1\r\nHost: {}\r\n\r\n", path, address);
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        response
+    }
+
+    fn listen() -> (TcpListener, String) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+
+        (listener, address)
+    }
+
+    // returns the address for JSON lines and the one for browsers
+    fn start_web_server() -> (String, String) {
+        let server = Server::new(ServerConfig {
+            seed: Some(2),
+            ..get_quick_match_config()
+        });
+        let (listener, address) = listen();
+        let (web_listener, web_address) = listen();
+        let web_server = server.clone();
+        thread::spawn(move || server.serve(listener));
+        thread::spawn(move || web_server.serve_web(web_listener));
+
+        (address, web_address)
+    }
+
+    #[test]
+    fn test_line_longer_than_the_limit_ends_the_connection() {
+        let address = start_server(get_quick_match_config());
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+
+        // one byte more than a line may have, and no newline
+        stream.write_all(&[b'a'; 8193]).unwrap();
+
+        let mut buffer = [0; 64];
+        assert_eq!(stream.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_serves_the_browser_client_page() {
+        let (_, web_address) = start_web_server();
+
+        let page = get(&web_address, "/");
+        assert!(page.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(page.contains("Content-Type: text/html"));
+        assert!(page.contains("<title>Belot</title>"));
+        assert!(page.contains("new WebSocket("));
+
+        let missing = get(&web_address, "/missing");
+        assert!(missing.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    }
+
+    #[test]
+    fn test_browser_and_terminal_clients_share_a_table() {
+        let (address, web_address) = start_web_server();
+
+        let browser = thread::spawn(move || {
+            let stream = TcpStream::connect(&web_address).unwrap();
+            let url = format!("ws://{}/", web_address);
+            let (socket, _) = tungstenite::client(url, stream).unwrap();
+            let mut connection = Connection::from_web_socket(socket);
+            connection
+                .send(&ClientMessage::Hello {
+                    name: "Ana".to_string(),
+                    token: None,
+                })
+                .unwrap();
+            play_quick_match(connection)
+        });
+        let terminal = thread::spawn(move || play_quick_match(connect(&address, "Ivo")));
+
+        for messages in [browser.join().unwrap(), terminal.join().unwrap()] {
+            let (_, names) = get_match_seat(&messages);
+            let mut client_names = [names[0].as_str(), names[2].as_str()];
+            client_names.sort();
+            assert_eq!(client_names, ["Ana", "Ivo"]);
+            assert!(matches!(
+                messages.last(),
+                Some(ServerMessage::MatchOver { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Browser clients. A browser that opens the address of the web listener
+//! gets the client page, the page connects back to the same address with a
+//! WebSocket and speaks the same messages as every other client.
+
+use std::{
+    io::{self, Read, Write},
+    net::TcpStream,
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::Connection;
+
+/// Page of the browser client, the whole client is in this one file.
+pub const CLIENT_PAGE: &str = include_str!("web_client.html");
+
+/// How long a browser has to send the headers of its request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_REQUEST_LENGTH: usize = 8192;
+
+/// Headers of the request waiting on the stream, they are only peeked at
+/// so the WebSocket handshake can still read them.
+fn peek_request(stream: &TcpStream) -> io::Result<String> {
+    let deadline = Instant::now() + REQUEST_TIMEOUT;
+    let mut buffer = vec![0; MAX_REQUEST_LENGTH];
+    loop {
+        let length = stream.peek(&mut buffer)?;
+        let end = buffer[..length]
+            .windows(4)
+            .position(|bytes| bytes == b"\r\n\r\n");
+        if let Some(end) = end {
+            buffer.truncate(end + 4);
+            return String::from_utf8(buffer).map_err(|_| io::ErrorKind::InvalidData.into());
+        }
+        if length == 0 || length == buffer.len() {
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+        if Instant::now() > deadline {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn is_web_socket_request(request: &str) -> bool {
+    request.lines().any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("upgrade")
+                && value.trim().eq_ignore_ascii_case("websocket")
+        })
+    })
+}
+
+/// Answers a plain HTTP request, the client page is the only thing there
+/// is to get.
+fn respond(stream: &mut TcpStream, request: &str) -> io::Result<()> {
+    let mut request_line = request.lines().next().unwrap_or_default().split(' ');
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line.next().unwrap_or_default();
+    let (status, content_type, body) = match (method, path) {
+        ("GET", "/" | "/index.html") => ("200 OK", "text/html; charset=utf-8", CLIENT_PAGE),
+        ("GET", _) => ("404 Not Found", "text/plain; charset=utf-8", "Not found\n"),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            "Method not allowed\n",
+        ),
+    };
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes())?;
+    stream.flush()
+}
+
+/// Upgrades WebSocket requests to a connection, any other request gets
+/// the client page or an error and `None`.
+pub fn accept(mut stream: TcpStream) -> io::Result<Option<Connection>> {
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    let request = peek_request(&stream)?;
+    if !is_web_socket_request(&request) {
+        stream.read_exact(&mut vec![0; request.len()])?;
+        respond(&mut stream, &request)?;
+        return Ok(None);
+    }
+
+    let socket =
+        tungstenite::accept(stream).map_err(|error| io::Error::other(error.to_string()))?;
+    // players take their time, the read timeout was only for the handshake
+    socket.get_ref().set_read_timeout(None)?;
+
+    Ok(Some(Connection::from_web_socket(socket)))
+}
@@ -0,0 +1,56 @@
+use pyo3::{exceptions::PyTypeError, prelude::*};
+
+use crate::{
+    bindings::agent::{Agent, AgentError, get_agent_player, get_bot},
+    clients::engine_protocol::Request,
+    game::{round::Round, round_player::RoundPlayer, table::Seat},
+};
+
+use super::round::PyRound;
+
+/// Agent written in Python, see the [module](super) for what it is asked.
+struct PythonAgent {
+    agent: Py<PyAny>,
+}
+
+impl Agent for PythonAgent {
+    type Error = PyErr;
+
+    fn ask(&self, round_state: &Round, seat: Seat, request: &Request) -> PyResult<String> {
+        Python::attach(|py| {
+            let round = PyRound::new(round_state.clone(), request.is_trump_called());
+            self.agent
+                .bind(py)
+                .call_method1(
+                    "answer",
+                    (
+                        round,
+                        seat.index(),
+                        request.to_line(),
+                        request.get_options(),
+                    ),
+                )?
+                .extract()
+        })
+    }
+}
+
+/// Player of a seat, the name of a bot of the engine or a Python agent.
+pub fn get_round_player(
+    agent: Bound<'_, PyAny>,
+    error: &AgentError<PyErr>,
+) -> PyResult<Box<dyn RoundPlayer>> {
+    if let Ok(name) = agent.extract::<String>() {
+        return Ok(get_bot(&name)?);
+    }
+    if !agent.hasattr("answer")? {
+        return Err(PyTypeError::new_err(
+            "an agent is a bot name or has an `answer` method",
+        ));
+    }
+
+    let agent = PythonAgent {
+        agent: agent.unbind(),
+    };
+    Ok(get_agent_player(agent, error))
+}
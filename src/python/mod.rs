@@ -0,0 +1,131 @@
+//! Python module `belot`, so agents written in Python can play against the
+//! bots of the engine without reimplementing the rules. Build it with
+//! `maturin develop` from the root of the crate.
+//!
+//! Cards, contracts and bids are strings with the names of round records,
+//! for example `Herz-Jack`, `AllTrumps` or `Dalje`, seats count from 0 and
+//! teams are `A`, `B` and `C`. A Python agent is any object with an
+//! `answer(round, seat, request, options)` method. It gets the round as it
+//! is, the request line of the [`engine_protocol`] and every answer the
+//! request allows, and returns one of them:
+//!
+//! ```text
+//! import belot
+//!
+//! class FirstOption:
+//!     def answer(self, round, seat, request, options):
+//!         return options[0]
+//!
+//! round = belot.Round(seed=1)
+//! round.play([FirstOption(), "heuristic", FirstOption(), "heuristic"])
+//! print(round.score)
+//! ```
+//!
+//! [`engine_protocol`]: crate::clients::engine_protocol
+
+mod agent;
+mod round;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::{
+    bindings::{BindingError, get_declarations, get_points},
+    game::rules::{RuleFlags, RuleSet},
+};
+
+use self::round::{PyMatch, PyRound};
+
+impl From<BindingError> for PyErr {
+    fn from(error: BindingError) -> Self {
+        PyValueError::new_err(error.message)
+    }
+}
+
+/// Rules a round is played with, every rule is off by default.
+#[pyclass(name = "Rules", module = "belot", from_py_object)]
+#[derive(Debug, Clone, Default)]
+pub struct PyRules {
+    #[pyo3(get, set)]
+    three_players: bool,
+    #[pyo3(get, set)]
+    kontra: bool,
+    #[pyo3(get, set)]
+    extended_contracts: bool,
+    #[pyo3(get, set)]
+    auction: bool,
+    #[pyo3(get, set)]
+    bela_on_second_card: bool,
+}
+
+#[pymethods]
+impl PyRules {
+    #[new]
+    #[pyo3(signature = (*, three_players=false, kontra=false, extended_contracts=false, auction=false, bela_on_second_card=false))]
+    fn new(
+        three_players: bool,
+        kontra: bool,
+        extended_contracts: bool,
+        auction: bool,
+        bela_on_second_card: bool,
+    ) -> Self {
+        Self {
+            three_players,
+            kontra,
+            extended_contracts,
+            auction,
+            bela_on_second_card,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Rules(three_players={}, kontra={}, extended_contracts={}, auction={}, bela_on_second_card={})",
+            self.three_players,
+            self.kontra,
+            self.extended_contracts,
+            self.auction,
+            self.bela_on_second_card
+        )
+        .replace("true", "True")
+        .replace("false", "False")
+    }
+}
+
+impl PyRules {
+    fn get_rule_set(&self) -> RuleSet {
+        RuleSet::from_flags(RuleFlags {
+            three_players: self.three_players,
+            kontra: self.kontra,
+            extended_contracts: self.extended_contracts,
+            auction: self.auction,
+            bela_on_second_card: self.bela_on_second_card,
+        })
+    }
+}
+
+/// Declarations the hand holds, as their cards and points.
+#[pyfunction]
+fn possible_declarations(hand: Vec<String>) -> PyResult<Vec<(Vec<String>, usize)>> {
+    Ok(get_declarations(&hand)?)
+}
+
+/// Points the card is worth in a trick under the contract.
+#[pyfunction]
+fn card_points(card: &str, contract: &str) -> PyResult<usize> {
+    Ok(get_points(card, contract)?)
+}
+
+#[pymodule]
+fn belot(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyRules>()?;
+    module.add_class::<PyRound>()?;
+    module.add_class::<PyMatch>()?;
+    module.add_function(wrap_pyfunction!(possible_declarations, module)?)?;
+    module.add_function(wrap_pyfunction!(card_points, module)?)?;
+    module.add_function(wrap_pyfunction!(round::deal, module)?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_python;
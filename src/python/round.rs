@@ -0,0 +1,359 @@
+use std::collections::BTreeMap;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::{
+    bindings::{
+        get_cards, get_names,
+        round::{get_round, get_table_config, get_team_points, play},
+    },
+    game::{
+        deck::Deal,
+        game_match::{DEFAULT_TARGET_POINTS, Match},
+        round::Round,
+        round_observer::NullObserver,
+        solver::DoubleDummySolver,
+    },
+};
+
+use super::{PyRules, agent::get_round_player};
+
+/// Cards dealt to every seat and the talon, shuffled with the seed.
+#[pyfunction]
+#[pyo3(signature = (rules=None, *, seed=None))]
+pub fn deal(
+    rules: Option<PyRules>,
+    seed: Option<u64>,
+) -> PyResult<(Vec<Vec<String>>, Vec<String>)> {
+    let rules = rules.unwrap_or_default().get_rule_set();
+    let round = Round::new(&get_table_config(rules, seed, None)?);
+    let hands = round
+        .deal
+        .hands
+        .iter()
+        .map(|hand| get_names(hand))
+        .collect();
+
+    Ok((hands, get_names(&round.deal.talon)))
+}
+
+/// Round of the engine, from the deal until it is scored.
+#[pyclass(name = "Round", module = "belot")]
+pub struct PyRound {
+    round: Round,
+    is_trump_called: bool,
+}
+
+impl PyRound {
+    pub fn new(round: Round, is_trump_called: bool) -> Self {
+        Self {
+            round,
+            is_trump_called,
+        }
+    }
+
+    fn check_trump_called(&self) -> PyResult<()> {
+        if self.is_trump_called {
+            Ok(())
+        } else {
+            Err(PyValueError::new_err("trump isn't called yet"))
+        }
+    }
+}
+
+#[pymethods]
+impl PyRound {
+    /// Shuffled deal, or the given hands and talon. The last seat deals
+    /// unless `dealer` says otherwise, the seat after the dealer plays
+    /// first.
+    #[new]
+    #[pyo3(signature = (rules=None, *, seed=None, dealer=None, hands=None, talon=None))]
+    fn py_new(
+        rules: Option<PyRules>,
+        seed: Option<u64>,
+        dealer: Option<usize>,
+        hands: Option<Vec<Vec<String>>>,
+        talon: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        let rules = rules.unwrap_or_default().get_rule_set();
+        let table_config = get_table_config(rules, seed, dealer)?;
+        let deal = hands
+            .map(|hands| -> PyResult<Deal> {
+                Ok(Deal {
+                    hands: hands
+                        .iter()
+                        .map(|hand| get_cards(hand))
+                        .collect::<Result<_, _>>()?,
+                    talon: get_cards(&talon.unwrap_or_default())?,
+                })
+            })
+            .transpose()?;
+
+        Ok(Self::new(get_round(&table_config, deal)?, false))
+    }
+
+    /// Plays the round to the end, `agents` has a bot name like
+    /// `heuristic` or a Python agent for every seat.
+    fn play(&mut self, agents: Vec<Bound<'_, PyAny>>) -> PyResult<()> {
+        if self.is_trump_called {
+            return Err(PyValueError::new_err("the round was already played"));
+        }
+        let number_of_players = self.round.get_number_of_players();
+        play(
+            agents,
+            number_of_players,
+            get_round_player,
+            |round_player| {
+                self.round.play_round(round_player, &NullObserver);
+                self.is_trump_called = true;
+            },
+        )
+    }
+
+    #[getter]
+    fn number_of_players(&self) -> usize {
+        self.round.get_number_of_players()
+    }
+
+    /// Team of every seat.
+    #[getter]
+    fn teams(&self) -> Vec<&'static str> {
+        let teams = self.round.players.get_seat_teams();
+        teams.into_iter().map(|team| team.into()).collect()
+    }
+
+    /// Cards every seat still holds.
+    #[getter]
+    fn hands(&self) -> Vec<Vec<String>> {
+        (&self.round.players)
+            .into_iter()
+            .map(|player| get_names(player.get_hand().cards()))
+            .collect()
+    }
+
+    /// Cards every seat was dealt, after a redeal the cards of the redeal.
+    #[getter]
+    fn dealt_hands(&self) -> Vec<Vec<String>> {
+        self.round
+            .deal
+            .hands
+            .iter()
+            .map(|hand| get_names(hand))
+            .collect()
+    }
+
+    #[getter]
+    fn talon(&self) -> Vec<String> {
+        get_names(&self.round.talon)
+    }
+
+    /// Seat that plays the next card.
+    #[getter]
+    fn seat_turn(&self) -> usize {
+        self.round.current_trick.get_seat_turn().index()
+    }
+
+    /// Contract and the seat that called it, `None` until trump is called.
+    #[getter]
+    fn trump(&self) -> Option<(&'static str, usize)> {
+        let trump = &self.round.trump;
+        self.is_trump_called
+            .then(|| (trump.kind.get_name(), trump.seat.index()))
+    }
+
+    /// `None`, `Kontra` or `Rekontra`.
+    #[getter]
+    fn doubling(&self) -> &'static str {
+        self.round.doubling.into()
+    }
+
+    /// Bids of the auction with the seats that made them.
+    #[getter]
+    fn bids(&self) -> Vec<(usize, &'static str)> {
+        let bids = &self.round.auction.bids;
+        bids.iter()
+            .map(|item| (item.seat.index(), item.bid.get_name()))
+            .collect()
+    }
+
+    /// Cards of the trick that is being played, in the order they were
+    /// played from `trick_first_seat`.
+    #[getter]
+    fn current_trick(&self) -> Vec<String> {
+        get_names(&self.round.current_trick.cards_on_table)
+    }
+
+    #[getter]
+    fn trick_first_seat(&self) -> usize {
+        self.round.current_trick.get_first_seat().index()
+    }
+
+    /// Finished tricks as their first seat, cards, winner and card points.
+    #[getter]
+    fn tricks(&self) -> Vec<(usize, Vec<String>, usize, usize)> {
+        let tricks = &self.round.trick_history;
+        tricks
+            .iter()
+            .map(|item| {
+                (
+                    item.trick.get_first_seat().index(),
+                    get_names(&item.trick.cards_on_table),
+                    item.winner_seat.index(),
+                    item.points,
+                )
+            })
+            .collect()
+    }
+
+    /// Declarations that count, as the seat, cards and points.
+    #[getter]
+    fn declarations(&self) -> Vec<(usize, Vec<String>, usize)> {
+        let declarations = self.round.team_declarations.declarations.iter().flatten();
+        declarations
+            .map(|item| {
+                (
+                    item.seat.index(),
+                    get_names(&item.declaration.cards),
+                    item.declaration.points,
+                )
+            })
+            .collect()
+    }
+
+    /// Seats that declared bela.
+    #[getter]
+    fn bela(&self) -> Vec<usize> {
+        let announcements = &self.round.bela_announcements;
+        announcements
+            .iter()
+            .map(|announcement| announcement.seat.index())
+            .collect()
+    }
+
+    /// Card points of every team from the tricks taken so far.
+    #[getter]
+    fn points(&self) -> BTreeMap<&'static str, usize> {
+        get_team_points(&self.round.points, &self.round.players.get_teams())
+    }
+
+    /// Points of every team once the round is scored, with declarations,
+    /// bela and štiglja, before doubling.
+    #[getter]
+    fn final_points(&self) -> BTreeMap<&'static str, usize> {
+        get_team_points(&self.round.final_points, &self.round.players.get_teams())
+    }
+
+    /// Points every team gets for the round in a match.
+    #[getter]
+    fn score(&self) -> BTreeMap<&'static str, usize> {
+        let result = self.round.get_result();
+        get_team_points(&result.points, &self.round.players.get_teams())
+    }
+
+    #[getter]
+    fn is_done(&self) -> bool {
+        self.is_trump_called && !self.round.players.have_cards()
+    }
+
+    /// Cards the seat on turn may play.
+    fn playable_cards(&self) -> PyResult<Vec<String>> {
+        self.check_trump_called()?;
+        let round = &self.round;
+        let cards = round
+            .current_trick
+            .get_playeble_cards(&round.players, &round.trump.kind);
+
+        Ok(get_names(&cards))
+    }
+
+    /// Card points the team of the seat on turn gets in the rest of the
+    /// round when everybody sees every card and plays perfectly.
+    fn solve(&self) -> PyResult<usize> {
+        self.check_trump_called()?;
+        Ok(self.get_solver().solve())
+    }
+
+    /// Every card the seat on turn may play with the points its team gets
+    /// after it, see [`PyRound::solve`].
+    fn solve_cards(&self) -> PyResult<Vec<(String, usize)>> {
+        self.check_trump_called()?;
+        let solved_cards = self.get_solver().solve_cards();
+
+        Ok(solved_cards
+            .into_iter()
+            .map(|solved_card| (solved_card.card.get_name(), solved_card.points))
+            .collect())
+    }
+}
+
+impl PyRound {
+    fn get_solver(&self) -> DoubleDummySolver {
+        let seat = self.round.current_trick.get_seat_turn();
+        DoubleDummySolver::new(&self.round, self.round.players.get_team(seat))
+    }
+}
+
+/// Rounds played until a team reaches the target points.
+#[pyclass(name = "Match", module = "belot")]
+pub struct PyMatch {
+    game_match: Match,
+}
+
+#[pymethods]
+impl PyMatch {
+    #[new]
+    #[pyo3(signature = (rules=None, *, target_points=DEFAULT_TARGET_POINTS, seed=None))]
+    fn py_new(rules: Option<PyRules>, target_points: usize, seed: Option<u64>) -> PyResult<Self> {
+        let rules = rules.unwrap_or_default().get_rule_set();
+        let table_config = get_table_config(rules, seed, None)?;
+        Ok(Self {
+            game_match: Match::new(table_config, target_points),
+        })
+    }
+
+    /// Plays the next round with an agent for every seat and returns it.
+    fn play_round(&mut self, agents: Vec<Bound<'_, PyAny>>) -> PyResult<PyRound> {
+        if self.game_match.is_done() {
+            return Err(PyValueError::new_err("the match is over"));
+        }
+        let number_of_players = self
+            .game_match
+            .get_next_table_config()
+            .get_number_of_players();
+        let mut round = None;
+        play(
+            agents,
+            number_of_players,
+            get_round_player,
+            |round_player| round = Some(self.game_match.play_round(round_player, &NullObserver)),
+        )?;
+
+        Ok(PyRound::new(round.expect("round was played"), true))
+    }
+
+    #[getter]
+    fn target_points(&self) -> usize {
+        self.game_match.target_points
+    }
+
+    #[getter]
+    fn points(&self) -> BTreeMap<&'static str, usize> {
+        get_team_points(&self.game_match.points, &self.game_match.get_teams())
+    }
+
+    #[getter]
+    fn rounds_played(&self) -> usize {
+        self.game_match.round_results.len()
+    }
+
+    #[getter]
+    fn is_done(&self) -> bool {
+        self.game_match.is_done()
+    }
+
+    /// Team that won, `None` while the match goes on.
+    #[getter]
+    fn winner(&self) -> Option<&'static str> {
+        self.game_match.get_winner().map(|team| team.into())
+    }
+}
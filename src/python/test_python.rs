@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use pyo3::{prelude::*, types::PyDict};
+
+    use crate::python::belot;
+
+    // runs the code with the module imported as `belot`, the code checks
+    // itself with asserts
+    fn run_python(code: &CStr) {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "belot").unwrap();
+            belot(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("belot", module).unwrap();
+            if let Err(error) = py.run(code, Some(&globals), None) {
+                panic!("{}", error);
+            }
+        });
+    }
+
+    #[test]
+    fn test_python_agents_play_against_bots() {
+        run_python(
+            cr#"
+class FirstCard:
+    def __init__(self):
+        self.requests = []
+
+    def answer(self, round, seat, request, options):
+        self.requests.append(request.split()[0])
+        if request.startswith("card"):
+            assert round.seat_turn == seat
+            assert sorted(options) == sorted(round.playable_cards())
+        return options[0]
+
+agent = FirstCard()
+round = belot.Round(seed=1)
+assert round.trump is None and not round.is_done
+round.play([agent, "heuristic", agent, "random"])
+
+assert round.is_done and round.trump is not None
+assert len(round.tricks) == 8
+assert agent.requests.count("card") == 16
+assert sum(round.points.values()) == 162
+assert round.hands == [[], [], [], []]
+"#,
+        );
+    }
+
+    #[test]
+    fn test_agent_errors_are_raised_once_the_round_is_over() {
+        run_python(
+            cr#"
+class Nonsense:
+    def answer(self, round, seat, request, options):
+        return "nonsense"
+
+round = belot.Round(seed=2)
+try:
+    round.play([Nonsense(), "random", "random", "random"])
+    assert False
+except ValueError as error:
+    assert str(error) == "agent answered `nonsense` to `trump`"
+# the heuristic bot played for the agent
+assert round.is_done
+
+for agents in [["heuristic"] * 3, ["chess"] * 4]:
+    try:
+        belot.Round().play(agents)
+        assert False
+    except ValueError:
+        pass
+"#,
+        );
+    }
+
+    #[test]
+    fn test_solver_plays_three_players() {
+        run_python(
+            cr#"
+class Solver:
+    def answer(self, round, seat, request, options):
+        if not request.startswith("card"):
+            return options[0]
+        solved = round.solve_cards()
+        assert sorted(card for card, _ in solved) == sorted(options)
+        return max(solved, key=lambda solved_card: solved_card[1])[0]
+
+hands, talon = belot.deal(belot.Rules(three_players=True), seed=3)
+round = belot.Round(belot.Rules(three_players=True), hands=hands, talon=talon)
+assert round.hands == hands and round.talon == talon and len(talon) == 2
+round.play([Solver(), "heuristic", "heuristic"])
+assert round.is_done and len(round.tricks) == 10
+assert sorted(round.score) == ["A", "B", "C"]
+"#,
+        );
+    }
+
+    #[test]
+    fn test_only_whole_decks_are_dealt() {
+        run_python(
+            cr#"
+hands, talon = belot.deal(seed=4)
+for hands, talon, message in [
+    ([hands[0]] * 4, talon, "the hands and the talon need every card of the deck once"),
+    (hands[:3], talon, "every one of 4 hands needs 8 cards and the talon 0"),
+    (hands, [hands[0][0]], "every one of 4 hands needs 8 cards and the talon 0"),
+    ([hands[0][:7] + [hands[1][0]]] + hands[1:], talon, "the hands and the talon need every card of the deck once"),
+]:
+    try:
+        belot.Round(hands=hands, talon=talon)
+        assert False
+    except ValueError as error:
+        assert str(error) == message
+
+hands, talon = belot.deal(belot.Rules(three_players=True), seed=4)
+try:
+    belot.Round(belot.Rules(three_players=True), hands=hands, talon=[])
+    assert False
+except ValueError as error:
+    assert str(error) == "every one of 3 hands needs 10 cards and the talon 2"
+"#,
+        );
+    }
+
+    #[test]
+    fn test_rules_are_scored_like_the_engine() {
+        run_python(
+            cr#"
+hand = ["Herz-VII", "Herz-VIII", "Herz-IX", "Leaf-Jack", "Herz-Jack", "Pumpkin-Jack", "Acorn-Jack"]
+assert (["Herz-VII", "Herz-VIII", "Herz-IX"], 20) in belot.possible_declarations(hand)
+assert belot.card_points("Herz-Jack", "Herz") == 20
+assert belot.card_points("Herz-Jack", "Leaf") == 2
+
+game_match = belot.Match(target_points=200, seed=5)
+while not game_match.is_done:
+    game_match.play_round(["heuristic"] * 4)
+assert game_match.winner in ["A", "B"]
+assert max(game_match.points.values()) >= 200
+"#,
+        );
+    }
+}
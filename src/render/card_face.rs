@@ -0,0 +1,77 @@
+use strum_macros::{EnumIter, EnumString, IntoStaticStr};
+
+use crate::game::deck::{CardSuit, CardValue};
+
+/// Names printed on the cards. The German suited deck maps to the French
+/// one the usual way: leaves are spades, bells (pumpkins) are diamonds,
+/// hearts stay hearts and acorns are clubs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+#[non_exhaustive]
+pub enum CardFace {
+    /// Names the game uses internally and in round records.
+    #[default]
+    Deck,
+    /// Tref, Karo, Herc, Pik.
+    CroatianFrench,
+    /// Žir, Bundeva, Srce, List.
+    CroatianGerman,
+    /// Trèfle, Carreau, Cœur, Pique.
+    French,
+    /// Clubs, Diamonds, Hearts, Spades.
+    English,
+}
+
+impl CardFace {
+    pub fn get_suit_name(self, suit: &CardSuit) -> &'static str {
+        match (self, suit) {
+            (Self::Deck, suit) => suit.clone().into(),
+            (Self::CroatianFrench, CardSuit::Leaf) => "Pik",
+            (Self::CroatianFrench, CardSuit::Pumpkin) => "Karo",
+            (Self::CroatianFrench, CardSuit::Herz) => "Herc",
+            (Self::CroatianFrench, CardSuit::Acorn) => "Tref",
+            (Self::CroatianGerman, CardSuit::Leaf) => "List",
+            (Self::CroatianGerman, CardSuit::Pumpkin) => "Bundeva",
+            (Self::CroatianGerman, CardSuit::Herz) => "Srce",
+            (Self::CroatianGerman, CardSuit::Acorn) => "Žir",
+            (Self::French, CardSuit::Leaf) => "Pique",
+            (Self::French, CardSuit::Pumpkin) => "Carreau",
+            (Self::French, CardSuit::Herz) => "Cœur",
+            (Self::French, CardSuit::Acorn) => "Trèfle",
+            (Self::English, CardSuit::Leaf) => "Spades",
+            (Self::English, CardSuit::Pumpkin) => "Diamonds",
+            (Self::English, CardSuit::Herz) => "Hearts",
+            (Self::English, CardSuit::Acorn) => "Clubs",
+        }
+    }
+
+    pub fn get_value_name(self, value: &CardValue) -> &'static str {
+        let names = match self {
+            Self::Deck => return value.clone().into(),
+            Self::CroatianFrench => ["Dečko", "Dama", "Kralj", "As"],
+            Self::CroatianGerman => ["Dečko", "Baba", "Kralj", "As"],
+            Self::French => ["Valet", "Dame", "Roi", "As"],
+            Self::English => ["Jack", "Queen", "King", "Ace"],
+        };
+        match value {
+            CardValue::VII => "7",
+            CardValue::VIII => "8",
+            CardValue::IX => "9",
+            CardValue::X => "10",
+            CardValue::Jack => names[0],
+            CardValue::Queen => names[1],
+            CardValue::King => names[2],
+            CardValue::Kec => names[3],
+        }
+    }
+}
+
+/// French suit symbol of a suit, see [`CardFace`] for the mapping.
+pub fn get_suit_symbol(suit: &CardSuit) -> &'static str {
+    match suit {
+        CardSuit::Leaf => "♠",
+        CardSuit::Pumpkin => "♦",
+        CardSuit::Herz => "♥",
+        CardSuit::Acorn => "♣",
+    }
+}
@@ -0,0 +1,372 @@
+use strum_macros::{EnumIter, EnumString, IntoStaticStr};
+
+/// Language of the prompts and of the event descriptions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+#[non_exhaustive]
+pub enum Language {
+    #[default]
+    English,
+    Croatian,
+}
+
+impl Language {
+    pub fn pass(self) -> &'static str {
+        match self {
+            Self::English => "Pass",
+            Self::Croatian => "Dalje",
+        }
+    }
+
+    pub fn kontra(self) -> &'static str {
+        match self {
+            Self::English => "Double",
+            Self::Croatian => "Kontra",
+        }
+    }
+
+    pub fn rekontra(self) -> &'static str {
+        match self {
+            Self::English => "Redouble",
+            Self::Croatian => "Rekontra",
+        }
+    }
+
+    pub fn no_trumps(self) -> &'static str {
+        match self {
+            Self::English => "No trumps",
+            Self::Croatian => "Bez aduta",
+        }
+    }
+
+    pub fn all_trumps(self) -> &'static str {
+        match self {
+            Self::English => "All trumps",
+            Self::Croatian => "Sve adut",
+        }
+    }
+
+    pub fn yes(self) -> &'static str {
+        match self {
+            Self::English => "Yes",
+            Self::Croatian => "Da",
+        }
+    }
+
+    pub fn no(self) -> &'static str {
+        match self {
+            Self::English => "No",
+            Self::Croatian => "Ne",
+        }
+    }
+
+    /// Whether a typed answer means yes, the first letter is enough.
+    pub fn is_yes(self, input: &str) -> bool {
+        let yes = self.yes().to_lowercase();
+        let input = input.trim().to_lowercase();
+        !input.is_empty() && yes.starts_with(&input)
+    }
+
+    pub fn yes_no_hint(self) -> &'static str {
+        match self {
+            Self::English => "(y/n)",
+            Self::Croatian => "(d/n)",
+        }
+    }
+
+    pub fn team(self) -> &'static str {
+        match self {
+            Self::English => "TEAM",
+            Self::Croatian => "TIM",
+        }
+    }
+
+    pub fn trump(self) -> &'static str {
+        match self {
+            Self::English => "TRUMP",
+            Self::Croatian => "ADUT",
+        }
+    }
+
+    pub fn round_over(self, round_number: usize) -> String {
+        match self {
+            Self::English => format!("Round {} is over", round_number),
+            Self::Croatian => format!("Kraj {}. runde", round_number),
+        }
+    }
+
+    pub fn won_match(self, team: &str) -> String {
+        match self {
+            Self::English => format!("TEAM {} won the match!", team),
+            Self::Croatian => format!("TIM {} je pobijedio!", team),
+        }
+    }
+
+    pub fn waiting_for_players(self, seat: usize) -> String {
+        match self {
+            Self::English => format!("You sit in seat {}, waiting for the other players", seat),
+            Self::Croatian => format!("Sjediš na mjestu {}, čekaju se ostali igrači", seat),
+        }
+    }
+
+    pub fn reconnecting(self) -> &'static str {
+        match self {
+            Self::English => "The connection was lost, connecting again...",
+            Self::Croatian => "Veza je prekinuta, ponovno spajanje...",
+        }
+    }
+
+    pub fn hint_help(self) -> &'static str {
+        match self {
+            Self::English => "Type '?' for a hint.",
+            Self::Croatian => "Upiši '?' za savjet.",
+        }
+    }
+
+    pub fn expected_points(self) -> &'static str {
+        match self {
+            Self::English => "Expected points of your team:",
+            Self::Croatian => "Očekivani bodovi tvog tima:",
+        }
+    }
+
+    pub fn your_cards(self) -> &'static str {
+        match self {
+            Self::English => "Your cards are:",
+            Self::Croatian => "Tvoje karte su:",
+        }
+    }
+
+    pub fn your_cards_with_hidden(self) -> &'static str {
+        match self {
+            Self::English => "Your cards (two are hidden):",
+            Self::Croatian => "Tvoje karte (dvije su skrivene):",
+        }
+    }
+
+    pub fn hidden_cards_were(self) -> &'static str {
+        match self {
+            Self::English => "Your hidden cards were:",
+            Self::Croatian => "Skrivene karte su bile:",
+        }
+    }
+
+    pub fn choose_trump(self) -> &'static str {
+        match self {
+            Self::English => "Choose a trump",
+            Self::Croatian => "Odaberi adut",
+        }
+    }
+
+    pub fn must_choose_trump(self) -> &'static str {
+        match self {
+            Self::English => "You have to choose a trump",
+            Self::Croatian => "Moraš odabrati adut",
+        }
+    }
+
+    pub fn type_to_pass(self) -> String {
+        match self {
+            Self::English => format!("or type '{}' to pass", self.pass()),
+            Self::Croatian => format!("ili upiši '{}'", self.pass()),
+        }
+    }
+
+    pub fn invalid_input(self) -> &'static str {
+        match self {
+            Self::English => "Invalid input, please try again.",
+            Self::Croatian => "Neispravan unos, pokušaj ponovno.",
+        }
+    }
+
+    pub fn your_turn(self) -> &'static str {
+        match self {
+            Self::English => "Your turn, choose a card to play",
+            Self::Croatian => "Na redu si, odaberi kartu",
+        }
+    }
+
+    pub fn choose_marked_card(self) -> &'static str {
+        match self {
+            Self::English => "Choose the number of a card marked with '*':",
+            Self::Croatian => "Odaberi broj karte označene s '*':",
+        }
+    }
+
+    pub fn card_not_playable(self) -> &'static str {
+        match self {
+            Self::English => "You cannot play that card, choose one of the marked cards.",
+            Self::Croatian => "Ne možeš igrati tu kartu, odaberi jednu od označenih.",
+        }
+    }
+
+    pub fn enter_number(self, max: usize) -> String {
+        match self {
+            Self::English => format!("Please enter a number between 1 and {}.", max),
+            Self::Croatian => format!("Upiši broj između 1 i {}.", max),
+        }
+    }
+
+    pub fn your_bids(self) -> &'static str {
+        match self {
+            Self::English => "Your bids are:",
+            Self::Croatian => "Možeš licitirati:",
+        }
+    }
+
+    pub fn make_bid(self) -> &'static str {
+        match self {
+            Self::English => "Make a bid",
+            Self::Croatian => "Licitiraj",
+        }
+    }
+
+    pub fn ask_declaration(self, cards: &str, points: usize) -> String {
+        match self {
+            Self::English => format!("Declare {} for {} points?", cards, points),
+            Self::Croatian => format!("Zvati {} za {} bodova?", cards, points),
+        }
+    }
+
+    pub fn ask_bela(self) -> &'static str {
+        match self {
+            Self::English => "Declare bela?",
+            Self::Croatian => "Zvati belu?",
+        }
+    }
+
+    pub fn ask_kontra(self) -> &'static str {
+        match self {
+            Self::English => "Call kontra?",
+            Self::Croatian => "Zvati kontru?",
+        }
+    }
+
+    pub fn ask_rekontra(self) -> &'static str {
+        match self {
+            Self::English => "Call rekontra?",
+            Self::Croatian => "Zvati rekontru?",
+        }
+    }
+
+    pub fn plays(self, name: &str, card: &str) -> String {
+        match self {
+            Self::English => format!("{} plays {}", name, card),
+            Self::Croatian => format!("{} igra {}", name, card),
+        }
+    }
+
+    pub fn calls(self, name: &str, contract: &str) -> String {
+        match self {
+            Self::English => format!("{} calls {}", name, contract),
+            Self::Croatian => format!("{} zove {}", name, contract),
+        }
+    }
+
+    pub fn passes(self, name: &str) -> String {
+        match self {
+            Self::English => format!("{} passes", name),
+            Self::Croatian => format!("{} kaže dalje", name),
+        }
+    }
+
+    pub fn bids(self, name: &str, bid: &str) -> String {
+        match self {
+            Self::English => format!("{} bids {}", name, bid),
+            Self::Croatian => format!("{} licitira {}", name, bid),
+        }
+    }
+
+    pub fn redealt(self) -> &'static str {
+        match self {
+            Self::English => "everybody passed, cards are dealt again",
+            Self::Croatian => "svi su rekli dalje, karte se dijele ponovno",
+        }
+    }
+
+    pub fn declares(self, name: &str, cards: &str, points: usize) -> String {
+        match self {
+            Self::English => format!("{} declares {} for {}", name, cards, points),
+            Self::Croatian => format!("{} zove {} za {}", name, cards, points),
+        }
+    }
+
+    pub fn calls_kontra(self, name: &str) -> String {
+        match self {
+            Self::English => format!("{} calls kontra", name),
+            Self::Croatian => format!("{} zove kontru", name),
+        }
+    }
+
+    pub fn calls_rekontra(self, name: &str) -> String {
+        match self {
+            Self::English => format!("{} calls rekontra", name),
+            Self::Croatian => format!("{} zove rekontru", name),
+        }
+    }
+
+    pub fn declares_bela(self, name: &str) -> String {
+        match self {
+            Self::English => format!("{} declares bela", name),
+            Self::Croatian => format!("{} zove belu", name),
+        }
+    }
+
+    pub fn holds(self, name: &str, cards: &str) -> String {
+        match self {
+            Self::English => format!("{} holds {}", name, cards),
+            Self::Croatian => format!("{} drži {}", name, cards),
+        }
+    }
+
+    pub fn deal_verified(self, round_number: usize) -> String {
+        match self {
+            Self::English => format!("The deal of round {} was checked, it is fair", round_number),
+            Self::Croatian => format!("Dijeljenje {}. runde je provjereno i pošteno", round_number),
+        }
+    }
+
+    pub fn deal_not_verified(self, round_number: usize, reason: &str) -> String {
+        match self {
+            Self::English => format!(
+                "The deal of round {} can't be trusted: {}",
+                round_number, reason
+            ),
+            Self::Croatian => format!(
+                "Dijeljenju {}. runde ne može se vjerovati: {}",
+                round_number, reason
+            ),
+        }
+    }
+
+    pub fn timed_out(self, name: &str) -> String {
+        match self {
+            Self::English => format!("{} ran out of time", name),
+            Self::Croatian => format!("{} nije odigrao na vrijeme", name),
+        }
+    }
+
+    pub fn bot_took_over(self, name: &str) -> String {
+        match self {
+            Self::English => format!("the bot plays for {}", name),
+            Self::Croatian => format!("bot igra umjesto {}", name),
+        }
+    }
+
+    pub fn player_returned(self, name: &str) -> String {
+        match self {
+            Self::English => format!("{} is back", name),
+            Self::Croatian => format!("{} se vratio", name),
+        }
+    }
+
+    pub fn takes_trick(self, name: &str, points: usize, team: &str) -> String {
+        match self {
+            Self::English => format!(
+                "{} takes the trick with {} points for team {}",
+                name, points, team
+            ),
+            Self::Croatian => format!("{} nosi štih s {} bodova za tim {}", name, points, team),
+        }
+    }
+}
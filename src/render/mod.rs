@@ -0,0 +1,118 @@
+mod card_face;
+mod language;
+
+#[cfg(test)]
+mod test_render;
+
+pub use card_face::CardFace;
+pub use language::Language;
+
+use crate::game::{
+    auction::Bid,
+    deck::{Card, CardSuit, CardValue},
+    trump::ContractKind,
+};
+
+use card_face::get_suit_symbol;
+
+/// How cards and messages are shown to people. Round records always use
+/// the names of [`CardFace::Deck`], whatever the renderer shows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Renderer {
+    pub card_face: CardFace,
+    pub language: Language,
+    /// Color suits with ANSI escape codes.
+    pub colors: bool,
+    /// Show suits as Unicode symbols instead of names.
+    pub symbols: bool,
+}
+
+impl Renderer {
+    pub fn get_suit_name(&self, suit: &CardSuit) -> &'static str {
+        if self.symbols {
+            get_suit_symbol(suit)
+        } else {
+            self.card_face.get_suit_name(suit)
+        }
+    }
+
+    pub fn get_value_name(&self, value: &CardValue) -> &'static str {
+        self.card_face.get_value_name(value)
+    }
+
+    /// Wraps the text in the ANSI color of the suit when colors are on.
+    fn paint(&self, suit: &CardSuit, text: String) -> String {
+        if !self.colors {
+            return text;
+        }
+        let color = match suit {
+            CardSuit::Herz => "31",
+            CardSuit::Pumpkin => "33",
+            CardSuit::Leaf => "32",
+            CardSuit::Acorn => "38;2;181;101;29",
+        };
+        format!("\x1b[{}m{}\x1b[0m", color, text)
+    }
+
+    pub fn get_card_name(&self, card: &Card) -> String {
+        let name = format!(
+            "{} {}",
+            self.get_suit_name(&card.suit),
+            self.get_value_name(&card.value)
+        );
+        self.paint(&card.suit, name)
+    }
+
+    /// Card names separated by commas.
+    pub fn get_card_names(&self, cards: &[Card]) -> String {
+        cards
+            .iter()
+            .map(|card| self.get_card_name(card))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn get_contract_name(&self, kind: &ContractKind) -> String {
+        match kind {
+            ContractKind::Suit(suit) => {
+                self.paint(suit, self.card_face.get_suit_name(suit).to_string())
+            }
+            ContractKind::NoTrumps => self.language.no_trumps().to_string(),
+            ContractKind::AllTrumps => self.language.all_trumps().to_string(),
+        }
+    }
+
+    pub fn get_bid_name(&self, bid: &Bid) -> String {
+        match bid {
+            Bid::Pass => self.language.pass().to_string(),
+            Bid::Contract(kind) => self.get_contract_name(kind),
+            Bid::Double => self.language.kontra().to_string(),
+            Bid::Redouble => self.language.rekontra().to_string(),
+        }
+    }
+
+    /// Finds the contract a person typed, by its shown name, its suit
+    /// symbol or its name in round records, ignoring case.
+    pub fn parse_contract(&self, input: &str, kinds: &[ContractKind]) -> Option<ContractKind> {
+        let input = input.trim().to_lowercase();
+        kinds
+            .iter()
+            .find(|kind| {
+                let mut names = vec![
+                    kind.get_name().to_lowercase(),
+                    Self::default().get_contract_name(kind).to_lowercase(),
+                    Renderer {
+                        colors: false,
+                        ..*self
+                    }
+                    .get_contract_name(kind)
+                    .to_lowercase(),
+                ];
+                if let ContractKind::Suit(suit) = kind {
+                    names.push(get_suit_symbol(suit).to_string());
+                }
+                names.contains(&input)
+            })
+            .cloned()
+    }
+}
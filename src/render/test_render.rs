@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use strum::IntoEnumIterator;
+
+    use crate::game::auction::Bid;
+    use crate::game::deck::{Card, CardSuit, CardValue};
+    use crate::game::trump::ContractKind;
+    use crate::render::{CardFace, Language, Renderer};
+
+    #[test]
+    fn test_every_face_names_cards_apart() {
+        for card_face in CardFace::iter() {
+            let renderer = Renderer {
+                card_face,
+                ..Renderer::default()
+            };
+            let names = CardSuit::iter()
+                .flat_map(|suit| CardValue::iter().map(move |value| Card::new(suit.clone(), value)))
+                .map(|card| renderer.get_card_name(&card))
+                .collect::<HashSet<_>>();
+
+            assert_eq!(names.len(), 32, "{:?}", card_face);
+        }
+    }
+
+    #[test]
+    fn test_croatian_names() {
+        let card = Card::new(CardSuit::Acorn, CardValue::Queen);
+        let get_name = |card_face| {
+            Renderer {
+                card_face,
+                ..Renderer::default()
+            }
+            .get_card_name(&card)
+        };
+
+        assert_eq!(get_name(CardFace::Deck), "Acorn Queen");
+        assert_eq!(get_name(CardFace::CroatianFrench), "Tref Dama");
+        assert_eq!(get_name(CardFace::CroatianGerman), "Žir Baba");
+        assert_eq!(get_name(CardFace::English), "Clubs Queen");
+    }
+
+    #[test]
+    fn test_symbols_and_colors() {
+        let renderer = Renderer {
+            card_face: CardFace::English,
+            colors: true,
+            symbols: true,
+            ..Renderer::default()
+        };
+        let card = Card::new(CardSuit::Herz, CardValue::Kec);
+
+        assert_eq!(renderer.get_card_name(&card), "\x1b[31m♥ Ace\x1b[0m");
+    }
+
+    #[test]
+    fn test_localized_pass() {
+        let renderer = Renderer {
+            language: Language::Croatian,
+            ..Renderer::default()
+        };
+
+        assert_eq!(renderer.get_bid_name(&Bid::Pass), "Dalje");
+        assert_eq!(renderer.get_bid_name(&Bid::Double), "Kontra");
+        assert_eq!(Renderer::default().get_bid_name(&Bid::Redouble), "Redouble");
+        assert_eq!(
+            renderer
+                .language
+                .calls("a", &renderer.get_contract_name(&ContractKind::NoTrumps)),
+            "a zove Bez aduta"
+        );
+        assert!(renderer.language.is_yes("d"));
+        assert!(!renderer.language.is_yes("y"));
+        assert!(Language::English.is_yes("Yes"));
+        assert!(!Language::English.is_yes(""));
+    }
+
+    #[test]
+    fn test_parse_contract() {
+        let renderer = Renderer {
+            card_face: CardFace::CroatianFrench,
+            colors: true,
+            ..Renderer::default()
+        };
+        let kinds = [
+            ContractKind::Suit(CardSuit::Herz),
+            ContractKind::Suit(CardSuit::Acorn),
+        ];
+
+        for input in ["herz", "Herc", "♥ "] {
+            assert_eq!(
+                renderer.parse_contract(input, &kinds),
+                Some(ContractKind::Suit(CardSuit::Herz))
+            );
+        }
+        assert_eq!(
+            renderer.parse_contract("tref", &kinds),
+            Some(ContractKind::Suit(CardSuit::Acorn))
+        );
+        assert_eq!(renderer.parse_contract("notrumps", &kinds), None);
+    }
+}
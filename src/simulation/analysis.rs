@@ -0,0 +1,322 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::{
+    clients::replay_round_player::ReplayRoundPlayer,
+    game::{
+        auction::{Bid, get_all_contract_kinds},
+        deck::{Card, CardSuit},
+        declaration::{Declaration, DeclaratonWithPlayerInfo, get_possible_declarations},
+        player::Hand,
+        record::RoundRecord,
+        round::Round,
+        round_observer::NullObserver,
+        round_player::RoundPlayer,
+        solver::DoubleDummySolver,
+        table::Seat,
+        trump::ContractKind,
+    },
+};
+
+use super::{
+    hint::{get_contract_hints, sample_round},
+    stats::Mean,
+};
+
+/// Deals sampled for every decision when nothing else is chosen.
+pub const DEFAULT_ANALYSIS_SAMPLES: usize = 20;
+/// Decisions that lose at least this many expected points are mistakes,
+/// smaller losses are within the noise of the sampling.
+pub const MISTAKE_THRESHOLD: f64 = 2.0;
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DecisionKind {
+    Contract {
+        called: ContractKind,
+        best: ContractKind,
+    },
+    /// Declaration the player held but didn't call.
+    Declaration(Declaration),
+    /// Bela the player held but didn't announce.
+    Bela,
+    Card {
+        trick_index: usize,
+        played: Card,
+        best: Card,
+    },
+}
+
+/// One decision of a player and how many points it cost their team
+/// compared to the best option.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decision {
+    pub seat: Seat,
+    pub kind: DecisionKind,
+    pub points_lost: f64,
+}
+
+impl Decision {
+    pub fn is_mistake(&self) -> bool {
+        self.points_lost >= MISTAKE_THRESHOLD
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RoundReport {
+    pub decisions: Vec<Decision>,
+}
+
+impl RoundReport {
+    /// Points every seat lost over the whole round.
+    pub fn get_points_lost(&self, number_of_players: usize) -> Vec<f64> {
+        let mut points_lost = vec![0.0; number_of_players];
+        for decision in &self.decisions {
+            points_lost[decision.seat.index()] += decision.points_lost;
+        }
+        points_lost
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AnalysisConfig {
+    /// Deals sampled from the acting player's information for every
+    /// decision.
+    pub samples: usize,
+    pub seed: u64,
+}
+
+/// Round as the acting seat saw it right before playing a card.
+struct CardPlay {
+    round_state: Round,
+    seat: Seat,
+    available_cards: Vec<Card>,
+}
+
+/// Replays a record and keeps a copy of the round before every card play.
+struct SnapshotRoundPlayer {
+    replay: ReplayRoundPlayer,
+    card_plays: Rc<RefCell<Vec<CardPlay>>>,
+}
+
+impl RoundPlayer for SnapshotRoundPlayer {
+    fn try_call_trump(&self, round_state: &Round, seat: Seat) -> Option<CardSuit> {
+        self.replay.try_call_trump(round_state, seat)
+    }
+
+    fn must_call_trump(&self, round_state: &Round, seat: Seat) -> CardSuit {
+        self.replay.must_call_trump(round_state, seat)
+    }
+
+    fn try_call_contract(&self, round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        self.replay.try_call_contract(round_state, seat)
+    }
+
+    fn must_call_contract(&self, round_state: &Round, seat: Seat) -> ContractKind {
+        self.replay.must_call_contract(round_state, seat)
+    }
+
+    fn make_bid(&self, round_state: &Round, seat: Seat, valid_bids: Vec<Bid>) -> Bid {
+        self.replay.make_bid(round_state, seat, valid_bids)
+    }
+
+    fn play_card(&self, round_state: &Round, seat: Seat, available_cards: Vec<Card>) -> Card {
+        self.card_plays.borrow_mut().push(CardPlay {
+            round_state: round_state.clone(),
+            seat,
+            available_cards: available_cards.clone(),
+        });
+        self.replay.play_card(round_state, seat, available_cards)
+    }
+
+    fn call_declaration(&self, round_state: &Round, seat: Seat, declaration: &Declaration) -> bool {
+        self.replay.call_declaration(round_state, seat, declaration)
+    }
+
+    fn will_declare_bella(&self, round_state: &Round, seat: Seat) -> bool {
+        self.replay.will_declare_bella(round_state, seat)
+    }
+
+    fn will_call_kontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.replay.will_call_kontra(round_state, seat)
+    }
+
+    fn will_call_rekontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.replay.will_call_rekontra(round_state, seat)
+    }
+}
+
+fn replay(record: &RoundRecord) -> Round {
+    let mut round = Round::from_deal(&record.table_config, &record.deal);
+    round.play_round(
+        Box::new(ReplayRoundPlayer::new(record.clone())),
+        &NullObserver,
+    );
+    round
+}
+
+fn get_round_points(record: &RoundRecord, seat: Seat) -> f64 {
+    let round = replay(record);
+    let team = round.players.get_team(seat);
+    round.get_result().points.get_points(team) as f64
+}
+
+fn get_card_plays(record: &RoundRecord) -> Vec<CardPlay> {
+    let card_plays = Rc::new(RefCell::new(vec![]));
+    let round_player = SnapshotRoundPlayer {
+        replay: ReplayRoundPlayer::new(record.clone()),
+        card_plays: card_plays.clone(),
+    };
+    let mut round = Round::from_deal(&record.table_config, &record.deal);
+    round.play_round(Box::new(round_player), &NullObserver);
+
+    card_plays.take()
+}
+
+/// Compares the trump call with every other contract the caller could
+/// have called, nothing is returned for auctions.
+pub fn analyze_contract(
+    record: &RoundRecord,
+    config: AnalysisConfig,
+    rng: &mut StdRng,
+) -> Option<Decision> {
+    let rules = &record.table_config.rules;
+    // contracts of an auction depend on every bid, not on one call
+    if rules.auction {
+        return None;
+    }
+    let seat = record.trump.seat;
+    let round_state = Round::from_deal(&record.table_config, &record.deal);
+    let contracts = get_all_contract_kinds()
+        .into_iter()
+        .filter(|kind| rules.extended_contracts || matches!(kind, ContractKind::Suit(_)))
+        .collect::<Vec<_>>();
+    let hints = get_contract_hints(
+        &round_state,
+        seat,
+        &record.deal.hands[seat.index()],
+        &contracts,
+        config.samples,
+        rng,
+    );
+    let best = hints
+        .iter()
+        .max_by(|first, second| first.points.get_mean().total_cmp(&second.points.get_mean()))?;
+    let called = hints.iter().find(|hint| hint.option == record.trump.kind)?;
+
+    Some(Decision {
+        seat,
+        kind: DecisionKind::Contract {
+            called: called.option.clone(),
+            best: best.option.clone(),
+        },
+        points_lost: best.points.get_mean() - called.points.get_mean(),
+    })
+}
+
+/// Declarations and bela the players held but didn't call.
+pub fn analyze_declarations(record: &RoundRecord) -> Vec<Decision> {
+    let mut decisions = vec![];
+    for (index, hand) in record.deal.hands.iter().enumerate() {
+        let seat = Seat::new(index);
+        let points = get_round_points(record, seat);
+        let missed_declarations = get_possible_declarations(&Hand::new(hand.clone()))
+            .into_iter()
+            .filter(|declaration| {
+                !record
+                    .declarations
+                    .iter()
+                    .any(|info| info.seat == seat && info.declaration == *declaration)
+            });
+        for declaration in missed_declarations {
+            let mut declared = record.clone();
+            declared.declarations.push(DeclaratonWithPlayerInfo {
+                declaration: declaration.clone(),
+                seat,
+            });
+            decisions.push(Decision {
+                seat,
+                kind: DecisionKind::Declaration(declaration),
+                points_lost: get_round_points(&declared, seat) - points,
+            });
+        }
+
+        if !record.bela_seats.contains(&seat) {
+            let mut announced = record.clone();
+            announced.bela_seats.push(seat);
+            decisions.push(Decision {
+                seat,
+                kind: DecisionKind::Bela,
+                points_lost: get_round_points(&announced, seat) - points,
+            });
+        }
+    }
+
+    // bela or declarations that the rules never offered change nothing
+    decisions.retain(|decision| decision.points_lost > 0.0);
+    decisions
+}
+
+fn analyze_card_play(
+    card_play: &CardPlay,
+    played: &Card,
+    config: AnalysisConfig,
+    rng: &mut StdRng,
+) -> Option<Decision> {
+    let CardPlay {
+        round_state,
+        seat,
+        available_cards,
+    } = card_play;
+    if available_cards.len() < 2 {
+        return None;
+    }
+    let team = round_state.players.get_team(*seat);
+    let hand = round_state.get_player(*seat).hand.cards().clone();
+    let mut points = vec![Mean::default(); available_cards.len()];
+    for _ in 0..config.samples {
+        let sampled = sample_round(round_state, *seat, &hand, rng);
+        for solved_card in DoubleDummySolver::new(&sampled, team).solve_cards() {
+            let index = available_cards
+                .iter()
+                .position(|card| *card == solved_card.card)?;
+            points[index].add(solved_card.points);
+        }
+    }
+    let best_index = (0..points.len()).max_by(|first, second| {
+        points[*first]
+            .get_mean()
+            .total_cmp(&points[*second].get_mean())
+    })?;
+    let played_index = available_cards.iter().position(|card| card == played)?;
+
+    Some(Decision {
+        seat: *seat,
+        kind: DecisionKind::Card {
+            trick_index: round_state.trick_history.len(),
+            played: played.clone(),
+            best: available_cards[best_index].clone(),
+        },
+        points_lost: points[best_index].get_mean() - points[played_index].get_mean(),
+    })
+}
+
+/// Analyzes the decisions of every player in a recorded round. Card play
+/// is solved double dummy on deals sampled from what the player knew, so a
+/// card is only a mistake if it was worse on the cards the player could
+/// expect. The trump call is compared with the other contracts by
+/// simulating the round with heuristic bots, and declarations or bela that
+/// weren't called are scored by replaying the round with them.
+pub fn analyze_round(record: &RoundRecord, config: AnalysisConfig) -> RoundReport {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut decisions = vec![];
+    decisions.extend(analyze_contract(record, config, &mut rng));
+    decisions.extend(analyze_declarations(record));
+    for (card_play, (_, played)) in get_card_plays(record).iter().zip(&record.plays) {
+        decisions.extend(analyze_card_play(card_play, played, config, &mut rng));
+    }
+
+    RoundReport { decisions }
+}
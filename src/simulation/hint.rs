@@ -0,0 +1,279 @@
+use std::cell::RefCell;
+
+use rand::{Rng, seq::SliceRandom};
+
+use crate::{
+    clients::heuristic_round_player::HeuristicRoundPlayer,
+    game::{
+        deck::{Card, CardSuit, Deck},
+        declaration::Declaration,
+        player::Hand,
+        round::Round,
+        round_observer::NullObserver,
+        round_player::RoundPlayer,
+        table::Seat,
+        trump::ContractKind,
+    },
+};
+
+use super::stats::Mean;
+
+/// Deals simulated for a hint when nothing else is chosen.
+pub const DEFAULT_HINT_SAMPLES: usize = 200;
+/// Deals that try to respect the suits players are known to be out of,
+/// after that the voids are ignored so sampling always ends.
+const MAX_SAMPLE_ATTEMPTS: usize = 50;
+
+/// Expected points of the asking player's team when they choose `option`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hint<T> {
+    pub option: T,
+    pub points: Mean,
+}
+
+/// Plays every seat with the heuristic bot, except for the one decision
+/// the hint is about.
+struct HintRoundPlayer {
+    seat: Seat,
+    contract: Option<ContractKind>,
+    card: RefCell<Option<Card>>,
+    bot: HeuristicRoundPlayer,
+}
+
+impl HintRoundPlayer {
+    fn new(seat: Seat, contract: Option<ContractKind>, card: Option<Card>) -> Self {
+        Self {
+            seat,
+            contract,
+            card: RefCell::new(card),
+            bot: HeuristicRoundPlayer,
+        }
+    }
+
+    /// Everybody before the asking player passes, since they did so in
+    /// the real round.
+    fn get_contract(&self, seat: Seat) -> Option<Option<ContractKind>> {
+        let contract = self.contract.as_ref()?;
+        Some((seat == self.seat).then(|| contract.clone()))
+    }
+}
+
+impl RoundPlayer for HintRoundPlayer {
+    fn try_call_trump(&self, round_state: &Round, seat: Seat) -> Option<CardSuit> {
+        match self.get_contract(seat) {
+            Some(Some(ContractKind::Suit(suit))) => Some(suit),
+            Some(_) => None,
+            None => self.bot.try_call_trump(round_state, seat),
+        }
+    }
+
+    fn must_call_trump(&self, round_state: &Round, seat: Seat) -> CardSuit {
+        match self.get_contract(seat) {
+            Some(Some(ContractKind::Suit(suit))) => suit,
+            _ => self.bot.must_call_trump(round_state, seat),
+        }
+    }
+
+    fn try_call_contract(&self, round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        match self.get_contract(seat) {
+            Some(contract) => contract,
+            None => self.bot.try_call_contract(round_state, seat),
+        }
+    }
+
+    fn must_call_contract(&self, round_state: &Round, seat: Seat) -> ContractKind {
+        match self.get_contract(seat) {
+            Some(Some(contract)) => contract,
+            _ => self.bot.must_call_contract(round_state, seat),
+        }
+    }
+
+    fn play_card(&self, round_state: &Round, seat: Seat, available_cards: Vec<Card>) -> Card {
+        if seat == self.seat
+            && let Some(card) = self.card.borrow_mut().take()
+        {
+            return card;
+        }
+        self.bot.play_card(round_state, seat, available_cards)
+    }
+
+    fn call_declaration(&self, round_state: &Round, seat: Seat, declaration: &Declaration) -> bool {
+        self.bot.call_declaration(round_state, seat, declaration)
+    }
+
+    fn will_declare_bella(&self, round_state: &Round, seat: Seat) -> bool {
+        self.bot.will_declare_bella(round_state, seat)
+    }
+
+    fn will_call_kontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.bot.will_call_kontra(round_state, seat)
+    }
+
+    fn will_call_rekontra(&self, round_state: &Round, seat: Seat) -> bool {
+        self.bot.will_call_rekontra(round_state, seat)
+    }
+}
+
+/// Suits every seat has shown to be out of by not following the lead.
+fn get_void_suits(round_state: &Round) -> Vec<Vec<CardSuit>> {
+    let number_of_players = round_state.get_number_of_players();
+    let mut void_suits = vec![vec![]; number_of_players];
+    let tricks = round_state
+        .trick_history
+        .iter()
+        .map(|trick_item| &trick_item.trick)
+        .chain([&round_state.current_trick]);
+    for trick in tricks {
+        let Some(lead) = trick.cards_on_table.first() else {
+            continue;
+        };
+        for (index, card) in trick.cards_on_table.iter().enumerate() {
+            let seat = trick.get_first_seat().offset(index, number_of_players);
+            if card.suit != lead.suit && !void_suits[seat.index()].contains(&lead.suit) {
+                void_suits[seat.index()].push(lead.suit.clone());
+            }
+        }
+    }
+
+    void_suits
+}
+
+/// Hands for every seat drawn from the shuffled pool on top of the cards
+/// the seat is known to hold, the rest of the pool is the talon.
+fn deal_pool(
+    pool: &[Card],
+    known_cards: &[Vec<Card>],
+    hand_sizes: &[usize],
+    void_suits: Option<&[Vec<CardSuit>]>,
+) -> Option<(Vec<Vec<Card>>, Vec<Card>)> {
+    let mut remaining = pool.to_vec();
+    let mut hands = vec![];
+    for (index, known) in known_cards.iter().enumerate() {
+        let mut hand = known.clone();
+        let missing = hand_sizes[index].checked_sub(hand.len())?;
+        for _ in 0..missing {
+            let position = remaining.iter().position(|card| {
+                void_suits.is_none_or(|void_suits| !void_suits[index].contains(&card.suit))
+            })?;
+            hand.push(remaining.remove(position));
+        }
+        hands.push(hand);
+    }
+
+    Some((hands, remaining))
+}
+
+/// Copy of the round where the cards `seat` can't see are dealt at random,
+/// consistent with everything the seat knows: its own `known_hand`, the
+/// played cards, the declared cards and the suits others didn't follow.
+pub fn sample_round(
+    round_state: &Round,
+    seat: Seat,
+    known_hand: &[Card],
+    rng: &mut impl Rng,
+) -> Round {
+    let number_of_players = round_state.get_number_of_players();
+    let view = round_state.get_player_view(seat);
+    let mut known_cards = vec![vec![]; number_of_players];
+    known_cards[seat.index()] = known_hand.to_vec();
+    for (known_seat, card) in view.get_known_cards() {
+        known_cards[known_seat.index()].push(card);
+    }
+    let mut pool = Deck::new()
+        .deck
+        .into_iter()
+        .filter(|card| {
+            !view.is_card_played(card) && !known_cards.iter().flatten().any(|known| known == card)
+        })
+        .collect::<Vec<_>>();
+    let hand_sizes = (0..number_of_players)
+        .map(|index| round_state.get_player(Seat::new(index)).hand.cards().len())
+        .collect::<Vec<_>>();
+    let void_suits = get_void_suits(round_state);
+
+    let mut deal = None;
+    for attempt in 0..MAX_SAMPLE_ATTEMPTS {
+        pool.shuffle(rng);
+        let void_suits = (attempt + 1 < MAX_SAMPLE_ATTEMPTS).then_some(void_suits.as_slice());
+        deal = deal_pool(&pool, &known_cards, &hand_sizes, void_suits);
+        if deal.is_some() {
+            break;
+        }
+    }
+    let (hands, talon) = deal.expect("unseen cards should fill the hands");
+
+    let mut sampled = round_state.clone();
+    for (index, hand) in hands.into_iter().enumerate() {
+        let player = sampled
+            .players
+            .get_mut(Seat::new(index))
+            .expect("seat should be valid index");
+        player.hand = Hand::new(hand);
+    }
+    sampled.players.sort_hands();
+    sampled.talon = talon;
+
+    sampled
+}
+
+/// Expected points for every playable card of the seat on turn, every
+/// card is tried on the same sampled deals.
+pub fn get_card_hints(
+    round_state: &Round,
+    seat: Seat,
+    playable_cards: &[Card],
+    number_of_samples: usize,
+    rng: &mut impl Rng,
+) -> Vec<Hint<Card>> {
+    let team = round_state.players.get_team(seat);
+    let known_hand = round_state.get_player(seat).hand.cards().clone();
+    let mut hints = playable_cards
+        .iter()
+        .map(|card| Hint {
+            option: card.clone(),
+            points: Mean::default(),
+        })
+        .collect::<Vec<_>>();
+    for _ in 0..number_of_samples {
+        let sampled = sample_round(round_state, seat, &known_hand, rng);
+        for hint in &mut hints {
+            let mut round = sampled.clone();
+            let round_player = HintRoundPlayer::new(seat, None, Some(hint.option.clone()));
+            round.finish_round(&round_player, &NullObserver);
+            hint.points.add(round.get_result().points.get_points(team));
+        }
+    }
+
+    hints
+}
+
+/// Expected points for every contract the seat could call now, seeing
+/// only `shown_cards` of its hand.
+pub fn get_contract_hints(
+    round_state: &Round,
+    seat: Seat,
+    shown_cards: &[Card],
+    contracts: &[ContractKind],
+    number_of_samples: usize,
+    rng: &mut impl Rng,
+) -> Vec<Hint<ContractKind>> {
+    let team = round_state.players.get_team(seat);
+    let mut hints = contracts
+        .iter()
+        .map(|contract| Hint {
+            option: contract.clone(),
+            points: Mean::default(),
+        })
+        .collect::<Vec<_>>();
+    for _ in 0..number_of_samples {
+        let sampled = sample_round(round_state, seat, shown_cards, rng);
+        for hint in &mut hints {
+            let mut round = sampled.clone();
+            let round_player = HintRoundPlayer::new(seat, Some(hint.option.clone()), None);
+            round.play_round(Box::new(round_player), &NullObserver);
+            hint.points.add(round.get_result().points.get_points(team));
+        }
+    }
+
+    hints
+}
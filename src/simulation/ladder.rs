@@ -0,0 +1,162 @@
+use std::{error::Error, fmt};
+
+/// Rating every agent starts with.
+pub const INITIAL_RATING: f64 = 1500.0;
+/// Most rating points a single pairing in a match can move.
+const K_FACTOR: f64 = 24.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rating {
+    pub name: String,
+    pub rating: f64,
+    pub matches: usize,
+    pub wins: usize,
+}
+
+impl Rating {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            rating: INITIAL_RATING,
+            matches: 0,
+            wins: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LadderError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for LadderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ladder line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for LadderError {}
+
+/// Elo ratings of named agents. Every match is scored as a game between
+/// every pair of agents in it, so the ladder works for three player matches
+/// too. The ladder is stored as plain text with one agent per line.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ladder {
+    ratings: Vec<Rating>,
+}
+
+impl Ladder {
+    pub fn parse(text: &str) -> Result<Self, LadderError> {
+        let mut ladder = Self::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let error = |message: &str| LadderError {
+                line: index + 1,
+                message: message.to_string(),
+            };
+            let parts = line.split_whitespace().collect::<Vec<_>>();
+            let [keyword, name, rating, matches, wins] = parts[..] else {
+                return Err(error("expected `agent name rating matches wins`"));
+            };
+            if keyword != "agent" {
+                return Err(error(&format!("unknown keyword `{}`", keyword)));
+            }
+            if ladder.find(name).is_some() {
+                return Err(error(&format!("agent `{}` is listed twice", name)));
+            }
+            ladder.ratings.push(Rating {
+                name: name.to_string(),
+                rating: rating
+                    .parse()
+                    .map_err(|_| error("rating is not a number"))?,
+                matches: matches
+                    .parse()
+                    .map_err(|_| error("matches is not a number"))?,
+                wins: wins.parse().map_err(|_| error("wins is not a number"))?,
+            });
+        }
+
+        Ok(ladder)
+    }
+
+    pub fn to_text(&self) -> String {
+        self.ratings
+            .iter()
+            .map(|rating| {
+                format!(
+                    "agent {} {:.2} {} {}\n",
+                    rating.name, rating.rating, rating.matches, rating.wins
+                )
+            })
+            .collect()
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.ratings.iter().position(|rating| rating.name == name)
+    }
+
+    fn get_or_insert(&mut self, name: &str) -> usize {
+        if let Some(index) = self.find(name) {
+            return index;
+        }
+        self.ratings.push(Rating::new(name));
+        self.ratings.len() - 1
+    }
+
+    pub fn get_rating(&self, name: &str) -> f64 {
+        self.find(name)
+            .map_or(INITIAL_RATING, |index| self.ratings[index].rating)
+    }
+
+    /// Updates the ratings with the final points of a match, `names` and
+    /// `points` are in the same order. Agents with the same name play for
+    /// the same entry, so they are not rated against each other.
+    pub fn add_match(&mut self, names: &[String], points: &[usize]) {
+        assert_eq!(names.len(), points.len());
+        let indexes = names
+            .iter()
+            .map(|name| self.get_or_insert(name))
+            .collect::<Vec<_>>();
+        let pairings = names.len().saturating_sub(1).max(1) as f64;
+        let mut changes = vec![0.0; indexes.len()];
+        for first in 0..indexes.len() {
+            for second in first + 1..indexes.len() {
+                if indexes[first] == indexes[second] {
+                    continue;
+                }
+                let score = match points[first].cmp(&points[second]) {
+                    std::cmp::Ordering::Greater => 1.0,
+                    std::cmp::Ordering::Equal => 0.5,
+                    std::cmp::Ordering::Less => 0.0,
+                };
+                let difference =
+                    self.ratings[indexes[second]].rating - self.ratings[indexes[first]].rating;
+                let expected = 1.0 / (1.0 + 10f64.powf(difference / 400.0));
+                let change = K_FACTOR / pairings * (score - expected);
+                changes[first] += change;
+                changes[second] -= change;
+            }
+        }
+
+        let best_points = points.iter().max().copied();
+        for (position, index) in indexes.iter().enumerate() {
+            let rating = &mut self.ratings[*index];
+            rating.rating += changes[position];
+            rating.matches += 1;
+            if Some(points[position]) == best_points {
+                rating.wins += 1;
+            }
+        }
+    }
+
+    /// Ratings from the best agent to the worst.
+    pub fn get_leaderboard(&self) -> Vec<&Rating> {
+        let mut leaderboard = self.ratings.iter().collect::<Vec<_>>();
+        leaderboard.sort_by(|first, second| second.rating.total_cmp(&first.rating));
+        leaderboard
+    }
+}
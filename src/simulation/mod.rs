@@ -0,0 +1,265 @@
+pub mod analysis;
+pub mod hint;
+pub mod ladder;
+pub mod stats;
+pub mod tournament;
+
+#[cfg(test)]
+mod test_simulation;
+
+#[cfg(test)]
+mod test_tournament;
+
+#[cfg(test)]
+mod test_ladder;
+
+#[cfg(test)]
+mod test_hint;
+
+#[cfg(test)]
+mod test_analysis;
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use crate::{
+    clients::seated_round_player::SeatedRoundPlayer,
+    game::{
+        game_match::Match, round::Round, round_observer::NullObserver, round_player::RoundPlayer,
+        table::TableConfig, team::Team,
+    },
+};
+
+use stats::AgentStats;
+
+/// Seeds of two matches are this far apart, so their rounds never share a
+/// deal.
+pub const MATCH_SEED_STRIDE: u64 = 1 << 20;
+
+/// Makes a fresh player for every round, players are created on the thread
+/// that plays the round.
+pub type AgentFactory = fn() -> Box<dyn RoundPlayer>;
+
+#[derive(Debug, Clone)]
+pub struct Agent {
+    pub name: String,
+    pub factory: AgentFactory,
+}
+
+impl Agent {
+    pub fn new(name: &str, factory: AgentFactory) -> Self {
+        Self {
+            name: name.to_string(),
+            factory,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    /// Table of every match, its seed is replaced by `seed`.
+    pub table_config: TableConfig,
+    pub number_of_matches: usize,
+    pub target_points: usize,
+    pub seed: u64,
+    pub threads: usize,
+    /// Plays every match once for each way of seating the agents, so every
+    /// agent gets to play every hand of the same deals.
+    pub duplicate_deals: bool,
+}
+
+/// Agent that plays for `team` when the agents are seated with the given
+/// rotation. Rotation `r` gives the team at index `t` to the agent at index
+/// `(t + r) % number of agents`.
+fn get_agent_index(number_of_agents: usize, teams: &[Team], team: Team, rotation: usize) -> usize {
+    let team_index = teams
+        .iter()
+        .position(|item| *item == team)
+        .expect("team should be at the table");
+
+    (team_index + rotation) % number_of_agents
+}
+
+/// Round player that lets every seat be played by the agent of its team.
+fn get_round_player(
+    agents: &[Agent],
+    table_config: &TableConfig,
+    rotation: usize,
+) -> SeatedRoundPlayer {
+    let teams = table_config.get_teams();
+    let players = table_config
+        .seats
+        .iter()
+        .map(|seat_config| {
+            let agent_index = get_agent_index(agents.len(), &teams, seat_config.team, rotation);
+            (agents[agent_index].factory)()
+        })
+        .collect();
+
+    SeatedRoundPlayer::new(players)
+}
+
+/// Runs every job on one of the threads and returns the results in the
+/// order of the jobs, no matter which thread finished first.
+fn run_in_parallel<Job: Sync, Output: Send>(
+    jobs: &[Job],
+    threads: usize,
+    run_job: impl Fn(&Job) -> Output + Sync,
+) -> Vec<Output> {
+    let next_job = AtomicUsize::new(0);
+    let mut outputs = thread::scope(|scope| {
+        let handles = (0..threads.max(1))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut outputs = vec![];
+                    loop {
+                        let job_index = next_job.fetch_add(1, Ordering::Relaxed);
+                        let Some(job) = jobs.get(job_index) else {
+                            break;
+                        };
+                        outputs.push((job_index, run_job(job)));
+                    }
+                    outputs
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("simulation thread should not panic"))
+            .collect::<Vec<_>>()
+    });
+    outputs.sort_by_key(|(job_index, _)| *job_index);
+
+    outputs.into_iter().map(|(_, output)| output).collect()
+}
+
+/// How a simulation went, overall and match by match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SimulationResult {
+    /// Stats in the order of the agents.
+    pub agent_stats: Vec<AgentStats>,
+    /// Final points of every played match, in the order of the agents.
+    pub match_points: Vec<Vec<usize>>,
+}
+
+/// Match that one of the threads has to play, with the agents seated by
+/// `rotation`.
+#[derive(Debug, Clone, Copy)]
+struct MatchJob {
+    match_index: usize,
+    rotation: usize,
+}
+
+/// Plays agents against each other headlessly, one agent for every team.
+/// Seats are rotated between matches so no agent keeps the better seat.
+pub struct Simulation {
+    config: SimulationConfig,
+    agents: Vec<Agent>,
+}
+
+impl Simulation {
+    pub fn new(config: SimulationConfig, agents: Vec<Agent>) -> Self {
+        assert_eq!(
+            agents.len(),
+            config.table_config.get_teams().len(),
+            "every team should get an agent"
+        );
+        Self { config, agents }
+    }
+
+    fn get_jobs(&self) -> Vec<MatchJob> {
+        let number_of_agents = self.agents.len();
+        (0..self.config.number_of_matches)
+            .flat_map(|match_index| {
+                let rotations = if self.config.duplicate_deals {
+                    0..number_of_agents
+                } else {
+                    let rotation = match_index % number_of_agents;
+                    rotation..rotation + 1
+                };
+                rotations.map(move |rotation| MatchJob {
+                    match_index,
+                    rotation,
+                })
+            })
+            .collect()
+    }
+
+    fn get_agent_index(&self, teams: &[Team], team: Team, rotation: usize) -> usize {
+        get_agent_index(self.agents.len(), teams, team, rotation)
+    }
+
+    fn add_round(&self, stats: &mut [AgentStats], teams: &[Team], rotation: usize, round: &Round) {
+        let points = round.get_result().points;
+        let stigl_team = round.is_stigl();
+        for team in teams {
+            let agent_stats = &mut stats[self.get_agent_index(teams, *team, rotation)];
+            agent_stats.round_points.add(points.get_points(*team));
+            agent_stats.stiglje.add(stigl_team == Some(*team));
+        }
+
+        let caller_team = round.players.get_team(round.trump.seat);
+        stats[self.get_agent_index(teams, caller_team, rotation)]
+            .falls
+            .add(round.has_trump_caller_failed());
+    }
+
+    fn play_match(&self, job: &MatchJob) -> (Vec<AgentStats>, Vec<usize>) {
+        let mut stats = self.get_empty_stats();
+        let seed = self
+            .config
+            .seed
+            .wrapping_add(job.match_index as u64 * MATCH_SEED_STRIDE);
+        let table_config = self.config.table_config.clone().with_seed(Some(seed));
+        let teams = table_config.get_teams();
+        let mut game_match = Match::new(table_config.clone(), self.config.target_points);
+        while !game_match.is_done() {
+            let round_player = get_round_player(&self.agents, &table_config, job.rotation);
+            let round = game_match.play_round(Box::new(round_player), &NullObserver);
+            self.add_round(&mut stats, &teams, job.rotation, &round);
+        }
+
+        let winner = game_match.get_winner();
+        let mut points = vec![0; self.agents.len()];
+        for team in &teams {
+            let agent_index = self.get_agent_index(&teams, *team, job.rotation);
+            stats[agent_index].matches_won.add(winner == Some(*team));
+            points[agent_index] = game_match.points.get_points(*team);
+        }
+
+        (stats, points)
+    }
+
+    fn get_empty_stats(&self) -> Vec<AgentStats> {
+        self.agents
+            .iter()
+            .map(|agent| AgentStats::new(&agent.name))
+            .collect()
+    }
+
+    /// Plays all matches, the result only depends on the seed and not on
+    /// the number of threads.
+    pub fn run(&self) -> SimulationResult {
+        let matches = run_in_parallel(&self.get_jobs(), self.config.threads, |job| {
+            self.play_match(job)
+        });
+
+        let mut agent_stats = self.get_empty_stats();
+        let mut match_points = vec![];
+        for (stats, points) in matches {
+            for (agent_stats, other) in agent_stats.iter_mut().zip(&stats) {
+                agent_stats.merge(other);
+            }
+            match_points.push(points);
+        }
+
+        SimulationResult {
+            agent_stats,
+            match_points,
+        }
+    }
+}
@@ -0,0 +1,119 @@
+/// Normal quantile of the 95% confidence intervals.
+const CONFIDENCE_Z: f64 = 1.96;
+
+/// How often something happened out of a number of tries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Proportion {
+    pub successes: u64,
+    pub trials: u64,
+}
+
+impl Proportion {
+    pub fn add(&mut self, success: bool) {
+        self.trials += 1;
+        if success {
+            self.successes += 1;
+        }
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        self.successes += other.successes;
+        self.trials += other.trials;
+    }
+
+    pub fn get_rate(&self) -> f64 {
+        if self.trials == 0 {
+            return 0.0;
+        }
+        self.successes as f64 / self.trials as f64
+    }
+
+    /// Wilson score interval, it stays inside 0..1 even for rates close to
+    /// the edges and for few trials.
+    pub fn get_confidence_interval(&self) -> (f64, f64) {
+        if self.trials == 0 {
+            return (0.0, 1.0);
+        }
+        let trials = self.trials as f64;
+        let rate = self.get_rate();
+        let z_squared = CONFIDENCE_Z * CONFIDENCE_Z;
+        let denominator = 1.0 + z_squared / trials;
+        let center = (rate + z_squared / (2.0 * trials)) / denominator;
+        let margin = CONFIDENCE_Z
+            * (rate * (1.0 - rate) / trials + z_squared / (4.0 * trials * trials)).sqrt()
+            / denominator;
+
+        ((center - margin).max(0.0), (center + margin).min(1.0))
+    }
+}
+
+/// Running mean of whole numbers. Sums are kept as integers, so merging
+/// results of different threads gives the same numbers in any order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Mean {
+    pub count: u64,
+    pub sum: u64,
+    pub sum_of_squares: u64,
+}
+
+impl Mean {
+    pub fn add(&mut self, value: usize) {
+        let value = value as u64;
+        self.count += 1;
+        self.sum += value;
+        self.sum_of_squares += value * value;
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.sum_of_squares += other.sum_of_squares;
+    }
+
+    pub fn get_mean(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.sum as f64 / self.count as f64
+    }
+
+    /// Half width of the 95% confidence interval of the mean.
+    pub fn get_margin(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        let count = self.count as f64;
+        let mean = self.get_mean();
+        let variance = (self.sum_of_squares as f64 - count * mean * mean) / (count - 1.0);
+
+        CONFIDENCE_Z * (variance.max(0.0) / count).sqrt()
+    }
+}
+
+/// Everything measured for one agent over a simulation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AgentStats {
+    pub name: String,
+    pub matches_won: Proportion,
+    pub round_points: Mean,
+    /// Rounds in which the agent's team called the contract and fell.
+    pub falls: Proportion,
+    /// Rounds in which the agent's team took every trick.
+    pub stiglje: Proportion,
+}
+
+impl AgentStats {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        self.matches_won.merge(&other.matches_won);
+        self.round_points.merge(&other.round_points);
+        self.falls.merge(&other.falls);
+        self.stiglje.merge(&other.stiglje);
+    }
+}
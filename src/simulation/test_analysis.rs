@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use crate::clients::heuristic_round_player::HeuristicRoundPlayer;
+    use crate::game::record::RoundRecord;
+    use crate::game::round::Round;
+    use crate::game::round_observer::NullObserver;
+    use crate::game::rules::RuleSet;
+    use crate::game::table::TableConfig;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::simulation::analysis::{
+        AnalysisConfig, DecisionKind, analyze_contract, analyze_declarations, analyze_round,
+    };
+
+    const CONFIG: AnalysisConfig = AnalysisConfig {
+        samples: 1,
+        seed: 1,
+    };
+
+    fn get_record(seed: u64) -> RoundRecord {
+        let player_names = ["a", "b", "c", "d"].map(String::from).to_vec();
+        let table_config = TableConfig::new(player_names, RuleSet::default()).with_seed(Some(seed));
+        let mut round = Round::new(&table_config);
+        round.play_round(HeuristicRoundPlayer::boxed(), &NullObserver);
+
+        RoundRecord::new(&table_config, &round)
+    }
+
+    #[test]
+    fn test_played_cards_are_never_better_than_the_best() {
+        let record = get_record(3);
+
+        let report = analyze_round(&record, CONFIG);
+
+        let card_decisions = report
+            .decisions
+            .iter()
+            .filter(|decision| matches!(decision.kind, DecisionKind::Card { .. }))
+            .collect::<Vec<_>>();
+        assert!(!card_decisions.is_empty());
+        for decision in &card_decisions {
+            assert!(decision.points_lost >= 0.0);
+            if let DecisionKind::Card { played, best, .. } = &decision.kind
+                && played == best
+            {
+                assert_eq!(decision.points_lost, 0.0);
+            }
+        }
+        let points_lost = report.get_points_lost(4).iter().sum::<f64>();
+        let decisions_lost = report
+            .decisions
+            .iter()
+            .map(|decision| decision.points_lost)
+            .sum::<f64>();
+        assert!((points_lost - decisions_lost).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_skipped_declaration_is_a_mistake() {
+        let mut record = (0..100)
+            .map(get_record)
+            .find(|record| record.declarations.len() == 1)
+            .expect("some deal should have a single declaration");
+        let skipped = record.declarations.remove(0);
+
+        let decisions = analyze_declarations(&record);
+
+        let decision = decisions
+            .iter()
+            .find(|decision| {
+                decision.kind == DecisionKind::Declaration(skipped.declaration.clone())
+            })
+            .expect("skipped declaration should be reported");
+        assert_eq!(decision.seat, skipped.seat);
+        assert!(decision.is_mistake());
+    }
+
+    #[test]
+    fn test_contract_call_is_analyzed() {
+        let record = get_record(5);
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let decision = analyze_contract(&record, CONFIG, &mut rng).unwrap();
+        assert_eq!(decision.seat, record.trump.seat);
+        assert!(matches!(
+            &decision.kind,
+            DecisionKind::Contract { called, .. } if *called == record.trump.kind
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::game::deck::{Card, CardSuit, CardValue};
+    use crate::game::player::Hand;
+    use crate::game::round::Round;
+    use crate::game::rules::RuleSet;
+    use crate::game::table::TableConfig;
+    use crate::game::trump::{ContractKind, Trump};
+    use crate::simulation::hint::{get_card_hints, get_contract_hints, sample_round};
+
+    fn get_suit_cards(suit: CardSuit) -> Vec<Card> {
+        [
+            CardValue::VII,
+            CardValue::VIII,
+            CardValue::IX,
+            CardValue::X,
+            CardValue::Jack,
+            CardValue::Queen,
+            CardValue::King,
+            CardValue::Kec,
+        ]
+        .map(|value| Card::new(suit.clone(), value))
+        .to_vec()
+    }
+
+    // the seat on turn holds every herz card, the others get the rest of
+    // the deck in their original order
+    fn get_round() -> Round {
+        let player_names = ["a", "b", "c", "d"].map(String::from).to_vec();
+        let table_config = TableConfig::new(player_names, RuleSet::default()).with_seed(Some(5));
+        let mut round = Round::new(&table_config);
+        let seat = round.seat_turn;
+        let mut other_cards = round
+            .players
+            .players
+            .iter()
+            .flat_map(|player| player.hand.cards().clone())
+            .filter(|card| card.suit != CardSuit::Herz)
+            .collect::<Vec<_>>();
+        for player in round.players.players.iter_mut() {
+            if player.get_seat() == seat {
+                player.hand = Hand::new(get_suit_cards(CardSuit::Herz));
+            } else {
+                player.hand = Hand::new(other_cards.split_off(other_cards.len() - 8));
+            }
+        }
+
+        round
+    }
+
+    #[test]
+    fn test_sampled_round_keeps_what_the_seat_knows() {
+        let round = get_round();
+        let seat = round.seat_turn;
+        let known_hand = round.get_player(seat).hand.cards().clone();
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..20 {
+            let sampled = sample_round(&round, seat, &known_hand, &mut rng);
+            assert_eq!(sampled.get_player(seat).hand.cards(), &known_hand);
+            let all_cards = sampled
+                .players
+                .players
+                .iter()
+                .flat_map(|player| {
+                    assert_eq!(player.hand.cards().len(), 8);
+                    player.hand.cards().clone()
+                })
+                .collect::<HashSet<_>>();
+            assert_eq!(all_cards.len(), 32);
+        }
+    }
+
+    #[test]
+    fn test_hidden_cards_are_sampled_too() {
+        let round = get_round();
+        let seat = round.seat_turn;
+        let shown_cards = &round.get_player(seat).hand.cards()[..6];
+        let mut rng = StdRng::seed_from_u64(2);
+
+        let hands = (0..20)
+            .map(|_| sample_round(&round, seat, shown_cards, &mut rng))
+            .map(|sampled| sampled.get_player(seat).hand.cards().clone())
+            .collect::<Vec<_>>();
+
+        for hand in &hands {
+            assert_eq!(hand.len(), 8);
+            assert!(shown_cards.iter().all(|card| hand.contains(card)));
+        }
+        assert!(
+            hands
+                .iter()
+                .any(|hand| hand != round.get_player(seat).hand.cards())
+        );
+    }
+
+    #[test]
+    fn test_holding_every_trump_wins_whatever_is_played() {
+        let mut round = get_round();
+        let seat = round.seat_turn;
+        round.trump = Trump {
+            kind: ContractKind::Suit(CardSuit::Herz),
+            seat,
+        };
+        let playable_cards = round.get_player(seat).hand.cards().clone();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let hints = get_card_hints(&round, seat, &playable_cards, 10, &mut rng);
+
+        assert_eq!(hints.len(), 8);
+        for hint in &hints {
+            assert_eq!(hint.points.get_mean(), hints[0].points.get_mean());
+            assert_eq!(hint.points.get_margin(), 0.0);
+        }
+        assert!(hints[0].points.get_mean() > 162.0);
+    }
+
+    #[test]
+    fn test_calling_the_long_suit_is_best() {
+        let round = get_round();
+        let seat = round.seat_turn;
+        let shown_cards = round.get_player(seat).hand.cards().clone();
+        let contracts = [
+            ContractKind::Suit(CardSuit::Herz),
+            ContractKind::Suit(CardSuit::Leaf),
+        ];
+        let mut rng = StdRng::seed_from_u64(4);
+
+        let hints = get_contract_hints(&round, seat, &shown_cards, &contracts, 10, &mut rng);
+
+        assert_eq!(hints.len(), 2);
+        assert!(hints[0].points.get_mean() > hints[1].points.get_mean());
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use crate::simulation::ladder::{INITIAL_RATING, Ladder};
+
+    fn get_names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_winner_gains_what_loser_loses() {
+        let mut ladder = Ladder::default();
+        ladder.add_match(&get_names(&["good", "bad"]), &[1010, 640]);
+
+        let gained = ladder.get_rating("good") - INITIAL_RATING;
+        let lost = INITIAL_RATING - ladder.get_rating("bad");
+        assert!(gained > 0.0);
+        assert!((gained - lost).abs() < 1e-9);
+        assert_eq!(ladder.get_leaderboard()[0].name, "good");
+        assert_eq!(ladder.get_leaderboard()[0].wins, 1);
+    }
+
+    #[test]
+    fn test_same_agent_is_not_rated_against_itself() {
+        let mut ladder = Ladder::default();
+        ladder.add_match(&get_names(&["bot", "bot"]), &[1010, 640]);
+
+        assert_eq!(ladder.get_rating("bot"), INITIAL_RATING);
+        assert_eq!(ladder.get_leaderboard()[0].matches, 2);
+    }
+
+    #[test]
+    fn test_ladder_text_round_trip() {
+        let mut ladder = Ladder::default();
+        ladder.add_match(&get_names(&["a", "b", "c"]), &[1001, 700, 300]);
+        let text = ladder.to_text();
+
+        let parsed = Ladder::parse(&text).unwrap();
+        assert_eq!(parsed.to_text(), text);
+        assert!(parsed.get_rating("b") > parsed.get_rating("c"));
+    }
+
+    #[test]
+    fn test_bad_ladder_line() {
+        let error = Ladder::parse("agent a 1500 1 1\nagent b x 1 0\n").unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use crate::clients::heuristic_round_player::HeuristicRoundPlayer;
+    use crate::clients::random_round_player::RandomRoundPlayer;
+    use crate::game::rules::{GameVariant, RuleSet};
+    use crate::game::table::TableConfig;
+    use crate::simulation::stats::{Mean, Proportion};
+    use crate::simulation::{Agent, Simulation, SimulationConfig};
+
+    fn get_config(rules: RuleSet, threads: usize, duplicate_deals: bool) -> SimulationConfig {
+        let player_names = ["a", "b", "c", "d"]
+            .map(String::from)
+            .into_iter()
+            .take(rules.variant.get_number_of_players())
+            .collect();
+        SimulationConfig {
+            table_config: TableConfig::new(player_names, rules),
+            number_of_matches: 4,
+            target_points: 300,
+            seed: 11,
+            threads,
+            duplicate_deals,
+        }
+    }
+
+    fn get_agents(number_of_agents: usize) -> Vec<Agent> {
+        (0..number_of_agents)
+            .map(|_| Agent::new("heuristic", HeuristicRoundPlayer::boxed))
+            .collect()
+    }
+
+    #[test]
+    fn test_results_do_not_depend_on_threads() {
+        let single = Simulation::new(get_config(RuleSet::default(), 1, false), get_agents(2)).run();
+        let parallel =
+            Simulation::new(get_config(RuleSet::default(), 3, false), get_agents(2)).run();
+
+        assert_eq!(single, parallel);
+        assert_eq!(single.agent_stats[0].matches_won.trials, 4);
+        assert_eq!(single.match_points.len(), 4);
+    }
+
+    #[test]
+    fn test_duplicate_deals_are_symmetric() {
+        let stats = Simulation::new(get_config(RuleSet::default(), 2, true), get_agents(2))
+            .run()
+            .agent_stats;
+
+        // Same agents play both sides of every deal, so they score the same.
+        assert_eq!(stats[0].matches_won.trials, 8);
+        assert_eq!(stats[0].round_points, stats[1].round_points);
+        assert_eq!(stats[0].matches_won, stats[1].matches_won);
+    }
+
+    #[test]
+    fn test_three_player_simulation() {
+        let rules = RuleSet {
+            variant: GameVariant::ThreePlayers,
+            ..RuleSet::default()
+        };
+        let mut agents = get_agents(2);
+        agents.push(Agent::new("random", || Box::new(RandomRoundPlayer)));
+        let stats = Simulation::new(get_config(rules, 2, false), agents)
+            .run()
+            .agent_stats;
+
+        let matches_won: u64 = stats.iter().map(|item| item.matches_won.successes).sum();
+        assert_eq!(matches_won, 4);
+    }
+
+    #[test]
+    fn test_confidence_intervals() {
+        let mut proportion = Proportion::default();
+        for index in 0..100 {
+            proportion.add(index < 50);
+        }
+        let (low, high) = proportion.get_confidence_interval();
+        assert!(low < 0.5 && high > 0.5);
+        assert!((high - low - 0.19).abs() < 0.01);
+
+        let mut mean = Mean::default();
+        for value in [10, 20, 30] {
+            mean.add(value);
+        }
+        assert_eq!(mean.get_mean(), 20.0);
+        assert!((mean.get_margin() - 11.316).abs() < 0.01);
+    }
+}
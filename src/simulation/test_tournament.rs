@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use crate::clients::heuristic_round_player::HeuristicRoundPlayer;
+    use crate::clients::random_round_player::RandomRoundPlayer;
+    use crate::game::rules::RuleSet;
+    use crate::game::table::TableConfig;
+    use crate::simulation::Agent;
+    use crate::simulation::tournament::{Tournament, TournamentConfig, get_imps};
+
+    fn get_config(threads: usize) -> TournamentConfig {
+        let player_names = ["a", "b", "c", "d"].map(String::from).to_vec();
+        TournamentConfig {
+            table_config: TableConfig::new(player_names, RuleSet::default()),
+            number_of_boards: 6,
+            seed: 3,
+            threads,
+        }
+    }
+
+    fn get_agents() -> Vec<Agent> {
+        vec![
+            Agent::new("heuristic", HeuristicRoundPlayer::boxed),
+            Agent::new("random", || Box::new(RandomRoundPlayer)),
+        ]
+    }
+
+    #[test]
+    fn test_imp_scale() {
+        assert_eq!(get_imps(0), 0);
+        assert_eq!(get_imps(9), 0);
+        assert_eq!(get_imps(10), 1);
+        assert_eq!(get_imps(-75), -6);
+        assert_eq!(get_imps(1000), 16);
+    }
+
+    #[test]
+    fn test_same_agents_tie_every_board() {
+        let agents = vec![
+            Agent::new("heuristic", HeuristicRoundPlayer::boxed),
+            Agent::new("heuristic", HeuristicRoundPlayer::boxed),
+        ];
+        let result = Tournament::new(get_config(2), agents.clone()).run();
+        assert_eq!(result, Tournament::new(get_config(1), agents).run());
+
+        for board in &result.boards {
+            assert_eq!(board.points[0], board.points[1]);
+            assert_eq!(board.imps, vec![0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_swings_are_zero_sum() {
+        let result = Tournament::new(get_config(1), get_agents()).run();
+
+        assert_eq!(result.boards.len(), 6);
+        for board in &result.boards {
+            assert_eq!(board.swings[0], -board.swings[1]);
+            assert_eq!(
+                board.swings[0],
+                board.points[0] as isize - board.points[1] as isize
+            );
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::game::{
+    game_match::{DEFAULT_TARGET_POINTS, Match},
+    round::Round,
+    round_observer::NullObserver,
+    table::TableConfig,
+};
+
+use super::{Agent, get_agent_index, get_round_player, run_in_parallel};
+
+/// IMP-like scale for belot points: a board swing is worth as many IMPs as
+/// there are thresholds it reaches, so a lucky big board can't outweigh
+/// many small edges.
+const IMP_THRESHOLDS: [usize; 16] = [
+    10, 20, 30, 40, 50, 70, 90, 110, 130, 160, 190, 230, 270, 320, 380, 450,
+];
+
+pub fn get_imps(swing: isize) -> isize {
+    let imps = IMP_THRESHOLDS
+        .iter()
+        .take_while(|threshold| swing.unsigned_abs() >= **threshold)
+        .count() as isize;
+
+    imps * swing.signum()
+}
+
+#[derive(Debug, Clone)]
+pub struct TournamentConfig {
+    /// Table of every board, its seed is replaced by `seed`.
+    pub table_config: TableConfig,
+    pub number_of_boards: usize,
+    pub seed: u64,
+    pub threads: usize,
+}
+
+/// One deal played once for every seating of the agents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardResult {
+    /// Points every agent scored over all seatings, in the order of the
+    /// agents.
+    pub points: Vec<usize>,
+    /// Points above the average of the other agents. With two agents this
+    /// is the difference between their scores on the same cards.
+    pub swings: Vec<isize>,
+    pub imps: Vec<isize>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AgentStanding {
+    pub name: String,
+    pub points: usize,
+    pub swing: isize,
+    pub imps: isize,
+    pub boards_won: usize,
+    pub boards_lost: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TournamentResult {
+    pub boards: Vec<BoardResult>,
+    pub standings: Vec<AgentStanding>,
+}
+
+/// Duplicate tournament: every board is dealt from the seed and played
+/// with the agents rotated through the teams, so every agent holds every
+/// hand once and the luck of the deal cancels out.
+pub struct Tournament {
+    config: TournamentConfig,
+    agents: Vec<Agent>,
+    /// Only used for its schedule of dealers and seeds, board `i` is the
+    /// `i`-th round of this match.
+    schedule: Match,
+}
+
+impl Tournament {
+    pub fn new(config: TournamentConfig, agents: Vec<Agent>) -> Self {
+        assert!(agents.len() > 1, "a tournament needs at least two agents");
+        assert_eq!(
+            agents.len(),
+            config.table_config.get_teams().len(),
+            "every team should get an agent"
+        );
+        let table_config = config.table_config.clone().with_seed(Some(config.seed));
+        let schedule = Match::new(table_config, DEFAULT_TARGET_POINTS);
+
+        Self {
+            config,
+            agents,
+            schedule,
+        }
+    }
+
+    fn play_board(&self, board_index: usize) -> BoardResult {
+        let number_of_agents = self.agents.len();
+        let table_config = self.schedule.get_round_table_config(board_index);
+        let teams = table_config.get_teams();
+        let mut points = vec![0; number_of_agents];
+        for rotation in 0..number_of_agents {
+            let mut round = Round::new(&table_config);
+            let round_player = get_round_player(&self.agents, &table_config, rotation);
+            round.play_round(Box::new(round_player), &NullObserver);
+
+            let round_points = round.get_result().points;
+            for team in &teams {
+                let agent_index = get_agent_index(number_of_agents, &teams, *team, rotation);
+                points[agent_index] += round_points.get_points(*team);
+            }
+        }
+
+        let total_points: usize = points.iter().sum();
+        let other_agents = number_of_agents as f64 - 1.0;
+        let swings = points
+            .iter()
+            .map(|agent_points| {
+                let others_average = (total_points - agent_points) as f64 / other_agents;
+                (*agent_points as f64 - others_average).round() as isize
+            })
+            .collect::<Vec<_>>();
+        let imps = swings.iter().map(|swing| get_imps(*swing)).collect();
+
+        BoardResult {
+            points,
+            swings,
+            imps,
+        }
+    }
+
+    /// Plays all boards, the result only depends on the seed and not on the
+    /// number of threads.
+    pub fn run(&self) -> TournamentResult {
+        let board_indexes = (0..self.config.number_of_boards).collect::<Vec<_>>();
+        let boards = run_in_parallel(&board_indexes, self.config.threads, |board_index| {
+            self.play_board(*board_index)
+        });
+
+        let mut standings = self
+            .agents
+            .iter()
+            .map(|agent| AgentStanding {
+                name: agent.name.clone(),
+                ..AgentStanding::default()
+            })
+            .collect::<Vec<_>>();
+        for board in &boards {
+            for (index, standing) in standings.iter_mut().enumerate() {
+                standing.points += board.points[index];
+                standing.swing += board.swings[index];
+                standing.imps += board.imps[index];
+                if board.imps[index] > 0 {
+                    standing.boards_won += 1;
+                } else if board.imps[index] < 0 {
+                    standing.boards_lost += 1;
+                }
+            }
+        }
+
+        TournamentResult { boards, standings }
+    }
+}
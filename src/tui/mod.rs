@@ -0,0 +1,288 @@
+mod tui_observer;
+mod tui_round_player;
+mod view;
+
+#[cfg(test)]
+mod test_view;
+
+pub use tui_observer::TuiObserver;
+pub use tui_round_player::TuiRoundPlayer;
+
+use std::{cell::RefCell, process, rc::Rc, time::Duration};
+
+use ratatui::{
+    DefaultTerminal,
+    crossterm::{
+        event::{
+            self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind,
+            MouseButton, MouseEventKind,
+        },
+        execute,
+    },
+    layout::{Position, Rect},
+};
+
+use belot_game_optmal::{
+    game::{
+        deck::Card,
+        round::Round,
+        table::Seat,
+        team::{Team, TeamPoints},
+        trick::TrickHistoryItem,
+    },
+    render::Renderer,
+};
+
+/// Events kept in the event list on the side of the table.
+const MAX_EVENTS: usize = 200;
+
+/// What the person at the terminal is asked to do right now.
+#[derive(Debug, Clone)]
+enum Prompt {
+    /// Pick one of the playable cards from the hand.
+    Card { playable_cards: Vec<Card> },
+    /// Pick one of the answers to a question.
+    Choice {
+        question: String,
+        answers: Vec<String>,
+    },
+    /// Read a message and press any key.
+    Message(String),
+    /// Nothing to do, the bots are playing.
+    Wait,
+}
+
+/// Everything on the screen that isn't part of the round state.
+struct Screen {
+    human_seat: Seat,
+    match_points: Vec<(Team, usize)>,
+    target_points: usize,
+    events: Vec<String>,
+    /// Last finished trick, shown until the next card is played.
+    last_trick: Option<TrickHistoryItem>,
+    /// Cards the player doesn't see yet while the contract is chosen.
+    hidden_cards: Vec<Card>,
+    is_contract_called: bool,
+    prompt: Prompt,
+    selected: usize,
+    /// Screen areas of the cards in the hand or of the answers, used to
+    /// find out what was clicked.
+    option_areas: Vec<Rect>,
+    renderer: Renderer,
+}
+
+impl Screen {
+    fn new(human_seat: Seat, target_points: usize, renderer: Renderer) -> Self {
+        Self {
+            human_seat,
+            match_points: vec![],
+            target_points,
+            events: vec![],
+            last_trick: None,
+            hidden_cards: vec![],
+            is_contract_called: false,
+            prompt: Prompt::Wait,
+            selected: 0,
+            option_areas: vec![],
+            renderer,
+        }
+    }
+}
+
+/// Full screen terminal interface. The agent of the human seat and the
+/// observer share it, one to ask questions and the other to show the round.
+pub struct Tui {
+    terminal: RefCell<DefaultTerminal>,
+    screen: RefCell<Screen>,
+}
+
+impl Tui {
+    /// Takes over the terminal until the returned value is dropped.
+    /// ANSI colors of the renderer are turned off, the interface colors
+    /// cards itself.
+    pub fn start(human_seat: Seat, target_points: usize, renderer: Renderer) -> Rc<Self> {
+        let renderer = Renderer {
+            colors: false,
+            ..renderer
+        };
+        let terminal = ratatui::init();
+        execute!(std::io::stdout(), EnableMouseCapture).expect("terminal should support mouse");
+
+        Rc::new(Self {
+            terminal: RefCell::new(terminal),
+            screen: RefCell::new(Screen::new(human_seat, target_points, renderer)),
+        })
+    }
+
+    pub fn set_match_points(&self, teams: &[Team], points: &TeamPoints) {
+        self.screen.borrow_mut().match_points = teams
+            .iter()
+            .map(|team| (*team, points.get_points(*team)))
+            .collect();
+    }
+
+    /// Forgets everything about the previous round.
+    pub fn start_round(&self) {
+        let mut screen = self.screen.borrow_mut();
+        screen.events.clear();
+        screen.last_trick = None;
+        screen.hidden_cards.clear();
+        screen.is_contract_called = false;
+    }
+
+    fn add_event(&self, event: String) {
+        let mut screen = self.screen.borrow_mut();
+        screen.events.extend(event.lines().map(String::from));
+        let extra_events = screen.events.len().saturating_sub(MAX_EVENTS);
+        screen.events.drain(..extra_events);
+    }
+
+    fn get_renderer(&self) -> Renderer {
+        self.screen.borrow().renderer
+    }
+
+    fn draw(&self, round_state: Option<&Round>) {
+        let mut screen = self.screen.borrow_mut();
+        self.terminal
+            .borrow_mut()
+            .draw(|frame| view::draw(frame, round_state, &mut screen))
+            .expect("terminal should be drawable");
+    }
+
+    fn get_number_of_options(&self, round_state: Option<&Round>) -> usize {
+        let screen = self.screen.borrow();
+        match &screen.prompt {
+            Prompt::Card { .. } => round_state
+                .map(|round_state| round_state.get_player(screen.human_seat).hand.cards().len())
+                .unwrap_or(0),
+            Prompt::Choice { answers, .. } => answers.len(),
+            Prompt::Message(_) | Prompt::Wait => 0,
+        }
+    }
+
+    fn is_enabled(&self, round_state: Option<&Round>, option: usize) -> bool {
+        let screen = self.screen.borrow();
+        match (&screen.prompt, round_state) {
+            (Prompt::Card { playable_cards }, Some(round_state)) => {
+                let hand = round_state.get_player(screen.human_seat).hand.cards();
+                hand.get(option)
+                    .is_some_and(|card| playable_cards.contains(card))
+            }
+            _ => true,
+        }
+    }
+
+    /// Shows the prompt and returns the chosen option once the player picks
+    /// one with the arrows and enter, a number key or the mouse.
+    fn ask(&self, round_state: Option<&Round>, prompt: Prompt) -> usize {
+        self.screen.borrow_mut().prompt = prompt;
+        let number_of_options = self.get_number_of_options(round_state);
+        let first_enabled = (0..number_of_options)
+            .find(|option| self.is_enabled(round_state, *option))
+            .unwrap_or(0);
+        self.screen.borrow_mut().selected = first_enabled;
+
+        loop {
+            self.draw(round_state);
+            let selected = self.screen.borrow().selected;
+            let chosen = match read_event() {
+                Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => quit(),
+                    KeyCode::Enter | KeyCode::Char(' ') => Some(selected),
+                    KeyCode::Left | KeyCode::Up => {
+                        self.move_selection(round_state, number_of_options, -1);
+                        None
+                    }
+                    KeyCode::Right | KeyCode::Down | KeyCode::Tab => {
+                        self.move_selection(round_state, number_of_options, 1);
+                        None
+                    }
+                    KeyCode::Char(digit) => digit
+                        .to_digit(10)
+                        .and_then(|digit| (digit as usize).checked_sub(1)),
+                    _ => None,
+                },
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                    let position = Position::new(mouse.column, mouse.row);
+                    self.screen
+                        .borrow()
+                        .option_areas
+                        .iter()
+                        .position(|area| area.contains(position))
+                }
+                _ => None,
+            };
+
+            if let Some(option) = chosen
+                && option < number_of_options
+                && self.is_enabled(round_state, option)
+            {
+                self.screen.borrow_mut().prompt = Prompt::Wait;
+                return option;
+            }
+        }
+    }
+
+    fn move_selection(&self, round_state: Option<&Round>, number_of_options: usize, step: isize) {
+        if number_of_options == 0 {
+            return;
+        }
+        let mut selected = self.screen.borrow().selected;
+        for _ in 0..number_of_options {
+            selected = (selected as isize + step).rem_euclid(number_of_options as isize) as usize;
+            if self.is_enabled(round_state, selected) {
+                break;
+            }
+        }
+        self.screen.borrow_mut().selected = selected;
+    }
+
+    /// Shows a message until any key is pressed.
+    pub fn show_message(&self, round_state: Option<&Round>, message: &str) {
+        self.screen.borrow_mut().prompt = Prompt::Message(message.to_string());
+        loop {
+            self.draw(round_state);
+            match read_event() {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    if key.code == KeyCode::Char('q') {
+                        quit();
+                    }
+                    break;
+                }
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                    break;
+                }
+                _ => {}
+            }
+        }
+        self.screen.borrow_mut().prompt = Prompt::Wait;
+    }
+
+    /// Shows the round for a moment, any key skips the wait.
+    fn pause(&self, round_state: &Round, duration: Duration) {
+        self.draw(Some(round_state));
+        if event::poll(duration).expect("terminal events should be readable") {
+            let _ = event::read();
+        }
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        restore_terminal();
+    }
+}
+
+fn restore_terminal() {
+    let _ = execute!(std::io::stdout(), DisableMouseCapture);
+    ratatui::restore();
+}
+
+fn read_event() -> Event {
+    event::read().expect("terminal events should be readable")
+}
+
+fn quit() -> ! {
+    restore_terminal();
+    process::exit(0);
+}
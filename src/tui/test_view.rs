@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use ratatui::{Terminal, backend::TestBackend};
+
+    use crate::tui::{Prompt, Screen, view};
+    use belot_game_optmal::game::{
+        round::Round,
+        rules::{GameVariant, RuleSet},
+        table::{Seat, TableConfig},
+    };
+    use belot_game_optmal::render::Renderer;
+
+    fn get_round(rules: RuleSet) -> Round {
+        let player_names = ["a", "b", "c", "d"]
+            .map(String::from)
+            .into_iter()
+            .take(rules.variant.get_number_of_players())
+            .collect();
+        Round::new(&TableConfig::new(player_names, rules))
+    }
+
+    fn draw(round_state: &Round, screen: &mut Screen) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal
+            .draw(|frame| view::draw(frame, Some(round_state), screen))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_every_card_in_hand_can_be_clicked() {
+        let round = get_round(RuleSet::default());
+        let human_seat = Seat::new(2);
+        let hand = round.get_player(human_seat).hand.cards().to_vec();
+        let mut screen = Screen::new(human_seat, 1001, Renderer::default());
+        screen.prompt = Prompt::Card {
+            playable_cards: hand[..1].to_vec(),
+        };
+
+        let text = draw(&round, &mut screen);
+
+        assert_eq!(screen.option_areas.len(), hand.len());
+        assert!(text.contains("c (you)"));
+        assert!(text.contains("TRUMP: ..."));
+    }
+
+    #[test]
+    fn test_answers_can_be_clicked() {
+        let round = get_round(RuleSet::default());
+        let mut screen = Screen::new(Seat::new(0), 1001, Renderer::default());
+        screen.prompt = Prompt::Choice {
+            question: "Call trump".to_string(),
+            answers: ["Herz", "Dalje"].map(String::from).to_vec(),
+        };
+
+        let text = draw(&round, &mut screen);
+
+        assert_eq!(screen.option_areas.len(), 2);
+        assert!(text.contains("2 Dalje"));
+    }
+
+    #[test]
+    fn test_three_players_fit_on_the_table() {
+        let round = get_round(RuleSet {
+            variant: GameVariant::ThreePlayers,
+            ..RuleSet::default()
+        });
+        let mut screen = Screen::new(Seat::new(1), 1001, Renderer::default());
+
+        let text = draw(&round, &mut screen);
+
+        for name in ["a", "b (you)", "c"] {
+            assert!(text.contains(&format!(" {} ", name)));
+        }
+        assert!(screen.option_areas.is_empty());
+    }
+}
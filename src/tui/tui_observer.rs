@@ -0,0 +1,62 @@
+use std::{rc::Rc, time::Duration};
+
+use belot_game_optmal::{
+    clients::log_observer::get_event_description,
+    game::{
+        round::{Round, RoundUpdateEvent},
+        round_observer::RoundObserver,
+    },
+};
+
+use super::Tui;
+
+/// How long a played card stays on the screen before the game goes on.
+const CARD_PAUSE: Duration = Duration::from_millis(500);
+/// How long a finished trick stays on the table.
+const TRICK_PAUSE: Duration = Duration::from_millis(1500);
+/// How long calls like kontra or bela are shown.
+const CALL_PAUSE: Duration = Duration::from_millis(900);
+
+/// Draws the table after every event, with short pauses so the bots don't
+/// play faster than a person can follow. Any key skips a pause.
+pub struct TuiObserver {
+    tui: Rc<Tui>,
+}
+
+impl TuiObserver {
+    pub fn new(tui: Rc<Tui>) -> Self {
+        Self { tui }
+    }
+}
+
+impl RoundObserver for TuiObserver {
+    fn on_update(&self, round_state: &Round, round_event: RoundUpdateEvent) {
+        let pause = match &round_event {
+            RoundUpdateEvent::CardPlayed { .. } => {
+                self.tui.screen.borrow_mut().last_trick = None;
+                CARD_PAUSE
+            }
+            RoundUpdateEvent::TrickDone(trick_item) => {
+                self.tui.screen.borrow_mut().last_trick = Some(trick_item.clone());
+                TRICK_PAUSE
+            }
+            RoundUpdateEvent::TrumpCallEvent { trump: Some(_), .. } => {
+                let mut screen = self.tui.screen.borrow_mut();
+                screen.is_contract_called = true;
+                screen.hidden_cards.clear();
+                CALL_PAUSE
+            }
+            RoundUpdateEvent::Redealt => {
+                self.tui.start_round();
+                CALL_PAUSE
+            }
+            _ => CALL_PAUSE,
+        };
+        self.tui.add_event(get_event_description(
+            &self.tui.get_renderer(),
+            round_state,
+            round_event,
+        ));
+        self.tui.pause(round_state, pause);
+    }
+}
@@ -0,0 +1,165 @@
+use std::rc::Rc;
+
+use rand::seq::IndexedRandom;
+
+use belot_game_optmal::game::{
+    auction::{Bid, get_all_contract_kinds},
+    deck::{Card, CardSuit},
+    declaration::Declaration,
+    round::Round,
+    round_player::RoundPlayer,
+    table::Seat,
+    trump::ContractKind,
+};
+
+use super::{Prompt, Tui};
+
+/// Cards a player doesn't see yet when the trump is called.
+const HIDDEN_CARDS: usize = 2;
+
+/// Lets the person at the terminal decide for their seat.
+pub struct TuiRoundPlayer {
+    tui: Rc<Tui>,
+}
+
+impl TuiRoundPlayer {
+    pub fn boxed(tui: Rc<Tui>) -> Box<dyn RoundPlayer> {
+        Box::new(Self { tui })
+    }
+
+    fn ask_question(&self, round_state: &Round, question: String, answers: &[&str]) -> usize {
+        let prompt = Prompt::Choice {
+            question,
+            answers: answers.iter().map(|answer| answer.to_string()).collect(),
+        };
+        self.tui.ask(Some(round_state), prompt)
+    }
+
+    fn ask_yes_no(&self, round_state: &Round, question: String) -> bool {
+        let language = self.tui.get_renderer().language;
+        self.ask_question(round_state, question, &[language.yes(), language.no()]) == 0
+    }
+
+    /// Hides part of the hand the first time the trump is asked for, like
+    /// the last cards of a real deal that come after the call.
+    fn hide_cards(&self, round_state: &Round, seat: Seat) {
+        let mut screen = self.tui.screen.borrow_mut();
+        if screen.hidden_cards.is_empty() {
+            let cards = round_state.get_player(seat).hand.cards();
+            screen.hidden_cards = cards
+                .choose_multiple(&mut rand::rng(), HIDDEN_CARDS)
+                .cloned()
+                .collect();
+        }
+    }
+
+    fn get_contract_kinds(&self, round_state: &Round) -> Vec<ContractKind> {
+        get_all_contract_kinds()
+            .into_iter()
+            .filter(|kind| {
+                round_state.rules.extended_contracts || matches!(kind, ContractKind::Suit(_))
+            })
+            .collect()
+    }
+
+    fn ask_contract(
+        &self,
+        round_state: &Round,
+        seat: Seat,
+        can_pass: bool,
+    ) -> Option<ContractKind> {
+        self.hide_cards(round_state, seat);
+        let renderer = self.tui.get_renderer();
+        let kinds = self.get_contract_kinds(round_state);
+        let mut answers = kinds
+            .iter()
+            .map(|kind| renderer.get_contract_name(kind))
+            .collect::<Vec<_>>();
+        let question = if can_pass {
+            answers.push(renderer.language.pass().to_string());
+            renderer.language.choose_trump()
+        } else {
+            renderer.language.must_choose_trump()
+        };
+
+        let answers = answers.iter().map(String::as_str).collect::<Vec<_>>();
+        let answer = self.ask_question(round_state, question.to_string(), &answers);
+        kinds.get(answer).cloned()
+    }
+}
+
+impl RoundPlayer for TuiRoundPlayer {
+    fn try_call_trump(&self, round_state: &Round, seat: Seat) -> Option<CardSuit> {
+        match self.ask_contract(round_state, seat, true) {
+            Some(ContractKind::Suit(suit)) => Some(suit),
+            _ => None,
+        }
+    }
+
+    fn must_call_trump(&self, round_state: &Round, seat: Seat) -> CardSuit {
+        match self.ask_contract(round_state, seat, false) {
+            Some(ContractKind::Suit(suit)) => suit,
+            _ => unreachable!("only suits are offered without extended contracts"),
+        }
+    }
+
+    fn try_call_contract(&self, round_state: &Round, seat: Seat) -> Option<ContractKind> {
+        self.ask_contract(round_state, seat, true)
+    }
+
+    fn must_call_contract(&self, round_state: &Round, seat: Seat) -> ContractKind {
+        self.ask_contract(round_state, seat, false)
+            .expect("a contract has to be chosen when passing isn't offered")
+    }
+
+    fn make_bid(&self, round_state: &Round, _seat: Seat, valid_bids: Vec<Bid>) -> Bid {
+        let renderer = self.tui.get_renderer();
+        let answers = valid_bids
+            .iter()
+            .map(|bid| renderer.get_bid_name(bid))
+            .collect::<Vec<_>>();
+        let answers = answers.iter().map(String::as_str).collect::<Vec<_>>();
+        let question = renderer.language.make_bid().to_string();
+        let answer = self.ask_question(round_state, question, &answers);
+
+        valid_bids[answer].clone()
+    }
+
+    fn play_card(&self, round_state: &Round, seat: Seat, available_cards: Vec<Card>) -> Card {
+        let prompt = Prompt::Card {
+            playable_cards: available_cards,
+        };
+        let index = self.tui.ask(Some(round_state), prompt);
+
+        round_state.get_player(seat).hand.cards()[index].clone()
+    }
+
+    fn call_declaration(
+        &self,
+        round_state: &Round,
+        _seat: Seat,
+        declaration: &Declaration,
+    ) -> bool {
+        let renderer = self.tui.get_renderer();
+        let cards = renderer.get_card_names(&declaration.cards);
+        let question = renderer
+            .language
+            .ask_declaration(&cards, declaration.points);
+        self.ask_yes_no(round_state, question)
+    }
+
+    fn will_declare_bella(&self, round_state: &Round, _seat: Seat) -> bool {
+        let question = self.tui.get_renderer().language.ask_bela();
+        self.ask_yes_no(round_state, question.to_string())
+    }
+
+    fn will_call_kontra(&self, round_state: &Round, _seat: Seat) -> bool {
+        let question = self.tui.get_renderer().language.ask_kontra();
+        self.ask_yes_no(round_state, question.to_string())
+    }
+
+    fn will_call_rekontra(&self, round_state: &Round, _seat: Seat) -> bool {
+        let question = self.tui.get_renderer().language.ask_rekontra();
+        self.ask_yes_no(round_state, question.to_string())
+    }
+}
@@ -0,0 +1,397 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Paragraph, Wrap},
+};
+
+use belot_game_optmal::game::{
+    deck::{Card, CardSuit},
+    kontra::Doubling,
+    round::Round,
+    table::Seat,
+};
+
+use super::{Prompt, Screen};
+
+const CARD_WIDTH: u16 = 10;
+const CARD_HEIGHT: u16 = 4;
+
+/// Where a seat sits on the screen, seen from the person at the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SeatPosition {
+    Bottom,
+    Right,
+    Top,
+    Left,
+}
+
+fn get_seat_position(round_state: &Round, human_seat: Seat, seat: Seat) -> SeatPosition {
+    let number_of_players = round_state.get_number_of_players();
+    let offset = (seat.index() + number_of_players - human_seat.index()) % number_of_players;
+    match (number_of_players, offset) {
+        (_, 0) => SeatPosition::Bottom,
+        (_, 1) => SeatPosition::Right,
+        (3, _) => SeatPosition::Left,
+        (_, 2) => SeatPosition::Top,
+        _ => SeatPosition::Left,
+    }
+}
+
+fn get_suit_color(suit: &CardSuit) -> Color {
+    match suit {
+        CardSuit::Herz => Color::Red,
+        CardSuit::Pumpkin => Color::Yellow,
+        CardSuit::Leaf => Color::Green,
+        CardSuit::Acorn => Color::Rgb(181, 101, 29),
+    }
+}
+
+fn get_card_span(screen: &Screen, card: &Card) -> Span<'static> {
+    Span::styled(
+        screen.renderer.get_card_name(card),
+        Style::default()
+            .fg(get_suit_color(&card.suit))
+            .add_modifier(Modifier::BOLD),
+    )
+}
+
+fn draw_header(frame: &mut Frame, area: Rect, round_state: Option<&Round>, screen: &Screen) {
+    let mut spans = vec![];
+    for (team, points) in &screen.match_points {
+        let team_name: &str = (*team).into();
+        let team = screen.renderer.language.team();
+        spans.push(Span::raw(format!("{} {}: {}   ", team, team_name, points)));
+    }
+    spans.push(Span::styled(
+        format!("(playing to {})", screen.target_points),
+        Style::default().fg(Color::DarkGray),
+    ));
+    let mut lines = vec![Line::from(spans)];
+
+    if let Some(round_state) = round_state {
+        let mut spans = vec![];
+        for team in round_state.players.get_teams() {
+            let team_name: &str = team.into();
+            let points = round_state.points.get_points(team)
+                + round_state.team_declarations.get_points_sum(&team);
+            spans.push(Span::raw(format!("Round {}: {}   ", team_name, points)));
+        }
+        if screen.is_contract_called {
+            let caller = round_state.get_player(round_state.trump.seat);
+            spans.push(Span::styled(
+                format!(
+                    "{}: {} ({})",
+                    screen.renderer.language.trump(),
+                    screen.renderer.get_contract_name(&round_state.trump.kind),
+                    caller.name
+                ),
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+            let doubling = match round_state.doubling {
+                Doubling::None => "",
+                Doubling::Kontra => "   KONTRA",
+                Doubling::Rekontra => "   REKONTRA",
+            };
+            spans.push(Span::styled(doubling, Style::default().fg(Color::Red)));
+        } else {
+            spans.push(Span::raw(format!(
+                "{}: ...",
+                screen.renderer.language.trump()
+            )));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(" Belot ")),
+        area,
+    );
+}
+
+fn draw_seat(frame: &mut Frame, area: Rect, round_state: &Round, screen: &Screen, seat: Seat) {
+    let player = round_state.get_player(seat);
+    let team_name: &str = player.get_team().into();
+    let mut lines = vec![Line::raw(format!(
+        "Team {}, {} cards",
+        team_name,
+        player.hand.cards().len()
+    ))];
+    if screen.is_contract_called && round_state.trump.seat == seat {
+        lines.push(Line::styled(
+            "called trump",
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    let is_on_turn = matches!(screen.prompt, Prompt::Wait | Prompt::Card { .. })
+        && screen.last_trick.is_none()
+        && round_state.players.have_cards()
+        && round_state.current_trick.get_seat_turn() == seat;
+    let border_style = if is_on_turn {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default()
+    };
+    let title = if seat == screen.human_seat {
+        format!(" {} (you) ", player.name)
+    } else {
+        format!(" {} ", player.name)
+    };
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(Block::bordered().title(title).border_style(border_style)),
+        area,
+    );
+}
+
+fn draw_trick(frame: &mut Frame, area: Rect, round_state: &Round, screen: &Screen) {
+    let number_of_players = round_state.get_number_of_players();
+    let trick = match &screen.last_trick {
+        Some(trick_item) => &trick_item.trick,
+        None => &round_state.current_trick,
+    };
+    let block = Block::bordered().title(" Trick ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [top, middle, bottom] = Layout::vertical([Constraint::Ratio(1, 3); 3]).areas(inner);
+    let [left, _, right] = Layout::horizontal([Constraint::Ratio(1, 3); 3]).areas(middle);
+    for (index, card) in trick.cards_on_table.iter().enumerate() {
+        let seat = trick.get_first_seat().offset(index, number_of_players);
+        let card_area = match get_seat_position(round_state, screen.human_seat, seat) {
+            SeatPosition::Bottom => bottom,
+            SeatPosition::Right => right,
+            SeatPosition::Top => top,
+            SeatPosition::Left => left,
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(get_card_span(screen, card))).alignment(Alignment::Center),
+            card_area,
+        );
+    }
+
+    if let Some(trick_item) = &screen.last_trick {
+        let winner = round_state.get_player(trick_item.winner_seat);
+        let text = format!("{} takes {} points", winner.name, trick_item.points);
+        frame.render_widget(
+            Paragraph::new(text)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(Color::Cyan)),
+            Layout::vertical([Constraint::Length(1)])
+                .flex(Flex::Center)
+                .split(middle)[0],
+        );
+    }
+}
+
+fn draw_table(frame: &mut Frame, area: Rect, round_state: &Round, screen: &Screen) {
+    let [top, middle, bottom] = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Min(7),
+        Constraint::Length(4),
+    ])
+    .areas(area);
+    let [left, center, right] = Layout::horizontal([
+        Constraint::Length(22),
+        Constraint::Min(30),
+        Constraint::Length(22),
+    ])
+    .areas(middle);
+    let [_, top, _] = Layout::horizontal([
+        Constraint::Min(0),
+        Constraint::Length(26),
+        Constraint::Min(0),
+    ])
+    .areas(top);
+    let [_, bottom, _] = Layout::horizontal([
+        Constraint::Min(0),
+        Constraint::Length(26),
+        Constraint::Min(0),
+    ])
+    .areas(bottom);
+
+    let number_of_players = round_state.get_number_of_players();
+    for index in 0..number_of_players {
+        let seat = Seat::new(index);
+        let seat_area = match get_seat_position(round_state, screen.human_seat, seat) {
+            SeatPosition::Bottom => bottom,
+            SeatPosition::Right => right,
+            SeatPosition::Top => top,
+            SeatPosition::Left => left,
+        };
+        draw_seat(frame, seat_area, round_state, screen, seat);
+    }
+    draw_trick(frame, center, round_state, screen);
+}
+
+fn draw_events(frame: &mut Frame, area: Rect, screen: &Screen) {
+    let visible_lines = area.height.saturating_sub(2) as usize;
+    let skipped_lines = screen.events.len().saturating_sub(visible_lines);
+    let lines = screen.events[skipped_lines..]
+        .iter()
+        .map(|event| Line::raw(event.as_str()))
+        .collect::<Vec<_>>();
+
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(" Events ")),
+        area,
+    );
+}
+
+fn get_card_style(is_enabled: bool, is_selected: bool) -> Style {
+    match (is_enabled, is_selected) {
+        (true, true) => Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+        (true, false) => Style::default().fg(Color::White),
+        (false, _) => Style::default().fg(Color::DarkGray),
+    }
+}
+
+fn draw_hand(frame: &mut Frame, area: Rect, round_state: &Round, screen: &mut Screen) {
+    let cards = round_state.get_player(screen.human_seat).hand.cards();
+    let playable_cards = match &screen.prompt {
+        Prompt::Card { playable_cards } => Some(playable_cards),
+        _ => None,
+    };
+    let mut card_areas = vec![];
+    for (index, card) in cards.iter().enumerate() {
+        let card_area = Rect {
+            x: area.x + index as u16 * (CARD_WIDTH + 1),
+            y: area.y,
+            width: CARD_WIDTH,
+            height: CARD_HEIGHT,
+        }
+        .intersection(area);
+        let is_hidden = screen.hidden_cards.contains(card);
+        let is_enabled = playable_cards.is_none_or(|playable_cards| playable_cards.contains(card));
+        let is_selected = playable_cards.is_some() && screen.selected == index;
+        let style = get_card_style(is_enabled, is_selected);
+
+        let (suit_line, value_line) = if is_hidden {
+            (Line::raw("??"), Line::raw(""))
+        } else {
+            let suit = screen.renderer.get_suit_name(&card.suit);
+            let value = screen.renderer.get_value_name(&card.value);
+            let suit_style = if is_enabled {
+                Style::default().fg(get_suit_color(&card.suit))
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            (Line::styled(suit, suit_style), Line::styled(value, style))
+        };
+        let block = Block::bordered()
+            .title(format!("{}", index + 1))
+            .border_style(style);
+        let block = if is_selected {
+            block.border_type(BorderType::Thick)
+        } else {
+            block
+        };
+        frame.render_widget(
+            Paragraph::new(vec![suit_line, value_line])
+                .alignment(Alignment::Center)
+                .block(block),
+            card_area,
+        );
+        card_areas.push(card_area);
+    }
+
+    if playable_cards.is_some() {
+        screen.option_areas = card_areas;
+    }
+}
+
+fn draw_prompt(frame: &mut Frame, area: Rect, screen: &mut Screen) {
+    let [text_area, answers_area, help_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(3),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+    let help_style = Style::default().fg(Color::DarkGray);
+
+    match &screen.prompt {
+        Prompt::Card { .. } => {
+            frame.render_widget(
+                Paragraph::new(screen.renderer.language.your_turn()),
+                text_area,
+            );
+            frame.render_widget(
+                Paragraph::new("←/→ select   enter play   1-8 pick   click a card   q quit")
+                    .style(help_style),
+                help_area,
+            );
+        }
+        Prompt::Choice { question, answers } => {
+            frame.render_widget(
+                Paragraph::new(question.as_str()).wrap(Wrap { trim: true }),
+                text_area,
+            );
+            let mut x = answers_area.x;
+            let mut answer_areas = vec![];
+            for (index, answer) in answers.iter().enumerate() {
+                let label = format!("{} {}", index + 1, answer);
+                let answer_area = Rect {
+                    x,
+                    y: answers_area.y,
+                    width: label.chars().count() as u16 + 2,
+                    height: answers_area.height,
+                }
+                .intersection(answers_area);
+                x += answer_area.width + 1;
+                let style = get_card_style(true, screen.selected == index);
+                frame.render_widget(
+                    Paragraph::new(label)
+                        .style(style)
+                        .block(Block::bordered().border_style(style)),
+                    answer_area,
+                );
+                answer_areas.push(answer_area);
+            }
+            screen.option_areas = answer_areas;
+            frame.render_widget(
+                Paragraph::new("←/→ select   enter choose   number pick   click   q quit")
+                    .style(help_style),
+                help_area,
+            );
+        }
+        Prompt::Message(message) => {
+            frame.render_widget(
+                Paragraph::new(message.as_str()).wrap(Wrap { trim: true }),
+                text_area.union(answers_area),
+            );
+            frame.render_widget(
+                Paragraph::new("press any key to continue").style(help_style),
+                help_area,
+            );
+        }
+        Prompt::Wait => {
+            frame.render_widget(Paragraph::new("...").style(help_style), text_area);
+        }
+    }
+}
+
+pub fn draw(frame: &mut Frame, round_state: Option<&Round>, screen: &mut Screen) {
+    screen.option_areas.clear();
+    let [header, body, hand, prompt] = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Min(15),
+        Constraint::Length(CARD_HEIGHT),
+        Constraint::Length(5),
+    ])
+    .areas(frame.area());
+    draw_header(frame, header, round_state, screen);
+
+    let [table, events] =
+        Layout::horizontal([Constraint::Min(70), Constraint::Length(40)]).areas(body);
+    draw_events(frame, events, screen);
+    if let Some(round_state) = round_state {
+        draw_table(frame, table, round_state, screen);
+        draw_hand(frame, hand, round_state, screen);
+    }
+    draw_prompt(frame, prompt, screen);
+}
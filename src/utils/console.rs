@@ -1,5 +1,6 @@
+use crate::game::{kontra::Doubling, round::Round};
+use crate::render::Renderer;
 use std::io::{self, Write, stdout};
-use crate::game::{round::Round, team::Team};
 
 pub fn wait_for_std_input() {
     let mut buffer = String::new();
@@ -11,21 +12,36 @@ pub fn clear_console() {
     stdout().flush().unwrap();
 }
 
-pub fn print_current_points(round_state: &Round) {
+pub fn print_current_points(renderer: &Renderer, round_state: &Round) {
     let declarations = &round_state.team_declarations;
-    let team_a_points = round_state.points.get_points(Team::A);
-    let team_b_points = round_state.points.get_points(Team::B);
-    let team_a_declarations = declarations.get_points_sum(&Team::A);
-    let team_b_declarations = declarations.get_points_sum(&Team::B);
-    let team_a_points = team_a_points + team_a_declarations;
-    let team_b_points = team_b_points + team_b_declarations;
+    let team_points = round_state
+        .rules
+        .variant
+        .get_teams()
+        .into_iter()
+        .map(|team| {
+            let points = round_state.points.get_points(team) + declarations.get_points_sum(&team);
+            let team_name: &str = team.into();
+            format!("{} {}: {}", renderer.language.team(), team_name, points)
+        })
+        .collect::<Vec<_>>()
+        .join("    ");
     let trump = &round_state.trump;
-    let trump_player = &round_state.get_player_by_index(trump.player_index).name;
-    let tump_color: &str = trump.trump_suit.clone().into();
+    let trump_player = &round_state.get_player(trump.seat).name;
+    let tump_color = renderer.get_contract_name(&trump.kind);
+    let doubling = match round_state.doubling {
+        Doubling::None => "",
+        Doubling::Kontra => "    KONTRA",
+        Doubling::Rekontra => "    REKONTRA",
+    };
 
     println!(
-        "TEAM A: {}    TEAM B: {}      TRUMP: {} - ({})",
-        team_a_points, team_b_points, tump_color, trump_player
+        "{}      {}: {} - ({}){}",
+        team_points,
+        renderer.language.trump(),
+        tump_color,
+        trump_player,
+        doubling
     );
-    println!("");
+    println!();
 }
@@ -1,2 +1,5 @@
-pub mod random;
 pub mod console;
+pub mod random;
+
+#[cfg(test)]
+mod test_random;
@@ -1,14 +1,76 @@
-use rand::random_range;
+use std::cell::RefCell;
+
+use rand::{
+    Rng, RngCore, SeedableRng,
+    distr::uniform::{SampleRange, SampleUniform},
+    rngs::StdRng,
+};
 use strum::EnumCount;
 
 use crate::game::deck::CardSuit;
 
+thread_local! {
+    static SOURCE: RefCell<Option<Box<dyn RngCore>>> = const { RefCell::new(None) };
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn get_default_source() -> Box<dyn RngCore> {
+    Box::new(rand::rng())
+}
+
+/// There is no OS entropy in the browser, until a source is set the
+/// engine draws from a fixed seed.
+#[cfg(target_arch = "wasm32")]
+fn get_default_source() -> Box<dyn RngCore> {
+    Box::new(StdRng::seed_from_u64(0))
+}
+
+/// Replaces the randomness of the current thread. Unseeded deals and the
+/// bots draw from it, by default it is OS entropy.
+pub fn set_random_source(source: Box<dyn RngCore>) {
+    SOURCE.with_borrow_mut(|current| *current = Some(source));
+}
+
+/// Makes the randomness of the current thread reproducible.
+pub fn set_random_seed(seed: u64) {
+    set_random_source(Box::new(StdRng::seed_from_u64(seed)));
+}
+
+/// Rng that draws from the randomness of the current thread, see
+/// [`set_random_source`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomSource;
+
+impl RandomSource {
+    fn with_source<T>(self, draw: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+        SOURCE
+            .with_borrow_mut(|source| draw(source.get_or_insert_with(get_default_source).as_mut()))
+    }
+}
+
+impl RngCore for RandomSource {
+    fn next_u32(&mut self) -> u32 {
+        self.with_source(|source| source.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.with_source(|source| source.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.with_source(|source| source.fill_bytes(dest))
+    }
+}
+
+pub fn random_range<T: SampleUniform, R: SampleRange<T>>(range: R) -> T {
+    RandomSource.random_range(range)
+}
 
-pub fn get_random_suit()->CardSuit{
+pub fn get_random_suit() -> CardSuit {
     let number = random_range(0..CardSuit::COUNT);
     match number {
         0 => CardSuit::Leaf,
-        1 => CardSuit::Pumpkin, 
+        1 => CardSuit::Pumpkin,
         2 => CardSuit::Herz,
         3 => CardSuit::Acorn,
         _ => CardSuit::Leaf, // Fallback to Leaf if out of range
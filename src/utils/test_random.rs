@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::round::Round;
+    use crate::game::rules::RuleSet;
+    use crate::game::table::TableConfig;
+    use crate::utils::random::{get_random_suit, random_range, set_random_seed};
+
+    fn deal_unseeded() -> Round {
+        let player_names = ["Ana", "Ivo", "Maja", "Luka"].map(String::from).to_vec();
+        Round::new(&TableConfig::new(player_names, RuleSet::default()))
+    }
+
+    #[test]
+    fn test_seeded_source_repeats_unseeded_deals() {
+        set_random_seed(7);
+        let first = deal_unseeded();
+        let first_draws = (random_range(0..1000), get_random_suit());
+
+        set_random_seed(7);
+        let second = deal_unseeded();
+        let second_draws = (random_range(0..1000), get_random_suit());
+
+        assert_eq!(first.deal.hands, second.deal.hands);
+        assert_eq!(first_draws, second_draws);
+
+        // another seed, another deal
+        set_random_seed(8);
+        assert_ne!(deal_unseeded().deal.hands, first.deal.hands);
+    }
+}
@@ -0,0 +1,55 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    bindings::agent::{Agent, AgentError, get_agent_player, get_bot},
+    clients::engine_protocol::Request,
+    game::{round::Round, round_player::RoundPlayer, table::Seat},
+};
+
+use super::{get_error, round::JsRound, to_js};
+
+/// Agent written in JavaScript, see the [module](super) for what it is
+/// asked.
+struct JsAgent {
+    agent: js_sys::Function,
+}
+
+impl Agent for JsAgent {
+    type Error = JsValue;
+
+    fn ask(&self, round_state: &Round, seat: Seat, request: &Request) -> Result<String, JsValue> {
+        let round = JsRound::new(round_state.clone(), request.is_trump_called());
+        let answer = self.agent.call4(
+            &JsValue::NULL,
+            &round.into(),
+            &seat.index().into(),
+            &request.to_line().into(),
+            &to_js(&request.get_options()),
+        )?;
+        answer.as_string().ok_or_else(|| {
+            get_error(format!(
+                "agent returned {:?} instead of a string to `{}`",
+                answer,
+                request.to_line()
+            ))
+        })
+    }
+}
+
+/// Player of a seat, the name of a bot of the engine or a JavaScript
+/// agent.
+pub fn get_round_player(
+    agent: JsValue,
+    error: &AgentError<JsValue>,
+) -> Result<Box<dyn RoundPlayer>, JsValue> {
+    if let Some(name) = agent.as_string() {
+        return Ok(get_bot(&name)?);
+    }
+    let Ok(agent) = agent.dyn_into::<js_sys::Function>() else {
+        return Err(get_error(
+            "an agent is a bot name or a function".to_string(),
+        ));
+    };
+
+    Ok(get_agent_player(JsAgent { agent }, error))
+}
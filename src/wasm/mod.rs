@@ -0,0 +1,171 @@
+//! JavaScript API of the engine, so the rules and the bots run in the
+//! browser. Build it without the default features, they need the OS:
+//!
+//! ```text
+//! cargo build --lib --release --target wasm32-unknown-unknown --no-default-features --features wasm
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/belot_game_optmal.wasm
+//! ```
+//!
+//! Names are the ones of the Python module `belot` in camel case,
+//! options are plain objects and seeds are numbers or BigInts. A JavaScript
+//! agent is a function `(round, seat, request, options) => answer` that
+//! returns one of the options as a string, it can't be async. See
+//! [`RequestAnswerer`](crate::clients::protocol_round_player::RequestAnswerer).
+//! There is no OS entropy in the browser, unseeded deals and the bots draw
+//! from [`setRandomSource`](set_random_source) or [`setRandomSeed`](set_random_seed):
+//!
+//! ```text
+//! import init, { Round, setRandomSource } from "./pkg/belot_game_optmal.js";
+//!
+//! await init();
+//! setRandomSource(() => crypto.getRandomValues(new Uint32Array(1))[0]);
+//! const round = new Round({ rules: { kontra: true } });
+//! round.play([(round, seat, request, options) => options[0], "heuristic", "random", "search"]);
+//! console.log(round.score);
+//! ```
+
+mod agent;
+mod round;
+
+use rand::{RngCore, SeedableRng, rand_core::impls, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    bindings::{BindingError, get_declarations, get_points},
+    game::rules::{RuleFlags, RuleSet},
+    utils::random,
+};
+
+fn get_error(message: String) -> JsValue {
+    JsError::new(&message).into()
+}
+
+fn from_js<T: for<'a> Deserialize<'a>>(value: JsValue) -> Result<T, JsValue> {
+    serde_wasm_bindgen::from_value(value).map_err(|error| get_error(error.to_string()))
+}
+
+fn to_js<T: Serialize>(value: &T) -> JsValue {
+    let serializer = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+    value
+        .serialize(&serializer)
+        .expect("engine values serialize")
+}
+
+impl From<BindingError> for JsValue {
+    fn from(error: BindingError) -> Self {
+        get_error(error.message)
+    }
+}
+
+/// Rules a round is played with, every rule is off by default.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Rules {
+    three_players: bool,
+    kontra: bool,
+    extended_contracts: bool,
+    auction: bool,
+    bela_on_second_card: bool,
+}
+
+impl Rules {
+    fn get_rule_set(&self) -> RuleSet {
+        RuleSet::from_flags(RuleFlags {
+            three_players: self.three_players,
+            kontra: self.kontra,
+            extended_contracts: self.extended_contracts,
+            auction: self.auction,
+            bela_on_second_card: self.bela_on_second_card,
+        })
+    }
+}
+
+/// Number the random source returned, `None` unless it is a whole number
+/// an unsigned 32 bit integer holds.
+fn get_random_number(number: Option<f64>) -> Option<u32> {
+    number
+        .filter(|number| number.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(number))
+        .map(|number| number as u32)
+}
+
+/// Randomness of the function, it returns an unsigned 32 bit integer.
+struct JsRandomSource {
+    next: js_sys::Function,
+}
+
+impl JsRandomSource {
+    fn try_next_u32(&self) -> Result<u32, String> {
+        let number = self
+            .next
+            .call0(&JsValue::NULL)
+            .map_err(|error| format!("the random source threw {:?}", error))?;
+        get_random_number(number.as_f64()).ok_or_else(|| {
+            format!(
+                "the random source returned {:?}, not an unsigned 32 bit integer",
+                number
+            )
+        })
+    }
+}
+
+impl RngCore for JsRandomSource {
+    /// The source was checked when it was set, one that fails later panics,
+    /// a round has no way to be handed the error.
+    fn next_u32(&mut self) -> u32 {
+        self.try_next_u32()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+}
+
+/// Makes unseeded deals and the bots draw from the function, for example
+/// `() => crypto.getRandomValues(new Uint32Array(1))[0]`. The function is
+/// called once to check it returns an unsigned 32 bit integer.
+#[wasm_bindgen(js_name = setRandomSource)]
+pub fn set_random_source(next: js_sys::Function) -> Result<(), JsValue> {
+    let source = JsRandomSource { next };
+    source.try_next_u32().map_err(get_error)?;
+    random::set_random_source(Box::new(source));
+    Ok(())
+}
+
+/// Makes unseeded deals and the bots reproducible.
+#[wasm_bindgen(js_name = setRandomSeed)]
+pub fn set_random_seed(seed: JsValue) -> Result<(), JsValue> {
+    random::set_random_source(Box::new(StdRng::seed_from_u64(from_js(seed)?)));
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct DeclarationValue {
+    cards: Vec<String>,
+    points: usize,
+}
+
+/// Declarations the hand holds, as their cards and points.
+#[wasm_bindgen(js_name = possibleDeclarations)]
+pub fn possible_declarations(hand: Vec<String>) -> Result<JsValue, JsValue> {
+    let declarations: Vec<_> = get_declarations(&hand)?
+        .into_iter()
+        .map(|(cards, points)| DeclarationValue { cards, points })
+        .collect();
+
+    Ok(to_js(&declarations))
+}
+
+/// Points the card is worth in a trick under the contract.
+#[wasm_bindgen(js_name = cardPoints)]
+pub fn card_points(card: &str, contract: &str) -> Result<usize, JsValue> {
+    Ok(get_points(card, contract)?)
+}
+
+#[cfg(test)]
+mod test_wasm;
@@ -0,0 +1,464 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    bindings::{
+        get_cards, get_names,
+        round::{get_round, get_table_config, get_team_points, play},
+    },
+    game::{
+        deck::Deal,
+        game_match::{DEFAULT_TARGET_POINTS, Match},
+        round::Round,
+        round_observer::NullObserver,
+        solver::DoubleDummySolver,
+    },
+};
+
+use super::{DeclarationValue, Rules, agent::get_round_player, from_js, get_error, to_js};
+
+/// Options of `new Round()` and `deal()`, every one may be left out.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct RoundOptions {
+    rules: Rules,
+    seed: Option<u64>,
+    dealer: Option<usize>,
+    hands: Option<Vec<Vec<String>>>,
+    talon: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct MatchOptions {
+    rules: Rules,
+    target_points: usize,
+    seed: Option<u64>,
+}
+
+impl Default for MatchOptions {
+    fn default() -> Self {
+        Self {
+            rules: Rules::default(),
+            target_points: DEFAULT_TARGET_POINTS,
+            seed: None,
+        }
+    }
+}
+
+fn get_options<T: Default + for<'a> Deserialize<'a>>(options: JsValue) -> Result<T, JsValue> {
+    Ok(from_js::<Option<T>>(options)?.unwrap_or_default())
+}
+
+#[derive(Serialize)]
+struct DealValue {
+    hands: Vec<Vec<String>>,
+    talon: Vec<String>,
+}
+
+/// Cards dealt to every seat and the talon, shuffled with the seed.
+#[wasm_bindgen]
+pub fn deal(options: JsValue) -> Result<JsValue, JsValue> {
+    let options: RoundOptions = get_options(options)?;
+    let round = Round::new(&get_table_config(
+        options.rules.get_rule_set(),
+        options.seed,
+        None,
+    )?);
+    let hands = round
+        .deal
+        .hands
+        .iter()
+        .map(|hand| get_names(hand))
+        .collect();
+
+    Ok(to_js(&DealValue {
+        hands,
+        talon: get_names(&round.deal.talon),
+    }))
+}
+
+#[derive(Serialize)]
+struct TrumpValue {
+    contract: &'static str,
+    seat: usize,
+}
+
+#[derive(Serialize)]
+struct BidValue {
+    seat: usize,
+    bid: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TrickValue {
+    first_seat: usize,
+    cards: Vec<String>,
+    winner: usize,
+    points: usize,
+}
+
+#[derive(Serialize)]
+struct SeatDeclarationValue {
+    seat: usize,
+    #[serde(flatten)]
+    declaration: DeclarationValue,
+}
+
+#[derive(Serialize)]
+struct SolvedCardValue {
+    card: String,
+    points: usize,
+}
+
+/// Round of the engine, from the deal until it is scored.
+#[wasm_bindgen(js_name = Round)]
+pub struct JsRound {
+    round: Round,
+    is_trump_called: bool,
+}
+
+impl JsRound {
+    pub fn new(round: Round, is_trump_called: bool) -> Self {
+        Self {
+            round,
+            is_trump_called,
+        }
+    }
+
+    fn check_trump_called(&self) -> Result<(), JsValue> {
+        if self.is_trump_called {
+            Ok(())
+        } else {
+            Err(get_error("trump isn't called yet".to_string()))
+        }
+    }
+
+    fn get_solver(&self) -> DoubleDummySolver {
+        let seat = self.round.current_trick.get_seat_turn();
+        DoubleDummySolver::new(&self.round, self.round.players.get_team(seat))
+    }
+}
+
+#[wasm_bindgen(js_class = Round)]
+impl JsRound {
+    /// Shuffled deal, or the given hands and talon. The last seat deals
+    /// unless `dealer` says otherwise, the seat after the dealer plays
+    /// first.
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(options: JsValue) -> Result<JsRound, JsValue> {
+        let options: RoundOptions = get_options(options)?;
+        let rules = options.rules.get_rule_set();
+        let table_config = get_table_config(rules, options.seed, options.dealer)?;
+        let deal = options
+            .hands
+            .map(|hands| -> Result<Deal, JsValue> {
+                Ok(Deal {
+                    hands: hands
+                        .iter()
+                        .map(|hand| get_cards(hand))
+                        .collect::<Result<_, _>>()?,
+                    talon: get_cards(&options.talon.unwrap_or_default())?,
+                })
+            })
+            .transpose()?;
+
+        Ok(Self::new(get_round(&table_config, deal)?, false))
+    }
+
+    /// Plays the round to the end, `agents` has a bot name like
+    /// `heuristic` or a JavaScript agent for every seat.
+    pub fn play(&mut self, agents: Vec<JsValue>) -> Result<(), JsValue> {
+        if self.is_trump_called {
+            return Err(get_error("the round was already played".to_string()));
+        }
+        let number_of_players = self.round.get_number_of_players();
+        play(
+            agents,
+            number_of_players,
+            get_round_player,
+            |round_player| {
+                self.round.play_round(round_player, &NullObserver);
+                self.is_trump_called = true;
+            },
+        )
+    }
+
+    #[wasm_bindgen(getter, js_name = numberOfPlayers)]
+    pub fn number_of_players(&self) -> usize {
+        self.round.get_number_of_players()
+    }
+
+    /// Team of every seat.
+    #[wasm_bindgen(getter)]
+    pub fn teams(&self) -> Vec<String> {
+        let teams = self.round.players.get_seat_teams();
+        teams
+            .into_iter()
+            .map(|team| <&str>::from(team).to_string())
+            .collect()
+    }
+
+    /// Cards every seat still holds.
+    #[wasm_bindgen(getter)]
+    pub fn hands(&self) -> JsValue {
+        let hands: Vec<_> = (&self.round.players)
+            .into_iter()
+            .map(|player| get_names(player.get_hand().cards()))
+            .collect();
+        to_js(&hands)
+    }
+
+    /// Cards every seat was dealt, after a redeal the cards of the redeal.
+    #[wasm_bindgen(getter, js_name = dealtHands)]
+    pub fn dealt_hands(&self) -> JsValue {
+        let hands: Vec<_> = self
+            .round
+            .deal
+            .hands
+            .iter()
+            .map(|hand| get_names(hand))
+            .collect();
+        to_js(&hands)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn talon(&self) -> Vec<String> {
+        get_names(&self.round.talon)
+    }
+
+    /// Seat that plays the next card.
+    #[wasm_bindgen(getter, js_name = seatTurn)]
+    pub fn seat_turn(&self) -> usize {
+        self.round.current_trick.get_seat_turn().index()
+    }
+
+    /// Contract and the seat that called it, `null` until trump is called.
+    #[wasm_bindgen(getter)]
+    pub fn trump(&self) -> JsValue {
+        let trump = &self.round.trump;
+        let trump = self.is_trump_called.then(|| TrumpValue {
+            contract: trump.kind.get_name(),
+            seat: trump.seat.index(),
+        });
+        to_js(&trump)
+    }
+
+    /// `None`, `Kontra` or `Rekontra`.
+    #[wasm_bindgen(getter)]
+    pub fn doubling(&self) -> String {
+        <&str>::from(self.round.doubling).to_string()
+    }
+
+    /// Bids of the auction with the seats that made them.
+    #[wasm_bindgen(getter)]
+    pub fn bids(&self) -> JsValue {
+        let bids: Vec<_> = self
+            .round
+            .auction
+            .bids
+            .iter()
+            .map(|item| BidValue {
+                seat: item.seat.index(),
+                bid: item.bid.get_name(),
+            })
+            .collect();
+        to_js(&bids)
+    }
+
+    /// Cards of the trick that is being played, in the order they were
+    /// played from `trickFirstSeat`.
+    #[wasm_bindgen(getter, js_name = currentTrick)]
+    pub fn current_trick(&self) -> Vec<String> {
+        get_names(&self.round.current_trick.cards_on_table)
+    }
+
+    #[wasm_bindgen(getter, js_name = trickFirstSeat)]
+    pub fn trick_first_seat(&self) -> usize {
+        self.round.current_trick.get_first_seat().index()
+    }
+
+    /// Finished tricks as their first seat, cards, winner and card points.
+    #[wasm_bindgen(getter)]
+    pub fn tricks(&self) -> JsValue {
+        let tricks: Vec<_> = self
+            .round
+            .trick_history
+            .iter()
+            .map(|item| TrickValue {
+                first_seat: item.trick.get_first_seat().index(),
+                cards: get_names(&item.trick.cards_on_table),
+                winner: item.winner_seat.index(),
+                points: item.points,
+            })
+            .collect();
+        to_js(&tricks)
+    }
+
+    /// Declarations that count, as the seat, cards and points.
+    #[wasm_bindgen(getter)]
+    pub fn declarations(&self) -> JsValue {
+        let declarations = self.round.team_declarations.declarations.iter().flatten();
+        let declarations: Vec<_> = declarations
+            .map(|item| SeatDeclarationValue {
+                seat: item.seat.index(),
+                declaration: DeclarationValue {
+                    cards: get_names(&item.declaration.cards),
+                    points: item.declaration.points,
+                },
+            })
+            .collect();
+        to_js(&declarations)
+    }
+
+    /// Seats that declared bela.
+    #[wasm_bindgen(getter)]
+    pub fn bela(&self) -> Vec<usize> {
+        let announcements = &self.round.bela_announcements;
+        announcements
+            .iter()
+            .map(|announcement| announcement.seat.index())
+            .collect()
+    }
+
+    /// Card points of every team from the tricks taken so far.
+    #[wasm_bindgen(getter)]
+    pub fn points(&self) -> JsValue {
+        to_js(&get_team_points(
+            &self.round.points,
+            &self.round.players.get_teams(),
+        ))
+    }
+
+    /// Points of every team once the round is scored, with declarations,
+    /// bela and štiglja, before doubling.
+    #[wasm_bindgen(getter, js_name = finalPoints)]
+    pub fn final_points(&self) -> JsValue {
+        to_js(&get_team_points(
+            &self.round.final_points,
+            &self.round.players.get_teams(),
+        ))
+    }
+
+    /// Points every team gets for the round in a match.
+    #[wasm_bindgen(getter)]
+    pub fn score(&self) -> JsValue {
+        let result = self.round.get_result();
+        to_js(&get_team_points(
+            &result.points,
+            &self.round.players.get_teams(),
+        ))
+    }
+
+    #[wasm_bindgen(getter, js_name = isDone)]
+    pub fn is_done(&self) -> bool {
+        self.is_trump_called && !self.round.players.have_cards()
+    }
+
+    /// Cards the seat on turn may play.
+    #[wasm_bindgen(js_name = playableCards)]
+    pub fn playable_cards(&self) -> Result<Vec<String>, JsValue> {
+        self.check_trump_called()?;
+        let round = &self.round;
+        let cards = round
+            .current_trick
+            .get_playeble_cards(&round.players, &round.trump.kind);
+
+        Ok(get_names(&cards))
+    }
+
+    /// Card points the team of the seat on turn gets in the rest of the
+    /// round when everybody sees every card and plays perfectly.
+    pub fn solve(&self) -> Result<usize, JsValue> {
+        self.check_trump_called()?;
+        Ok(self.get_solver().solve())
+    }
+
+    /// Every card the seat on turn may play with the points its team gets
+    /// after it, see [`JsRound::solve`].
+    #[wasm_bindgen(js_name = solveCards)]
+    pub fn solve_cards(&self) -> Result<JsValue, JsValue> {
+        self.check_trump_called()?;
+        let solved_cards: Vec<_> = self
+            .get_solver()
+            .solve_cards()
+            .into_iter()
+            .map(|solved_card| SolvedCardValue {
+                card: solved_card.card.get_name(),
+                points: solved_card.points,
+            })
+            .collect();
+
+        Ok(to_js(&solved_cards))
+    }
+}
+
+/// Rounds played until a team reaches the target points.
+#[wasm_bindgen(js_name = Match)]
+pub struct JsMatch {
+    game_match: Match,
+}
+
+#[wasm_bindgen(js_class = Match)]
+impl JsMatch {
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(options: JsValue) -> Result<JsMatch, JsValue> {
+        let options: MatchOptions = get_options(options)?;
+        let table_config = get_table_config(options.rules.get_rule_set(), options.seed, None)?;
+        Ok(Self {
+            game_match: Match::new(table_config, options.target_points),
+        })
+    }
+
+    /// Plays the next round with an agent for every seat and returns it.
+    #[wasm_bindgen(js_name = playRound)]
+    pub fn play_round(&mut self, agents: Vec<JsValue>) -> Result<JsRound, JsValue> {
+        if self.game_match.is_done() {
+            return Err(get_error("the match is over".to_string()));
+        }
+        let number_of_players = self
+            .game_match
+            .get_next_table_config()
+            .get_number_of_players();
+        let mut round = None;
+        play(
+            agents,
+            number_of_players,
+            get_round_player,
+            |round_player| round = Some(self.game_match.play_round(round_player, &NullObserver)),
+        )?;
+
+        Ok(JsRound::new(round.expect("round was played"), true))
+    }
+
+    #[wasm_bindgen(getter, js_name = targetPoints)]
+    pub fn target_points(&self) -> usize {
+        self.game_match.target_points
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn points(&self) -> JsValue {
+        to_js(&get_team_points(
+            &self.game_match.points,
+            &self.game_match.get_teams(),
+        ))
+    }
+
+    #[wasm_bindgen(getter, js_name = roundsPlayed)]
+    pub fn rounds_played(&self) -> usize {
+        self.game_match.round_results.len()
+    }
+
+    #[wasm_bindgen(getter, js_name = isDone)]
+    pub fn is_done(&self) -> bool {
+        self.game_match.is_done()
+    }
+
+    /// Team that won, `null` while the match goes on.
+    #[wasm_bindgen(getter)]
+    pub fn winner(&self) -> Option<String> {
+        let winner = self.game_match.get_winner();
+        winner.map(|team| <&str>::from(team).to_string())
+    }
+}
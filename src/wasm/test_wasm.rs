@@ -0,0 +1,17 @@
+#[cfg(test)]
+mod tests {
+    use crate::wasm::get_random_number;
+
+    #[test]
+    fn test_random_source_returns_unsigned_32_bit_integers() {
+        assert_eq!(get_random_number(Some(0.0)), Some(0));
+        assert_eq!(get_random_number(Some(42.0)), Some(42));
+        assert_eq!(get_random_number(Some(u32::MAX as f64)), Some(u32::MAX));
+
+        // what Math.random, a typo or a missing return give
+        for number in [0.5, -1.0, u32::MAX as f64 + 1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(get_random_number(Some(number)), None);
+        }
+        assert_eq!(get_random_number(None), None);
+    }
+}